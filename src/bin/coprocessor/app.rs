@@ -1,6 +1,6 @@
 use std::{
     fs,
-    path::{Path, PathBuf},
+    path::PathBuf,
     process::{Command, Stdio},
 };
 
@@ -22,21 +22,7 @@ pub async fn deploy(
 ) -> anyhow::Result<Value> {
     let (dir, manifest) = Manifest::load_from_path(manifest)?;
 
-    fn _load(manifest: &Manifest, dir: &Path, name: &str) -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
-        let artifacts = dir
-            .join(&manifest.valence.artifacts)
-            .join(name)
-            .canonicalize()?;
-
-        info!("Loading artifacts from `{}`...", artifacts.display());
-
-        let circuit = fs::read(artifacts.join("circuit.bin"))?;
-        let controller = fs::read(artifacts.join("controller.bin"))?;
-
-        Ok((circuit, controller))
-    }
-
-    let (circuit, controller) = _load(&manifest, &dir, name).inspect_err(|_| {
+    let (circuit, controller) = manifest.load_artifacts(&dir, name).inspect_err(|_| {
         error!("Artifacts failed to load! Did you forget to build?");
     })?;
 
@@ -119,19 +105,24 @@ pub async fn download(
 }
 
 pub async fn deploy_all(client: &CoprocessorClient, manifest: &str) -> anyhow::Result<Value> {
-    let m = fs::read_to_string(manifest)?;
-    let m: Manifest = toml::from_str(&m)?;
-    let names: Vec<String> = m.circuit.keys().map(|name| name.into()).collect();
-    let mut resources = Vec::with_capacity(names.len());
-
-    for n in names {
-        let id = deploy(client, manifest, &n).await?;
-
-        resources.push(json!({
-            "name": n,
-            "id": id,
-        }));
-    }
+    let (dir, manifest) = Manifest::load_from_path(manifest)?;
+
+    let ids = client
+        .deploy_manifest(&dir, &manifest)
+        .await
+        .inspect_err(|_| {
+            error!("Deployment failed! Did you forget to build?");
+        })?;
+
+    let resources: Vec<Value> = ids
+        .into_iter()
+        .map(|(name, id)| {
+            json!({
+                "name": name,
+                "id": id,
+            })
+        })
+        .collect();
 
     Ok(json!(resources))
 }
@@ -0,0 +1,103 @@
+use alloy::{
+    primitives::{address, Address, U256},
+    providers::Provider,
+    sol,
+};
+use alloy_signer_local::coins_bip39::English;
+use alloy_signer_local::{MnemonicBuilder, PrivateKeySigner};
+use serde_json::Value;
+use tonic::async_trait;
+
+use crate::evm::base_client::EvmBaseClient;
+use crate::evm::chains::constants::ARBITRUM_ONE_CHAIN_ID;
+use crate::evm::request_provider_client::{ConnectionConfig, RequestProviderClient};
+
+/// `ArbGasInfo` precompile exposing the l1 pricing state of the chain
+pub const ARB_GAS_INFO_ADDRESS: Address = address!("000000000000000000000000000000000000006C");
+
+sol!(
+    #[sol(rpc)]
+    interface ArbGasInfo {
+        function getL1BaseFeeEstimate() external view returns (uint256);
+    }
+);
+
+/// client for interacting with arbitrum one and its l1 related precompiles
+pub struct ArbitrumClient {
+    rpc_url: String,
+    signer: PrivateKeySigner,
+    connection_config: ConnectionConfig,
+}
+
+impl ArbitrumClient {
+    pub fn new(
+        rpc_url: &str,
+        mnemonic: &str,
+        mnemonic_derivation_index: Option<u32>,
+    ) -> anyhow::Result<Self> {
+        let builder = MnemonicBuilder::<English>::default().phrase(mnemonic);
+
+        let derivation_index = mnemonic_derivation_index.unwrap_or_default();
+
+        let signer = builder.index(derivation_index)?.build()?;
+
+        Ok(Self {
+            rpc_url: rpc_url.to_string(),
+            signer,
+            connection_config: ConnectionConfig::default(),
+        })
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        ARBITRUM_ONE_CHAIN_ID
+    }
+
+    /// returns the l1 block number the latest l2 block was created at.
+    /// arbitrum nodes include it as `l1BlockNumber` in their block responses.
+    pub async fn get_l1_block_number(&self) -> anyhow::Result<u64> {
+        let client = self.get_request_provider().await?;
+
+        let block: Value = client
+            .client()
+            .request("eth_getBlockByNumber", ("latest", false))
+            .await?;
+
+        let l1_block_number = block["l1BlockNumber"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("no l1BlockNumber in latest block"))?;
+
+        Ok(u64::from_str_radix(
+            l1_block_number.trim_start_matches("0x"),
+            16,
+        )?)
+    }
+
+    /// returns the chain's current estimate of the l1 base fee, in wei
+    pub async fn get_l1_base_fee_estimate(&self) -> anyhow::Result<U256> {
+        let client = self.get_request_provider().await?;
+
+        let arb_gas_info = ArbGasInfo::new(ARB_GAS_INFO_ADDRESS, &client);
+
+        let base_fee = self.query(arb_gas_info.getL1BaseFeeEstimate()).await?._0;
+
+        Ok(base_fee)
+    }
+}
+
+#[async_trait]
+impl EvmBaseClient for ArbitrumClient {}
+
+#[async_trait]
+impl RequestProviderClient for ArbitrumClient {
+    fn rpc_url(&self) -> String {
+        self.rpc_url.clone()
+    }
+
+    fn signer(&self) -> PrivateKeySigner {
+        self.signer.clone()
+    }
+
+    fn connection_config(&self) -> Option<&ConnectionConfig> {
+        Some(&self.connection_config)
+    }
+}
@@ -0,0 +1,168 @@
+use alloy::{primitives::U256, providers::Provider};
+use alloy_signer_local::coins_bip39::English;
+use alloy_signer_local::{MnemonicBuilder, PrivateKeySigner};
+use serde_json::{json, Value};
+use tonic::async_trait;
+
+use crate::evm::base_client::{Eip1559FeeEstimate, EvmBaseClient};
+use crate::evm::request_provider_client::{ConnectionConfig, RequestProviderClient};
+
+/// avalanche primary network the client connects to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvalancheNetwork {
+    Mainnet,
+    Fuji,
+}
+
+impl AvalancheNetwork {
+    /// evm chain id of the network's c-chain
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            AvalancheNetwork::Mainnet => 43114,
+            AvalancheNetwork::Fuji => 43113,
+        }
+    }
+
+    /// public api node of the network
+    pub fn default_api_url(&self) -> &'static str {
+        match self {
+            AvalancheNetwork::Mainnet => "https://api.avax.network",
+            AvalancheNetwork::Fuji => "https://api.avax-test.network",
+        }
+    }
+}
+
+/// client for interacting with the avalanche c-chain along with the
+/// avalanche specific platform (p-chain) and atomic tx apis of the node
+pub struct AvalancheClient {
+    api_url: String,
+    network: AvalancheNetwork,
+    signer: PrivateKeySigner,
+    connection_config: ConnectionConfig,
+}
+
+impl AvalancheClient {
+    /// builds a client for the given network. `api_url` is the base url of
+    /// the avalanche node (without the `/ext/bc/..` path) and defaults to the
+    /// public api node of the network.
+    pub fn new(
+        network: AvalancheNetwork,
+        api_url: Option<&str>,
+        mnemonic: &str,
+        mnemonic_derivation_index: Option<u32>,
+    ) -> anyhow::Result<Self> {
+        let builder = MnemonicBuilder::<English>::default().phrase(mnemonic);
+
+        let derivation_index = mnemonic_derivation_index.unwrap_or_default();
+
+        let signer = builder.index(derivation_index)?.build()?;
+
+        Ok(Self {
+            api_url: api_url
+                .unwrap_or(network.default_api_url())
+                .trim_end_matches('/')
+                .to_string(),
+            network,
+            signer,
+            connection_config: ConnectionConfig::default(),
+        })
+    }
+
+    pub fn network(&self) -> AvalancheNetwork {
+        self.network
+    }
+
+    /// returns the unlocked avax balance (in nAVAX) of a p-chain address,
+    /// e.g. `P-avax1...`
+    pub async fn query_p_chain_balance(&self, p_chain_address: &str) -> anyhow::Result<U256> {
+        let result = self
+            .avalanche_rpc(
+                "/ext/bc/P",
+                "platform.getBalance",
+                json!({ "addresses": [p_chain_address] }),
+            )
+            .await?;
+
+        let balance = result["unlocked"]
+            .as_str()
+            .or_else(|| result["balance"].as_str())
+            .ok_or_else(|| anyhow::anyhow!("no balance in platform.getBalance response"))?;
+
+        Ok(balance.parse()?)
+    }
+
+    /// returns an atomic (cross-chain import/export) tx of the c-chain
+    pub async fn avax_get_atomic_tx(&self, tx_id: &str) -> anyhow::Result<Value> {
+        self.avalanche_rpc(
+            "/ext/bc/C/avax",
+            "avax.getAtomicTx",
+            json!({ "txID": tx_id, "encoding": "hex" }),
+        )
+        .await
+    }
+
+    async fn avalanche_rpc(
+        &self,
+        path: &str,
+        method: &str,
+        params: Value,
+    ) -> anyhow::Result<Value> {
+        let response: Value = reqwest::Client::new()
+            .post(format!("{}{path}", self.api_url))
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params,
+            }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            return Err(anyhow::anyhow!("{method} failed: {error}"));
+        }
+
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("{method} returned no result"))
+    }
+}
+
+#[async_trait]
+impl EvmBaseClient for AvalancheClient {
+    /// the c-chain exposes its dynamic base fee through `eth_baseFee`. the
+    /// fee cap is the base fee with a 10% buffer plus the suggested tip.
+    async fn suggest_eip1559_fees(&self) -> anyhow::Result<Eip1559FeeEstimate> {
+        let client = self.get_request_provider().await?;
+
+        let base_fee: U256 = client.client().request_noparams("eth_baseFee").await?;
+        let base_fee: u128 = base_fee.try_into()?;
+
+        let max_priority_fee_per_gas = client.get_max_priority_fee_per_gas().await?;
+
+        Ok(Eip1559FeeEstimate {
+            max_fee_per_gas: base_fee * 11 / 10 + max_priority_fee_per_gas,
+            max_priority_fee_per_gas,
+            base_fee_per_gas: base_fee,
+        })
+    }
+}
+
+#[async_trait]
+impl RequestProviderClient for AvalancheClient {
+    fn rpc_url(&self) -> String {
+        format!("{}/ext/bc/C/rpc", self.api_url)
+    }
+
+    fn signer(&self) -> PrivateKeySigner {
+        self.signer.clone()
+    }
+
+    fn connection_config(&self) -> Option<&ConnectionConfig> {
+        Some(&self.connection_config)
+    }
+}
@@ -1,7 +1,13 @@
+use cosmos_sdk_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use cosmrs::tendermint::crypto::{default::Sha256, Sha256 as _};
 use tonic::async_trait;
 
 use crate::cosmos::{
-    base_client::BaseClient, grpc_client::GrpcSigningClient, wasm_client::WasmClient,
+    base_client::BaseClient,
+    grpc_client::{GrpcChannelCache, GrpcSigningClient},
+    types::parse_dec,
+    utils::collect_all_pages,
+    wasm_client::WasmClient,
 };
 
 const CHAIN_NAME: &str = "babylon";
@@ -14,6 +20,7 @@ pub struct BabylonClient {
     chain_id: String,
     chain_denom: String,
     gas_price: f64,
+    channel_cache: GrpcChannelCache,
 }
 
 impl BabylonClient {
@@ -31,6 +38,369 @@ impl BabylonClient {
             chain_id: chain_id.to_string(),
             chain_denom: CHAIN_DENOM.to_string(),
             gas_price: avg_gas_price,
+            channel_cache: GrpcChannelCache::default(),
+        })
+    }
+
+    pub async fn query_btc_header_info(&self, height: u32) -> anyhow::Result<BtcHeaderInfo> {
+        let response: QueryHeaderByHeightResponse = self
+            .query_grpc(
+                "/babylon.btclightclient.v1.Query/HeaderByHeight",
+                QueryHeaderByHeightRequest { height },
+            )
+            .await?;
+
+        response
+            .header
+            .map(BtcHeaderInfo::from)
+            .ok_or_else(|| anyhow::anyhow!("no btc header found at height {height}"))
+    }
+
+    pub async fn query_btc_base_header(&self) -> anyhow::Result<BtcHeaderInfo> {
+        let response: QueryBtcHeaderResponse = self
+            .query_grpc(
+                "/babylon.btclightclient.v1.Query/BaseHeader",
+                QueryBaseHeaderRequest {},
+            )
+            .await?;
+
+        response
+            .header
+            .map(BtcHeaderInfo::from)
+            .ok_or_else(|| anyhow::anyhow!("no btc base header found"))
+    }
+
+    pub async fn query_btc_chain_tip(&self) -> anyhow::Result<BtcHeaderInfo> {
+        let response: QueryBtcHeaderResponse = self
+            .query_grpc("/babylon.btclightclient.v1.Query/Tip", QueryTipRequest {})
+            .await?;
+
+        response
+            .header
+            .map(BtcHeaderInfo::from)
+            .ok_or_else(|| anyhow::anyhow!("no btc chain tip found"))
+    }
+
+    /// queries a finality provider by its hex encoded btc public key. the
+    /// total stake is taken from the current voting power of the provider.
+    pub async fn query_finality_provider(
+        &self,
+        fp_btc_pk: &str,
+    ) -> anyhow::Result<FinalityProvider> {
+        let response: QueryFinalityProviderResponse = self
+            .query_grpc(
+                "/babylon.btcstaking.v1.Query/FinalityProvider",
+                QueryFinalityProviderRequest {
+                    fp_btc_pk_hex: fp_btc_pk.to_string(),
+                },
+            )
+            .await?;
+
+        let finality_provider = response
+            .finality_provider
+            .ok_or_else(|| anyhow::anyhow!("finality provider {fp_btc_pk} not found"))?;
+
+        let total_sat = self.query_finality_provider_power(fp_btc_pk).await?;
+
+        Ok(FinalityProvider {
+            btc_pk: hex::encode(finality_provider.btc_pk),
+            addr: finality_provider.addr,
+            commission: parse_dec(&finality_provider.commission)?,
+            total_sat,
+        })
+    }
+
+    /// lists all registered finality providers along with their current
+    /// voting power
+    pub async fn query_finality_providers(&self) -> anyhow::Result<Vec<FinalityProvider>> {
+        let finality_providers = collect_all_pages(100, |page| async move {
+            let response: QueryFinalityProvidersResponse = self
+                .query_grpc(
+                    "/babylon.btcstaking.v1.Query/FinalityProviders",
+                    QueryFinalityProvidersRequest {
+                        pagination: Some(page.into()),
+                    },
+                )
+                .await?;
+
+            Ok((
+                response.finality_providers,
+                response.pagination.unwrap_or_default().into(),
+            ))
+        })
+        .await?;
+
+        let mut result = Vec::with_capacity(finality_providers.len());
+
+        for finality_provider in finality_providers {
+            let btc_pk = hex::encode(finality_provider.btc_pk);
+            let total_sat = self.query_finality_provider_power(&btc_pk).await?;
+
+            result.push(FinalityProvider {
+                btc_pk,
+                addr: finality_provider.addr,
+                commission: parse_dec(&finality_provider.commission)?,
+                total_sat,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// returns the current voting power of the finality provider, in sats
+    pub async fn query_finality_provider_power(&self, fp_btc_pk: &str) -> anyhow::Result<u64> {
+        let response: QueryFinalityProviderCurrentPowerResponse = self
+            .query_grpc(
+                "/babylon.finality.v1.Query/FinalityProviderCurrentPower",
+                QueryFinalityProviderCurrentPowerRequest {
+                    fp_btc_pk_hex: fp_btc_pk.to_string(),
+                },
+            )
+            .await?;
+
+        Ok(response.voting_power)
+    }
+
+    pub async fn query_btc_delegation(
+        &self,
+        staking_tx_hash_hex: &str,
+    ) -> anyhow::Result<BtcDelegation> {
+        let response: QueryBtcDelegationResponse = self
+            .query_grpc(
+                "/babylon.btcstaking.v1.Query/BTCDelegation",
+                QueryBtcDelegationRequest {
+                    staking_tx_hash_hex: staking_tx_hash_hex.to_string(),
+                },
+            )
+            .await?;
+
+        let delegation = response
+            .btc_delegation
+            .ok_or_else(|| anyhow::anyhow!("btc delegation {staking_tx_hash_hex} not found"))?;
+
+        Ok(BtcDelegation {
+            staking_tx_hash: staking_tx_hash_hex.to_string(),
+            fp_btc_pk_list: delegation.fp_btc_pk_list.iter().map(hex::encode).collect(),
+            total_sat: delegation.total_sat,
+            state: delegation.status_desc,
+        })
+    }
+
+    /// lists all btc delegations to the finality provider
+    pub async fn query_btc_delegations(
+        &self,
+        fp_btc_pk: &str,
+    ) -> anyhow::Result<Vec<BtcDelegation>> {
+        let delegator_delegations = collect_all_pages(100, |page| async move {
+            let response: QueryFinalityProviderDelegationsResponse = self
+                .query_grpc(
+                    "/babylon.btcstaking.v1.Query/FinalityProviderDelegations",
+                    QueryFinalityProviderDelegationsRequest {
+                        fp_btc_pk_hex: fp_btc_pk.to_string(),
+                        pagination: Some(page.into()),
+                    },
+                )
+                .await?;
+
+            Ok((
+                response.btc_delegator_delegations,
+                response.pagination.unwrap_or_default().into(),
+            ))
+        })
+        .await?;
+
+        delegator_delegations
+            .into_iter()
+            .flat_map(|delegator| delegator.dels)
+            .map(|delegation| {
+                Ok(BtcDelegation {
+                    staking_tx_hash: btc_txid(&hex::decode(&delegation.staking_tx_hex)?)?,
+                    fp_btc_pk_list: delegation.fp_btc_pk_list.iter().map(hex::encode).collect(),
+                    total_sat: delegation.total_sat,
+                    state: delegation.status_desc,
+                })
+            })
+            .collect()
+    }
+
+    /// returns the parameters of the btc staking module
+    pub async fn query_btc_staking_params(&self) -> anyhow::Result<BabylonBtcStakingParams> {
+        let response: QueryBtcStakingParamsResponse = self
+            .query_grpc(
+                "/babylon.btcstaking.v1.Query/Params",
+                QueryBtcStakingParamsRequest {},
+            )
+            .await?;
+
+        response
+            .params
+            .ok_or_else(|| anyhow::anyhow!("No btc staking params returned"))?
+            .try_into()
+    }
+}
+
+/// computes the hex encoded txid of a serialized btc transaction. the txid
+/// commits to the tx without its witness data, so any segwit marker and
+/// witnesses are skipped.
+fn btc_txid(tx: &[u8]) -> anyhow::Result<String> {
+    let mut reader = BtcTxReader { tx, pos: 0 };
+
+    let version = reader.read(4)?;
+
+    let is_segwit = reader.tx.get(reader.pos..reader.pos + 2) == Some(&[0x00, 0x01][..]);
+    if is_segwit {
+        reader.read(2)?;
+    }
+
+    let inputs_start = reader.pos;
+
+    let input_count = reader.read_varint()?;
+    for _ in 0..input_count {
+        // previous outpoint
+        reader.read(36)?;
+        let script_len = reader.read_varint()?;
+        reader.read(script_len)?;
+        // sequence
+        reader.read(4)?;
+    }
+
+    let output_count = reader.read_varint()?;
+    for _ in 0..output_count {
+        // value
+        reader.read(8)?;
+        let script_len = reader.read_varint()?;
+        reader.read(script_len)?;
+    }
+
+    let inputs_and_outputs = &tx[inputs_start..reader.pos];
+
+    if is_segwit {
+        for _ in 0..input_count {
+            let item_count = reader.read_varint()?;
+            for _ in 0..item_count {
+                let item_len = reader.read_varint()?;
+                reader.read(item_len)?;
+            }
+        }
+    }
+
+    let lock_time = reader.read(4)?;
+
+    let stripped_tx = [version, inputs_and_outputs, lock_time].concat();
+
+    // txids are displayed in reverse byte order
+    let mut txid = Sha256::digest(Sha256::digest(&stripped_tx));
+    txid.reverse();
+
+    Ok(hex::encode(txid))
+}
+
+struct BtcTxReader<'a> {
+    tx: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BtcTxReader<'a> {
+    fn read(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        let bytes = self
+            .tx
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| anyhow::anyhow!("btc tx is truncated"))?;
+
+        self.pos += len;
+
+        Ok(bytes)
+    }
+
+    fn read_varint(&mut self) -> anyhow::Result<usize> {
+        let value = match self.read(1)?[0] {
+            0xfd => u16::from_le_bytes(self.read(2)?.try_into()?) as u64,
+            0xfe => u32::from_le_bytes(self.read(4)?.try_into()?) as u64,
+            0xff => u64::from_le_bytes(self.read(8)?.try_into()?),
+            value => value as u64,
+        };
+
+        Ok(usize::try_from(value)?)
+    }
+}
+
+/// btc header as tracked by the babylon btc light client
+#[derive(Debug, Clone, PartialEq)]
+pub struct BtcHeaderInfo {
+    pub height: u32,
+    /// hex encoded block hash
+    pub hash: String,
+    /// hex encoded block header
+    pub header: String,
+    /// cumulative proof of work up to and including this header
+    pub cumulative_work: String,
+}
+
+impl From<BtcHeaderInfoResponse> for BtcHeaderInfo {
+    fn from(value: BtcHeaderInfoResponse) -> Self {
+        BtcHeaderInfo {
+            height: value.height,
+            hash: value.hash_hex,
+            header: value.header_hex,
+            cumulative_work: value.work,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FinalityProvider {
+    /// hex encoded btc public key
+    pub btc_pk: String,
+    /// babylon address of the finality provider
+    pub addr: String,
+    pub commission: f64,
+    /// total satoshis delegated to the finality provider
+    pub total_sat: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BtcDelegation {
+    pub staking_tx_hash: String,
+    /// hex encoded btc public keys of the finality providers delegated to
+    pub fp_btc_pk_list: Vec<String>,
+    pub total_sat: u64,
+    /// delegation status (e.g. `PENDING`, `ACTIVE`, `UNBONDED`)
+    pub state: String,
+}
+
+/// parameters of the btc staking module
+#[derive(Debug, Clone, PartialEq)]
+pub struct BabylonBtcStakingParams {
+    /// hex encoded public keys of the covenant committee
+    pub covenant_pks: Vec<String>,
+    pub covenant_quorum: u32,
+    pub min_staking_value_sat: i64,
+    pub max_staking_value_sat: i64,
+    pub min_staking_time_blocks: u32,
+    pub max_staking_time_blocks: u32,
+    pub min_slashing_tx_fee_sat: i64,
+    pub slashing_rate: f64,
+    pub unbonding_time_blocks: u32,
+    pub unbonding_fee_sat: i64,
+    pub min_commission_rate: f64,
+}
+
+impl TryFrom<BtcStakingParamsResponse> for BabylonBtcStakingParams {
+    type Error = anyhow::Error;
+
+    fn try_from(value: BtcStakingParamsResponse) -> anyhow::Result<Self> {
+        Ok(BabylonBtcStakingParams {
+            covenant_pks: value.covenant_pks.iter().map(hex::encode).collect(),
+            covenant_quorum: value.covenant_quorum,
+            min_staking_value_sat: value.min_staking_value_sat,
+            max_staking_value_sat: value.max_staking_value_sat,
+            min_staking_time_blocks: value.min_staking_time_blocks,
+            max_staking_time_blocks: value.max_staking_time_blocks,
+            min_slashing_tx_fee_sat: value.min_slashing_tx_fee_sat,
+            slashing_rate: parse_dec(&value.slashing_rate)?,
+            unbonding_time_blocks: value.unbonding_time_blocks,
+            unbonding_fee_sat: value.unbonding_fee_sat,
+            min_commission_rate: parse_dec(&value.min_commission_rate)?,
         })
     }
 }
@@ -67,7 +437,273 @@ impl GrpcSigningClient for BabylonClient {
         self.gas_price
     }
 
+    fn set_gas_price(&mut self, gas_price: f64) -> anyhow::Result<()> {
+        self.gas_price = gas_price;
+        Ok(())
+    }
+
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        Some(&self.channel_cache)
+    }
+
     fn gas_adjustment(&self) -> f64 {
         1.8
     }
 }
+
+// Proto definitions to query babylon. only the fields used by the client
+// are defined, any other fields are skipped when decoding.
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcHeaderInfoResponse {
+    #[prost(string, tag = "1")]
+    pub header_hex: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub hash_hex: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub height: u32,
+    #[prost(string, tag = "4")]
+    pub work: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryHeaderByHeightRequest {
+    #[prost(uint32, tag = "1")]
+    pub height: u32,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryHeaderByHeightResponse {
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<BtcHeaderInfoResponse>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryBaseHeaderRequest {}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryTipRequest {}
+
+/// shared shape of the `BaseHeader` and `Tip` query responses
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryBtcHeaderResponse {
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<BtcHeaderInfoResponse>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFinalityProviderRequest {
+    #[prost(string, tag = "1")]
+    pub fp_btc_pk_hex: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinalityProviderResponse {
+    /// commission rate as a cosmos-sdk decimal
+    #[prost(string, tag = "2")]
+    pub commission: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub addr: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "4")]
+    pub btc_pk: ::prost::alloc::vec::Vec<u8>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFinalityProviderResponse {
+    #[prost(message, optional, tag = "1")]
+    pub finality_provider: ::core::option::Option<FinalityProviderResponse>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFinalityProviderCurrentPowerRequest {
+    #[prost(string, tag = "1")]
+    pub fp_btc_pk_hex: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFinalityProviderCurrentPowerResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(uint64, tag = "2")]
+    pub voting_power: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryBtcDelegationRequest {
+    #[prost(string, tag = "1")]
+    pub staking_tx_hash_hex: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcDelegationResponse {
+    #[prost(string, tag = "1")]
+    pub staker_addr: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub fp_btc_pk_list: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, tag = "7")]
+    pub total_sat: u64,
+    #[prost(string, tag = "8")]
+    pub staking_tx_hex: ::prost::alloc::string::String,
+    #[prost(string, tag = "14")]
+    pub status_desc: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryBtcDelegationResponse {
+    #[prost(message, optional, tag = "1")]
+    pub btc_delegation: ::core::option::Option<BtcDelegationResponse>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFinalityProvidersRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<PageRequest>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFinalityProvidersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub finality_providers: ::prost::alloc::vec::Vec<FinalityProviderResponse>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<PageResponse>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFinalityProviderDelegationsRequest {
+    #[prost(string, tag = "1")]
+    pub fp_btc_pk_hex: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<PageRequest>,
+}
+
+/// delegations of a single btc staker
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcDelegatorDelegationsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub dels: ::prost::alloc::vec::Vec<BtcDelegationResponse>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFinalityProviderDelegationsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub btc_delegator_delegations: ::prost::alloc::vec::Vec<BtcDelegatorDelegationsResponse>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<PageResponse>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryBtcStakingParamsRequest {}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcStakingParamsResponse {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub covenant_pks: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, tag = "2")]
+    pub covenant_quorum: u32,
+    #[prost(int64, tag = "3")]
+    pub min_staking_value_sat: i64,
+    #[prost(int64, tag = "4")]
+    pub max_staking_value_sat: i64,
+    #[prost(uint32, tag = "5")]
+    pub min_staking_time_blocks: u32,
+    #[prost(uint32, tag = "6")]
+    pub max_staking_time_blocks: u32,
+    #[prost(int64, tag = "8")]
+    pub min_slashing_tx_fee_sat: i64,
+    /// slashing rate as a cosmos-sdk decimal
+    #[prost(string, tag = "9")]
+    pub slashing_rate: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "10")]
+    pub unbonding_time_blocks: u32,
+    #[prost(int64, tag = "11")]
+    pub unbonding_fee_sat: i64,
+    /// minimum commission rate as a cosmos-sdk decimal
+    #[prost(string, tag = "12")]
+    pub min_commission_rate: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryBtcStakingParamsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub params: ::core::option::Option<BtcStakingParamsResponse>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY_TX: &str = "020000000111111111111111111111111111111111111111111111111111111111111111110000000000ffffffff01e803000000000000160014222222222222222222222222222222222222222200000000";
+    const SEGWIT_TX: &str = "0200000000010111111111111111111111111111111111111111111111111111111111111111110000000000ffffffff01e80300000000000016001422222222222222222222222222222222222222220102abcd00000000";
+    const TXID: &str = "b91b720103788b9ae50dbff918bc375d185df91025b9f6e861efe208909b6adf";
+
+    const LOCAL_GRPC_URL: &str = "http://127.0.0.1";
+    const LOCAL_GRPC_PORT: &str = "9090";
+    const LOCAL_MNEMONIC: &str = "test test test test test test test test test test test junk";
+    const LOCAL_CHAIN_ID: &str = "localbabylon-1";
+
+    #[test]
+    fn btc_txid_ignores_witness_data() {
+        assert_eq!(btc_txid(&hex::decode(LEGACY_TX).unwrap()).unwrap(), TXID);
+        assert_eq!(btc_txid(&hex::decode(SEGWIT_TX).unwrap()).unwrap(), TXID);
+
+        let truncated = hex::decode(&LEGACY_TX[..LEGACY_TX.len() - 8]).unwrap();
+        assert!(btc_txid(&truncated).is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires local babylon instance"]
+    async fn test_query_btc_staking_state() {
+        let client = BabylonClient::new(
+            LOCAL_GRPC_URL,
+            LOCAL_GRPC_PORT,
+            LOCAL_MNEMONIC,
+            LOCAL_CHAIN_ID,
+        )
+        .await
+        .unwrap();
+
+        let params = client.query_btc_staking_params().await.unwrap();
+        assert!(params.covenant_quorum > 0);
+
+        let finality_providers = client.query_finality_providers().await.unwrap();
+        let finality_provider = finality_providers.first().unwrap();
+
+        let power = client
+            .query_finality_provider_power(&finality_provider.btc_pk)
+            .await
+            .unwrap();
+        assert_eq!(power, finality_provider.total_sat);
+
+        let delegations = client
+            .query_btc_delegations(&finality_provider.btc_pk)
+            .await
+            .unwrap();
+        for delegation in delegations {
+            assert!(delegation
+                .fp_btc_pk_list
+                .contains(&finality_provider.btc_pk));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::{collections::HashMap, path::Path};
+
 use async_trait::async_trait;
 use msgpacker::Unpackable as _;
 use serde::de::DeserializeOwned;
@@ -5,8 +7,9 @@ use serde_json::{json, Value};
 use tokio::time::{self, Duration};
 use uuid::Uuid;
 
-use crate::coprocessor::base_client::{
-    Base64, CoprocessorBaseClient, DomainProof, Entrypoint, Proof, Witnesses,
+use crate::coprocessor::{
+    base_client::{Base64, CoprocessorBaseClient, DomainProof, Entrypoint, Proof, Witnesses},
+    Manifest,
 };
 
 #[derive(Debug, Clone)]
@@ -15,6 +18,7 @@ pub struct RequestBuilder<'a> {
     circuit: Option<&'a str>,
     root: Option<&'a str>,
     args: Option<&'a Value>,
+    http_client: Option<&'a reqwest::Client>,
 }
 
 impl<'a> RequestBuilder<'a> {
@@ -24,6 +28,7 @@ impl<'a> RequestBuilder<'a> {
             circuit: None,
             root: None,
             args: None,
+            http_client: None,
         }
     }
 
@@ -42,6 +47,16 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
+    /// Sends the request with the given HTTP client instead of a new one.
+    pub fn with_http_client(mut self, http_client: &'a reqwest::Client) -> Self {
+        self.http_client.replace(http_client);
+        self
+    }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.http_client.cloned().unwrap_or_default()
+    }
+
     async fn _send<T: DeserializeOwned>(
         self,
         mut client: reqwest::RequestBuilder,
@@ -84,19 +99,19 @@ impl<'a> RequestBuilder<'a> {
     }
 
     pub async fn delete<T: DeserializeOwned>(self) -> anyhow::Result<T> {
-        let client = reqwest::Client::new().delete(self.uri);
+        let client = self.http_client().delete(self.uri);
 
         self._send::<T>(client).await
     }
 
     pub async fn get<T: DeserializeOwned>(self) -> anyhow::Result<T> {
-        let client = reqwest::Client::new().get(self.uri);
+        let client = self.http_client().get(self.uri);
 
         self._send::<T>(client).await
     }
 
     pub async fn post<T: DeserializeOwned>(self) -> anyhow::Result<T> {
-        let client = reqwest::Client::new().post(self.uri);
+        let client = self.http_client().post(self.uri);
 
         self._send::<T>(client).await
     }
@@ -109,6 +124,7 @@ impl<'a> RequestBuilder<'a> {
 pub struct CoprocessorClient {
     /// The co-processor address.
     pub coprocessor: String,
+    http_client: reqwest::Client,
 }
 
 impl Default for CoprocessorClient {
@@ -122,14 +138,26 @@ impl CoprocessorClient {
     pub const DEFAULT_COPROCESSOR: &str = "https://service.coprocessor.valence.zone";
 
     pub fn new(coprocessor: String) -> Self {
-        Self { coprocessor }
+        Self {
+            coprocessor,
+            http_client: reqwest::Client::new(),
+        }
     }
 
     /// Starts a client that connects to a localhost co-processor
     pub fn local() -> Self {
-        Self {
-            coprocessor: "http://127.0.0.1:37281".into(),
-        }
+        Self::new("http://127.0.0.1:37281".into())
+    }
+
+    /// Uses the given HTTP client for all requests, e.g. one configured with timeouts or
+    /// proxies.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    fn request<'a>(&'a self, uri: &'a str) -> RequestBuilder<'a> {
+        RequestBuilder::new(uri).with_http_client(&self.http_client)
     }
 
     /// Computes the URI of the co-processor.
@@ -137,6 +165,27 @@ impl CoprocessorClient {
         format!("{}/api/{}", self.coprocessor, path.as_ref(),)
     }
 
+    /// Deploys the controllers of all circuits listed on the manifest.
+    ///
+    /// Artifacts are loaded relative to `dir`, the manifest parent dir. Returns the deployed
+    /// circuit ids, keyed by circuit name.
+    pub async fn deploy_manifest<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        manifest: &Manifest,
+    ) -> anyhow::Result<HashMap<String, String>> {
+        let mut ids = HashMap::with_capacity(manifest.circuit.len());
+
+        for name in manifest.circuit.keys() {
+            let (circuit, controller) = manifest.load_artifacts(dir.as_ref(), name)?;
+            let id = self.deploy_controller(&controller, &circuit, None).await?;
+
+            ids.insert(name.clone(), id);
+        }
+
+        Ok(ids)
+    }
+
     /// Fetches a proof from the queue, returning if present.
     pub async fn get_proof_from_storage<P: AsRef<str>>(
         &self,
@@ -209,7 +258,8 @@ impl CoprocessorClient {
             }
         });
 
-        let res: Value = RequestBuilder::new(&uri)
+        let res: Value = self
+            .request(&uri)
             .with_circuit(circuit)
             .with_root(root)
             .with_args(&args)
@@ -246,14 +296,22 @@ impl CoprocessorClient {
 impl CoprocessorBaseClient for CoprocessorClient {
     async fn stats(&self) -> anyhow::Result<Value> {
         let uri = self.uri("stats");
-        let stats = RequestBuilder::new(&uri).get().await?;
+        let stats = self.request(&uri).get().await?;
 
         Ok(stats)
     }
 
+    async fn health_check(&self) -> anyhow::Result<bool> {
+        // the stats endpoint is the cheapest route exposed by the service
+        let uri = self.uri("stats");
+        let response = self.http_client.get(uri).send().await?;
+
+        Ok(response.status().is_success())
+    }
+
     async fn root(&self) -> anyhow::Result<String> {
         let uri = self.uri("historical");
-        let root: Value = RequestBuilder::new(&uri).get().await?;
+        let root: Value = self.request(&uri).get().await?;
 
         root.get("root")
             .and_then(Value::as_str)
@@ -268,7 +326,8 @@ impl CoprocessorBaseClient for CoprocessorClient {
         nonce: Option<u64>,
     ) -> anyhow::Result<String> {
         let uri = self.uri("registry/controller");
-        let ret: Value = RequestBuilder::new(&uri)
+        let ret: Value = self
+            .request(&uri)
             .with_args(&json!({
                 "controller": Base64::encode(controller),
                 "circuit": Base64::encode(circuit),
@@ -290,7 +349,8 @@ impl CoprocessorBaseClient for CoprocessorClient {
         circuit: &[u8],
     ) -> anyhow::Result<String> {
         let uri = self.uri("registry/domain");
-        let ret: Value = RequestBuilder::new(&uri)
+        let ret: Value = self
+            .request(&uri)
             .with_args(&json!({
                 "name": domain,
                 "controller": Base64::encode(controller),
@@ -307,10 +367,7 @@ impl CoprocessorBaseClient for CoprocessorClient {
 
     async fn get_storage_raw(&self, circuit: &str) -> anyhow::Result<Option<Vec<u8>>> {
         let uri = self.uri("circuit/storage/raw");
-        let response: Option<String> = RequestBuilder::new(&uri)
-            .with_circuit(circuit)
-            .get()
-            .await?;
+        let response: Option<String> = self.request(&uri).with_circuit(circuit).get().await?;
 
         let response = response.map(Base64::decode).transpose()?;
 
@@ -321,7 +378,7 @@ impl CoprocessorBaseClient for CoprocessorClient {
         let uri = self.uri("circuit/storage/raw");
         let contents = Base64::encode(contents);
 
-        RequestBuilder::new(&uri)
+        self.request(&uri)
             .with_circuit(circuit)
             .with_args(&json!(contents))
             .post::<Value>()
@@ -333,7 +390,8 @@ impl CoprocessorBaseClient for CoprocessorClient {
     async fn get_storage_file(&self, circuit: &str, path: &str) -> anyhow::Result<Option<Vec<u8>>> {
         let uri = self.uri("circuit/storage/fs");
 
-        let response: Option<String> = RequestBuilder::new(&uri)
+        let response: Option<String> = self
+            .request(&uri)
             .with_circuit(circuit)
             .with_args(&json!({
                 "path": path,
@@ -355,7 +413,7 @@ impl CoprocessorBaseClient for CoprocessorClient {
         let uri = self.uri("circuit/storage/fs");
         let contents = Base64::encode(contents);
 
-        RequestBuilder::new(&uri)
+        self.request(&uri)
             .with_circuit(circuit)
             .with_args(&json!({
                 "path": path,
@@ -369,7 +427,8 @@ impl CoprocessorBaseClient for CoprocessorClient {
 
     async fn get_witnesses(&self, circuit: &str, args: &Value) -> anyhow::Result<Witnesses> {
         let uri = self.uri("circuit/witnesses");
-        let witnesses = RequestBuilder::new(&uri)
+        let witnesses = self
+            .request(&uri)
             .with_circuit(circuit)
             .with_args(&json!({
                 "args": args
@@ -382,7 +441,8 @@ impl CoprocessorBaseClient for CoprocessorClient {
 
     async fn prove(&self, circuit: &str, args: &Value) -> anyhow::Result<DomainProof> {
         let uri = "http://prover.timewave.computer:37279/api/latest";
-        let data = reqwest::Client::new()
+        let data = self
+            .http_client
             .get(uri)
             .header("valence-coprocessor-circuit", circuit)
             .send()
@@ -405,10 +465,7 @@ impl CoprocessorBaseClient for CoprocessorClient {
 
     async fn get_vk(&self, circuit: &str) -> anyhow::Result<Vec<u8>> {
         let uri = self.uri("circuit/vk");
-        let data: String = RequestBuilder::new(&uri)
-            .with_circuit(circuit)
-            .get()
-            .await?;
+        let data: String = self.request(&uri).with_circuit(circuit).get().await?;
 
         Base64::decode(data)
     }
@@ -419,27 +476,22 @@ impl CoprocessorBaseClient for CoprocessorClient {
 
     async fn get_circuit(&self, circuit: &str) -> anyhow::Result<Vec<u8>> {
         let uri = self.uri("circuit/bytecode");
-        let data: String = RequestBuilder::new(&uri)
-            .with_circuit(circuit)
-            .get()
-            .await?;
+        let data: String = self.request(&uri).with_circuit(circuit).get().await?;
 
         Base64::decode(data)
     }
 
     async fn get_runtime(&self, circuit: &str) -> anyhow::Result<Vec<u8>> {
         let uri = self.uri("circuit/runtime");
-        let data: String = RequestBuilder::new(&uri)
-            .with_circuit(circuit)
-            .get()
-            .await?;
+        let data: String = self.request(&uri).with_circuit(circuit).get().await?;
 
         Base64::decode(data)
     }
 
     async fn entrypoint(&self, circuit: &str, args: &Value) -> anyhow::Result<Entrypoint> {
         let uri = self.uri("circuit/entrypoint");
-        let data = RequestBuilder::new(&uri)
+        let data = self
+            .request(&uri)
             .with_circuit(circuit)
             .with_args(args)
             .post()
@@ -451,7 +503,7 @@ impl CoprocessorBaseClient for CoprocessorClient {
     async fn get_latest_domain_block(&self, domain: &str) -> anyhow::Result<Value> {
         let uri = format!("registry/domain/{domain}/latest");
         let uri = self.uri(uri);
-        let data = RequestBuilder::new(&uri).get().await?;
+        let data = self.request(&uri).get().await?;
 
         Ok(data)
     }
@@ -459,21 +511,22 @@ impl CoprocessorBaseClient for CoprocessorClient {
     async fn add_domain_block(&self, domain: &str, args: &Value) -> anyhow::Result<Value> {
         let uri = format!("registry/domain/{domain}");
         let uri = self.uri(uri);
-        let data = RequestBuilder::new(&uri).with_args(args).post().await?;
+        let data = self.request(&uri).with_args(args).post().await?;
 
         Ok(data)
     }
 
     async fn provers(&self) -> anyhow::Result<Value> {
         let uri = self.uri("prover");
-        let data = RequestBuilder::new(&uri).get().await?;
+        let data = self.request(&uri).get().await?;
 
         Ok(data)
     }
 
     async fn provers_add(&self, prover: &str) -> anyhow::Result<Value> {
         let uri = self.uri("prover");
-        let data = RequestBuilder::new(&uri)
+        let data = self
+            .request(&uri)
             .with_args(&json!({"address": prover}))
             .post()
             .await?;
@@ -483,7 +536,8 @@ impl CoprocessorBaseClient for CoprocessorClient {
 
     async fn provers_remove(&self, prover: &str) -> anyhow::Result<Value> {
         let uri = self.uri("prover");
-        let data = RequestBuilder::new(&uri)
+        let data = self
+            .request(&uri)
             .with_args(&json!({"address": prover}))
             .delete()
             .await?;
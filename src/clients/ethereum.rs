@@ -1,13 +1,32 @@
 use crate::evm::base_client::EvmBaseClient;
-use crate::evm::request_provider_client::RequestProviderClient;
-
+use crate::evm::chains::{EvmClientConfig, RetryConfig};
+use crate::evm::request_provider_client::{ConnectionConfig, RequestProviderClient};
+
+use alloy::{
+    primitives::{Address, U256},
+    rpc::types::TransactionReceipt,
+    sol,
+};
 use alloy_signer_local::coins_bip39::English;
 use alloy_signer_local::{MnemonicBuilder, PrivateKeySigner};
 use tonic::async_trait;
 
+sol!(
+    #[sol(rpc)]
+    interface IERC721 {
+        function balanceOf(address owner) external view returns (uint256);
+        function ownerOf(uint256 tokenId) external view returns (address);
+        function tokenURI(uint256 tokenId) external view returns (string);
+        function safeTransferFrom(address from, address to, uint256 tokenId) external;
+    }
+);
+
 pub struct EthereumClient {
     rpc_url: String,
     signer: PrivateKeySigner,
+    connection_config: ConnectionConfig,
+    retry_config: Option<RetryConfig>,
+    default_gas_limit: Option<u64>,
 }
 
 impl EthereumClient {
@@ -25,8 +44,90 @@ impl EthereumClient {
         Ok(Self {
             rpc_url: rpc_url.to_string(),
             signer,
+            connection_config: ConnectionConfig::default(),
+            retry_config: None,
+            default_gas_limit: None,
         })
     }
+
+    /// builds a client connected to the rpc endpoint of the config, retrying
+    /// failed calls with its retry policy and sending txs without a gas limit
+    /// with the default gas limit of the config
+    pub fn from_config(
+        config: &EvmClientConfig,
+        mnemonic: &str,
+        mnemonic_derivation_index: Option<u32>,
+    ) -> anyhow::Result<Self> {
+        config.retry.validate()?;
+
+        let mut client = Self::new(&config.rpc_url, mnemonic, mnemonic_derivation_index)?;
+        client.retry_config = Some(config.retry.clone());
+        client.default_gas_limit = Some(config.default_gas_limit);
+        #[cfg(feature = "tls")]
+        if let Some(tls_config) = &config.tls {
+            client = client.with_tls_config(tls_config.clone());
+        }
+
+        Ok(client)
+    }
+
+    /// returns the owner of the erc-721 token
+    pub async fn get_nft_owner(
+        &self,
+        token_address: &Address,
+        token_id: U256,
+    ) -> anyhow::Result<Address> {
+        let client = self.get_request_provider().await?;
+
+        let nft = IERC721::new(*token_address, &client);
+
+        Ok(self.query(nft.ownerOf(token_id)).await?._0)
+    }
+
+    /// returns the number of erc-721 tokens of the collection held by `owner`
+    pub async fn get_nft_balance(
+        &self,
+        token_address: &Address,
+        owner: &Address,
+    ) -> anyhow::Result<U256> {
+        let client = self.get_request_provider().await?;
+
+        let nft = IERC721::new(*token_address, &client);
+
+        Ok(self.query(nft.balanceOf(*owner)).await?._0)
+    }
+
+    /// returns the metadata uri of the erc-721 token
+    pub async fn get_nft_metadata_uri(
+        &self,
+        token_address: &Address,
+        token_id: U256,
+    ) -> anyhow::Result<String> {
+        let client = self.get_request_provider().await?;
+
+        let nft = IERC721::new(*token_address, &client);
+
+        Ok(self.query(nft.tokenURI(token_id)).await?._0)
+    }
+
+    /// transfers the signer's erc-721 token via `safeTransferFrom`, which
+    /// reverts if `to` is a contract that does not accept erc-721 tokens
+    pub async fn transfer_nft(
+        &self,
+        token_address: &Address,
+        to: &Address,
+        token_id: U256,
+    ) -> anyhow::Result<TransactionReceipt> {
+        let client = self.get_request_provider().await?;
+
+        let nft = IERC721::new(*token_address, &client);
+
+        let transfer_tx = nft
+            .safeTransferFrom(self.signer.address(), *to, token_id)
+            .into_transaction_request();
+
+        self.sign_and_send(transfer_tx).await
+    }
 }
 
 #[async_trait]
@@ -41,6 +142,18 @@ impl RequestProviderClient for EthereumClient {
     fn signer(&self) -> PrivateKeySigner {
         self.signer.clone()
     }
+
+    fn connection_config(&self) -> Option<&ConnectionConfig> {
+        Some(&self.connection_config)
+    }
+
+    fn retry_config(&self) -> Option<RetryConfig> {
+        self.retry_config.clone()
+    }
+
+    fn default_gas_limit(&self) -> Option<u64> {
+        self.default_gas_limit
+    }
 }
 
 #[cfg(test)]
@@ -63,6 +176,47 @@ mod tests {
     const TEST_MNEMONIC: &str = "test test test test test test test test test test test junk";
     const TEST_CONTRACT_ADDR: &str = "0x610178dA211FEF7D417bC0e6FeD39F05609AD788";
 
+    #[test]
+    fn erc721_calls_use_standard_selectors() {
+        use alloy::sol_types::SolCall;
+
+        assert_eq!(IERC721::balanceOfCall::SELECTOR, [0x70, 0xa0, 0x82, 0x31]);
+        assert_eq!(IERC721::ownerOfCall::SELECTOR, [0x63, 0x52, 0x21, 0x1e]);
+        assert_eq!(IERC721::tokenURICall::SELECTOR, [0xc8, 0x7b, 0x56, 0xdd]);
+        assert_eq!(
+            IERC721::safeTransferFromCall::SELECTOR,
+            [0x42, 0x84, 0x2e, 0x0e]
+        );
+    }
+
+    #[tokio::test]
+    async fn watch_logs_rejects_zero_poll_interval() {
+        let client = EthereumClient::new(TEST_RPC_URL, TEST_MNEMONIC, None).unwrap();
+
+        let filter = crate::evm::logs::LogFilter {
+            address: None,
+            topics: vec![],
+            from_block: 0,
+            poll_interval: std::time::Duration::ZERO,
+        };
+
+        assert!(client.watch_logs(filter, |_| {}).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn block_subscriptions_reject_zero_poll_interval() {
+        let client = EthereumClient::new(TEST_RPC_URL, TEST_MNEMONIC, None).unwrap();
+
+        assert!(client
+            .subscribe_new_blocks(|_| {}, std::time::Duration::ZERO)
+            .await
+            .is_err());
+        assert!(client
+            .subscribe_new_blocks_with_header(|_| {}, std::time::Duration::ZERO)
+            .await
+            .is_err());
+    }
+
     #[tokio::test]
     #[ignore = "requires local anvil instance"]
     async fn test_eth_latest_block_height() {
@@ -152,6 +306,82 @@ mod tests {
         assert_eq!(pre_balance + U256::from(200), post_balance);
     }
 
+    #[tokio::test]
+    #[ignore = "requires local anvil instance"]
+    async fn test_eth_query_logs() {
+        use alloy::sol_types::SolEvent;
+
+        use crate::evm::logs::LogFilter;
+
+        let client = EthereumClient::new(TEST_RPC_URL, TEST_MNEMONIC, None).unwrap();
+        let provider = client.get_request_provider().await.unwrap();
+        let accounts = provider.get_accounts().await.unwrap();
+
+        let token_tx = MockERC20::deploy_builder(&provider, "Token1".to_string(), "T1".to_string())
+            .into_transaction_request();
+
+        let token_addr = client
+            .execute_tx(token_tx)
+            .await
+            .unwrap()
+            .contract_address
+            .unwrap();
+
+        let token = MockERC20::new(token_addr, provider);
+
+        let mint_tx = token
+            .mint(accounts[0], U256::from(1000))
+            .into_transaction_request();
+        let mint_block = client
+            .execute_tx(mint_tx)
+            .await
+            .unwrap()
+            .block_number
+            .unwrap();
+
+        let filter = LogFilter {
+            address: Some(vec![token_addr]),
+            topics: vec![Some(MockERC20::Transfer::SIGNATURE_HASH)],
+            from_block: mint_block,
+            poll_interval: std::time::Duration::from_secs(1),
+        };
+
+        let logs = client
+            .query_logs(&filter, mint_block, mint_block)
+            .await
+            .unwrap();
+
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].address(), token_addr);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires local anvil instance"]
+    async fn test_eth_subscribe_new_blocks() {
+        let client = EthereumClient::new(TEST_RPC_URL, TEST_MNEMONIC, None).unwrap();
+        let accounts = client.get_provider_accounts().await.unwrap();
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let _handle = client
+            .subscribe_new_blocks_with_header(
+                move |header| tx.send(header.number).unwrap(),
+                std::time::Duration::from_millis(100),
+            )
+            .await
+            .unwrap();
+
+        let current_block = rx.recv().await.unwrap();
+
+        // anvil mines a block for every tx
+        let transfer_request = TransactionRequest::default()
+            .with_to(accounts[1])
+            .with_value(U256::from(1));
+        client.execute_tx(transfer_request).await.unwrap();
+
+        assert!(rx.recv().await.unwrap() > current_block);
+    }
+
     #[tokio::test]
     #[ignore = "requires local anvil instance"]
     async fn test_eth_query_contract_states() {
@@ -173,4 +403,60 @@ mod tests {
         let response = client.query(req).await.unwrap();
         assert_eq!(U256::from(0), response._0);
     }
+
+    #[test]
+    fn config_gas_limit_is_applied_to_txs_without_one() {
+        use crate::evm::chains::{
+            constants::ARBITRUM_DEFAULT_GAS_LIMIT, EvmChain, EvmClientConfig,
+        };
+
+        let config = EvmClientConfig::for_chain(EvmChain::ArbitrumOne);
+        let client = EthereumClient::from_config(&config, TEST_MNEMONIC, None).unwrap();
+
+        let tx = client.with_default_gas_limit(TransactionRequest::default());
+        assert_eq!(tx.gas, Some(ARBITRUM_DEFAULT_GAS_LIMIT));
+
+        let tx =
+            client.with_default_gas_limit(TransactionRequest::default().with_gas_limit(21_000));
+        assert_eq!(tx.gas, Some(21_000));
+
+        let client = EthereumClient::new(TEST_RPC_URL, TEST_MNEMONIC, None).unwrap();
+        assert_eq!(
+            client
+                .with_default_gas_limit(TransactionRequest::default())
+                .gas,
+            None
+        );
+    }
+
+    #[cfg(feature = "tls")]
+    #[tokio::test]
+    async fn config_tls_is_used_for_the_provider() {
+        use crate::{
+            common::tls::TlsConfig,
+            evm::chains::{EvmChain, EvmClientConfig},
+        };
+
+        let tls_config = TlsConfig {
+            client_cert: Some(b"not a certificate".to_vec()),
+            client_key: Some(b"not a key".to_vec()),
+            ..Default::default()
+        };
+
+        let config = EvmClientConfig::for_chain(EvmChain::Sepolia).with_rpc_url(TEST_RPC_URL);
+
+        let client = EthereumClient::from_config(
+            &config.clone().with_tls(tls_config.clone()),
+            TEST_MNEMONIC,
+            None,
+        )
+        .unwrap();
+        assert_eq!(client.tls_config(), Some(tls_config));
+
+        // building the provider parses the identity without sending a request
+        assert!(client.get_request_provider().await.is_err());
+
+        let client = EthereumClient::from_config(&config, TEST_MNEMONIC, None).unwrap();
+        assert!(client.get_request_provider().await.is_ok());
+    }
 }
@@ -0,0 +1,196 @@
+use std::str::FromStr;
+
+use alloy::{
+    primitives::{keccak256, Address, B256},
+    providers::Provider,
+};
+use cosmrs::AccountId;
+use tonic::async_trait;
+
+use crate::{
+    clients::ethereum::EthereumClient,
+    cosmos::{
+        base_client::BaseClient,
+        grpc_client::{GrpcChannelCache, GrpcSigningClient},
+    },
+    evm::request_provider_client::RequestProviderClient,
+};
+
+const CHAIN_PREFIX: &str = "evmos";
+const CHAIN_DENOM: &str = "aevmos";
+
+/// endpoints of the two interfaces exposed by an evmos node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvmosClientConfig {
+    /// cosmos-sdk grpc endpoint, including the port
+    pub cosmos_grpc: String,
+    /// evm json-rpc endpoint
+    pub evm_rpc: String,
+    /// cosmos chain id, e.g. `evmos_9001-2`
+    pub chain_id_str: String,
+}
+
+/// state of an account as seen by the evm
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvmosAccount {
+    pub address: Address,
+    pub nonce: u64,
+    /// keccak256 of the account code, the hash of empty code for
+    /// externally owned accounts
+    pub code_hash: B256,
+}
+
+/// client for interacting with the evmos chain through both its cosmos-sdk
+/// and evm interfaces.
+///
+/// evmos accounts use `eth_secp256k1` keys, which the cosmos signing client
+/// does not support, so txs should be sent through the evm side (`evm()`).
+pub struct EvmosClient {
+    grpc_url: String,
+    mnemonic: String,
+    chain_id: String,
+    chain_denom: String,
+    chain_prefix: String,
+    gas_price: f64,
+    channel_cache: GrpcChannelCache,
+    evm: EthereumClient,
+}
+
+impl EvmosClient {
+    pub async fn new(config: EvmosClientConfig, mnemonic: &str) -> anyhow::Result<Self> {
+        let avg_gas_price = Self::query_chain_gas_config("evmos", CHAIN_DENOM).await?;
+
+        let evm = EthereumClient::new(&config.evm_rpc, mnemonic, None)?;
+
+        Ok(Self {
+            grpc_url: config.cosmos_grpc,
+            mnemonic: mnemonic.to_string(),
+            chain_id: config.chain_id_str,
+            chain_denom: CHAIN_DENOM.to_string(),
+            chain_prefix: CHAIN_PREFIX.to_string(),
+            gas_price: avg_gas_price,
+            channel_cache: GrpcChannelCache::default(),
+            evm,
+        })
+    }
+
+    /// connects to both interfaces of the node with the given tls material
+    #[cfg(feature = "tls")]
+    pub fn with_tls_config(mut self, tls_config: crate::common::tls::TlsConfig) -> Self {
+        self.evm = self.evm.with_tls_config(tls_config.clone());
+        GrpcSigningClient::with_tls_config(self, tls_config)
+    }
+
+    /// evm json-rpc interface of the chain
+    pub fn evm(&self) -> &EthereumClient {
+        &self.evm
+    }
+
+    /// converts an `evmos1..` address into its `0x..` representation
+    pub fn cosmos_to_hex_address(cosmos_addr: &str) -> anyhow::Result<Address> {
+        let account_id = AccountId::from_str(cosmos_addr)
+            .map_err(|e| anyhow::anyhow!("Failed to parse address: {e}"))?;
+
+        anyhow::ensure!(
+            account_id.prefix() == CHAIN_PREFIX,
+            "not an evmos address: {cosmos_addr}"
+        );
+
+        let bytes = account_id.to_bytes();
+
+        anyhow::ensure!(
+            bytes.len() == Address::len_bytes(),
+            "evmos address {cosmos_addr} is not 20 bytes long"
+        );
+
+        Ok(Address::from_slice(&bytes))
+    }
+
+    /// converts a `0x..` address into its `evmos1..` representation
+    pub fn hex_to_cosmos_address(evm_addr: &Address) -> anyhow::Result<String> {
+        let account_id = AccountId::new(CHAIN_PREFIX, evm_addr.as_slice())
+            .map_err(|e| anyhow::anyhow!("Failed to encode address: {e}"))?;
+
+        Ok(account_id.to_string())
+    }
+
+    /// returns the nonce and code hash of the account via the evm interface
+    pub async fn query_evm_account(&self, address: &Address) -> anyhow::Result<EvmosAccount> {
+        let client = self.evm.get_request_provider().await?;
+
+        let nonce = client.get_transaction_count(*address).await?;
+        let code = client.get_code_at(*address).await?;
+
+        Ok(EvmosAccount {
+            address: *address,
+            nonce,
+            code_hash: keccak256(&code),
+        })
+    }
+}
+
+#[async_trait]
+impl BaseClient for EvmosClient {}
+
+#[async_trait]
+impl GrpcSigningClient for EvmosClient {
+    fn grpc_url(&self) -> String {
+        self.grpc_url.to_string()
+    }
+
+    fn mnemonic(&self) -> String {
+        self.mnemonic.to_string()
+    }
+
+    fn chain_prefix(&self) -> String {
+        self.chain_prefix.to_string()
+    }
+
+    fn chain_id(&self) -> String {
+        self.chain_id.to_string()
+    }
+
+    fn chain_denom(&self) -> String {
+        self.chain_denom.to_string()
+    }
+
+    fn gas_price(&self) -> f64 {
+        self.gas_price
+    }
+
+    fn set_gas_price(&mut self, gas_price: f64) -> anyhow::Result<()> {
+        self.gas_price = gas_price;
+        Ok(())
+    }
+
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        Some(&self.channel_cache)
+    }
+
+    fn gas_adjustment(&self) -> f64 {
+        1.8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addresses_convert_between_bech32_and_hex() {
+        let evm_addr = Address::from_str("0x610178dA211FEF7D417bC0e6FeD39F05609AD788").unwrap();
+
+        let cosmos_addr = EvmosClient::hex_to_cosmos_address(&evm_addr).unwrap();
+        assert!(cosmos_addr.starts_with("evmos1"));
+
+        assert_eq!(
+            EvmosClient::cosmos_to_hex_address(&cosmos_addr).unwrap(),
+            evm_addr
+        );
+
+        let cosmos_hub_addr = AccountId::new("cosmos", evm_addr.as_slice())
+            .unwrap()
+            .to_string();
+        assert!(EvmosClient::cosmos_to_hex_address(&cosmos_hub_addr).is_err());
+    }
+}
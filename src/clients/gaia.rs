@@ -1,6 +1,14 @@
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::Any;
 use tonic::async_trait;
 
-use crate::cosmos::{base_client::BaseClient, grpc_client::GrpcSigningClient};
+use crate::{
+    common::transaction::TransactionResponse,
+    cosmos::{
+        base_client::BaseClient,
+        grpc_client::{GrpcChannelCache, GrpcSigningClient},
+    },
+};
 
 const CHAIN_PREFIX: &str = "cosmos";
 const CHAIN_DENOM: &str = "uatom";
@@ -13,6 +21,7 @@ pub struct CosmosHubClient {
     chain_denom: String,
     chain_prefix: String,
     gas_price: f64,
+    channel_cache: GrpcChannelCache,
 }
 
 impl CosmosHubClient {
@@ -32,8 +41,74 @@ impl CosmosHubClient {
             chain_denom: chain_denom.to_string(),
             chain_prefix: CHAIN_PREFIX.to_string(),
             gas_price: avg_gas_price,
+            channel_cache: GrpcChannelCache::default(),
         })
     }
+
+    /// tokenizes `amount` of the signer's delegation to the validator into
+    /// liquid staking share tokens owned by `tokenized_share_recipient`
+    pub async fn lsm_tokenize_share(
+        &self,
+        validator_address: &str,
+        amount: u128,
+        tokenized_share_recipient: &str,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let tokenize_msg = MsgTokenizeShares {
+            delegator_address: signing_client.address.to_string(),
+            validator_address: validator_address.to_string(),
+            amount: Some(ProtoCoin {
+                denom: self.chain_denom.to_string(),
+                amount: amount.to_string(),
+            }),
+            tokenized_share_owner: tokenized_share_recipient.to_string(),
+        };
+
+        self.estimate_and_broadcast(Any::from_msg(&tokenize_msg)?, memo)
+            .await
+    }
+
+    /// redeems liquid staking share tokens back into a delegation
+    pub async fn lsm_redeem_tokens(
+        &self,
+        amount: u128,
+        denom: &str,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let redeem_msg = MsgRedeemTokensForShares {
+            delegator_address: signing_client.address.to_string(),
+            amount: Some(ProtoCoin {
+                denom: denom.to_string(),
+                amount: amount.to_string(),
+            }),
+        };
+
+        self.estimate_and_broadcast(Any::from_msg(&redeem_msg)?, memo)
+            .await
+    }
+
+    /// returns the record backing a liquid staking share token denom
+    pub async fn query_tokenize_share_record_by_denom(
+        &self,
+        denom: &str,
+    ) -> anyhow::Result<TokenizeShareRecord> {
+        let response: QueryTokenizeShareRecordByDenomResponse = self
+            .query_grpc(
+                "/gaia.liquid.v1beta1.Query/TokenizeShareRecordByDenom",
+                QueryTokenizeShareRecordByDenomRequest {
+                    denom: denom.to_string(),
+                },
+            )
+            .await?;
+
+        response
+            .record
+            .ok_or_else(|| anyhow::anyhow!("no tokenize share record found for {denom}"))
+    }
 }
 
 /// gaia is a base cosmos chain
@@ -66,7 +141,131 @@ impl GrpcSigningClient for CosmosHubClient {
         self.gas_price
     }
 
+    fn set_gas_price(&mut self, gas_price: f64) -> anyhow::Result<()> {
+        self.gas_price = gas_price;
+        Ok(())
+    }
+
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        Some(&self.channel_cache)
+    }
+
     fn gas_adjustment(&self) -> f64 {
         1.8
     }
 }
+
+// Proto definitions to interact with the gaia liquid staking module
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgTokenizeShares {
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub validator_address: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub amount: ::core::option::Option<ProtoCoin>,
+    #[prost(string, tag = "4")]
+    pub tokenized_share_owner: ::prost::alloc::string::String,
+}
+
+impl ::prost::Name for MsgTokenizeShares {
+    const NAME: &'static str = "MsgTokenizeShares";
+    const PACKAGE: &'static str = "gaia.liquid.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.liquid.v1beta1.MsgTokenizeShares".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.liquid.v1beta1.MsgTokenizeShares".into()
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgRedeemTokensForShares {
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<ProtoCoin>,
+}
+
+impl ::prost::Name for MsgRedeemTokensForShares {
+    const NAME: &'static str = "MsgRedeemTokensForShares";
+    const PACKAGE: &'static str = "gaia.liquid.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.liquid.v1beta1.MsgRedeemTokensForShares".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.liquid.v1beta1.MsgRedeemTokensForShares".into()
+    }
+}
+
+/// record of tokenized validator shares. the share token denom is
+/// `{validator}/{id}`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenizeShareRecord {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(string, tag = "2")]
+    pub owner: ::prost::alloc::string::String,
+    /// module account holding the delegation backing the share tokens
+    #[prost(string, tag = "3")]
+    pub module_account: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub validator: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryTokenizeShareRecordByDenomRequest {
+    #[prost(string, tag = "1")]
+    pub denom: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryTokenizeShareRecordByDenomResponse {
+    #[prost(message, optional, tag = "1")]
+    pub record: ::core::option::Option<TokenizeShareRecord>,
+}
+
+#[cfg(all(test, feature = "tls"))]
+mod tests {
+    use crate::common::tls::TlsConfig;
+
+    use super::*;
+
+    fn client() -> CosmosHubClient {
+        CosmosHubClient {
+            grpc_url: "https://127.0.0.1:1".to_string(),
+            mnemonic: String::new(),
+            chain_id: "cosmoshub-4".to_string(),
+            chain_denom: CHAIN_DENOM.to_string(),
+            chain_prefix: CHAIN_PREFIX.to_string(),
+            gas_price: 0.005,
+            channel_cache: GrpcChannelCache::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn tls_config_is_used_for_the_grpc_channel() {
+        let tls_config = TlsConfig {
+            client_cert: Some(b"not a certificate".to_vec()),
+            client_key: Some(b"not a key".to_vec()),
+            ..Default::default()
+        };
+
+        let client = client().with_tls_config(tls_config.clone());
+        assert_eq!(client.tls_config(), Some(tls_config));
+
+        // the invalid identity is rejected while configuring the channel,
+        // before any connection is attempted
+        let error = client.connect_grpc_channel().await.unwrap_err();
+        assert!(format!("{error:#}").contains("TLS private key"));
+
+        let error = self::client().connect_grpc_channel().await.unwrap_err();
+        assert!(!format!("{error:#}").contains("TLS private key"));
+    }
+}
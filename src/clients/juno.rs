@@ -0,0 +1,132 @@
+use serde::Serialize;
+use serde_json::json;
+use tonic::async_trait;
+
+use crate::{
+    common::transaction::TransactionResponse,
+    cosmos::{
+        base_client::BaseClient,
+        grpc_client::{GrpcChannelCache, GrpcSigningClient},
+        wasm_client::WasmClient,
+    },
+};
+
+const CHAIN_NAME: &str = "juno";
+const CHAIN_PREFIX: &str = "juno";
+const CHAIN_DENOM: &str = "ujuno";
+
+/// client for interacting with the juno chain
+pub struct JunoClient {
+    grpc_url: String,
+    mnemonic: String,
+    chain_id: String,
+    chain_denom: String,
+    gas_price: f64,
+    channel_cache: GrpcChannelCache,
+}
+
+impl JunoClient {
+    pub async fn new(
+        rpc_url: &str,
+        rpc_port: &str,
+        mnemonic: &str,
+        chain_id: &str,
+    ) -> anyhow::Result<Self> {
+        let avg_gas_price = Self::query_chain_gas_config(CHAIN_NAME, CHAIN_DENOM).await?;
+
+        Ok(Self {
+            grpc_url: format!("{rpc_url}:{rpc_port}"),
+            mnemonic: mnemonic.to_string(),
+            chain_id: chain_id.to_string(),
+            chain_denom: CHAIN_DENOM.to_string(),
+            gas_price: avg_gas_price,
+            channel_cache: GrpcChannelCache::default(),
+        })
+    }
+
+    /// transfers funds over ibc to a juno contract and executes `msg` on it
+    /// through the ibc-hooks middleware once the packet is received.
+    ///
+    /// ibc-hooks requires the transfer receiver to be the contract that is
+    /// executed, so `receiver` must match `contract`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn juno_ibc_wasm_hook_transfer(
+        &self,
+        channel: &str,
+        receiver: &str,
+        contract: &str,
+        msg: &(impl Serialize + Sync),
+        amount: u128,
+        denom: &str,
+        timeout_seconds: u64,
+    ) -> anyhow::Result<TransactionResponse> {
+        if receiver != contract {
+            return Err(anyhow::anyhow!(
+                "ibc-hooks receiver must be the executed contract ({contract}), got: {receiver}"
+            ));
+        }
+
+        let memo = json!({
+            "wasm": {
+                "contract": contract,
+                "msg": msg,
+            }
+        });
+
+        self.ibc_transfer(
+            receiver.to_string(),
+            denom.to_string(),
+            amount.to_string(),
+            channel.to_string(),
+            timeout_seconds,
+            Some(memo.to_string()),
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl BaseClient for JunoClient {}
+
+#[async_trait]
+impl WasmClient for JunoClient {}
+
+#[async_trait]
+impl GrpcSigningClient for JunoClient {
+    fn grpc_url(&self) -> String {
+        self.grpc_url.to_string()
+    }
+
+    fn mnemonic(&self) -> String {
+        self.mnemonic.to_string()
+    }
+
+    fn chain_prefix(&self) -> String {
+        CHAIN_PREFIX.to_string()
+    }
+
+    fn chain_id(&self) -> String {
+        self.chain_id.to_string()
+    }
+
+    fn chain_denom(&self) -> String {
+        self.chain_denom.to_string()
+    }
+
+    fn gas_price(&self) -> f64 {
+        self.gas_price
+    }
+
+    fn set_gas_price(&mut self, gas_price: f64) -> anyhow::Result<()> {
+        self.gas_price = gas_price;
+        Ok(())
+    }
+
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        Some(&self.channel_cache)
+    }
+
+    fn gas_adjustment(&self) -> f64 {
+        1.8
+    }
+}
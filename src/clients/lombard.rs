@@ -1,7 +1,9 @@
 use tonic::async_trait;
 
 use crate::cosmos::{
-    base_client::BaseClient, grpc_client::GrpcSigningClient, wasm_client::WasmClient,
+    base_client::BaseClient,
+    grpc_client::{GrpcChannelCache, GrpcSigningClient},
+    wasm_client::WasmClient,
 };
 
 const CHAIN_NAME: &str = "lombardledger";
@@ -14,6 +16,7 @@ pub struct LombardClient {
     chain_id: String,
     chain_denom: String,
     gas_price: f64,
+    channel_cache: GrpcChannelCache,
 }
 
 impl LombardClient {
@@ -31,6 +34,7 @@ impl LombardClient {
             chain_id: chain_id.to_string(),
             chain_denom: CHAIN_DENOM.to_string(),
             gas_price: avg_gas_price,
+            channel_cache: GrpcChannelCache::default(),
         })
     }
 }
@@ -68,6 +72,15 @@ impl GrpcSigningClient for LombardClient {
         self.gas_price
     }
 
+    fn set_gas_price(&mut self, gas_price: f64) -> anyhow::Result<()> {
+        self.gas_price = gas_price;
+        Ok(())
+    }
+
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        Some(&self.channel_cache)
+    }
+
     fn gas_adjustment(&self) -> f64 {
         1.8
     }
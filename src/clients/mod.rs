@@ -1,24 +1,37 @@
+#[cfg(feature = "arbitrum")]
+pub mod arbitrum;
+#[cfg(feature = "avalanche")]
+pub mod avalanche;
 #[cfg(feature = "cosmos")]
 pub mod babylon;
 #[cfg(feature = "coprocessor")]
 pub mod coprocessor;
 #[cfg(feature = "evm")]
 pub mod ethereum;
+#[cfg(feature = "evmos")]
+pub mod evmos;
 #[cfg(feature = "cosmos")]
 pub mod gaia;
 #[cfg(feature = "cosmos")]
 pub mod ibc_eureka_route_client;
+#[cfg(feature = "juno")]
+pub mod juno;
 #[cfg(feature = "cosmos")]
 pub mod lombard;
 #[cfg(feature = "cosmos")]
 pub mod neutron;
 #[cfg(feature = "cosmos")]
 pub mod noble;
+#[cfg(feature = "optimism")]
+pub mod optimism;
 #[cfg(feature = "cosmos")]
 pub mod osmosis;
+#[cfg(feature = "polygon")]
+pub mod polygon;
+#[cfg(feature = "cosmos")]
+pub mod stargaze;
 #[cfg(feature = "indexer")]
 pub mod valence_indexer;
 
-// TODO fix
-//#[cfg(feature = "solana")]
-//pub mod solana;
+#[cfg(feature = "solana")]
+pub mod solana;
@@ -3,12 +3,22 @@ use std::str::FromStr;
 use crate::{
     common::transaction::TransactionResponse,
     cosmos::{
-        base_client::BaseClient, grpc_client::GrpcSigningClient, proto_timestamp::ProtoTimestamp,
-        wasm_client::WasmClient, CosmosServiceClient,
+        base_client::BaseClient,
+        errors::CosmosError,
+        grpc_client::{GrpcChannelCache, GrpcSigningClient},
+        proto_timestamp::ProtoTimestamp,
+        types::{proto_coins_to_coins, IbcFeeParams},
+        wasm_client::WasmClient,
+        CosmosServiceClient,
     },
 };
 use async_trait::async_trait;
+use cosmos_sdk_proto::tendermint::google::protobuf::Timestamp;
 use cosmrs::Denom;
+use ibc_proto::ibc::{
+    applications::fee::v1::Fee,
+    core::connection::v1::{QueryConnectionRequest, QueryConnectionResponse, State},
+};
 
 const CHAIN_PREFIX: &str = "neutron";
 const CHAIN_DENOM: &str = "untrn";
@@ -19,6 +29,7 @@ pub struct NeutronClient {
     chain_id: String,
     chain_denom: String,
     gas_price: f64,
+    channel_cache: GrpcChannelCache,
 }
 
 impl NeutronClient {
@@ -36,6 +47,7 @@ impl NeutronClient {
             chain_id: chain_id.to_string(),
             chain_denom: CHAIN_DENOM.to_string(),
             gas_price: avg_gas_price,
+            channel_cache: GrpcChannelCache::default(),
         })
     }
 }
@@ -81,7 +93,11 @@ impl NeutronClient {
 
         // Broadcast the transaction
         let mut grpc_client = CosmosServiceClient::new(channel);
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         TransactionResponse::try_from(broadcast_tx_response.tx_response)
     }
@@ -130,7 +146,11 @@ impl NeutronClient {
 
         // Broadcast the transaction
         let mut grpc_client = CosmosServiceClient::new(channel);
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         TransactionResponse::try_from(broadcast_tx_response.tx_response)
     }
@@ -192,12 +212,155 @@ impl BaseClient for NeutronClient {
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         TransactionResponse::try_from(broadcast_tx_response.tx_response)
     }
 }
 
+impl NeutronClient {
+    /// returns the latest oracle price of the `base/quote` currency pair
+    pub async fn query_oracle_price(&self, base: &str, quote: &str) -> anyhow::Result<OraclePrice> {
+        let response: GetPriceResponse = self
+            .query_grpc(
+                "/slinky.oracle.v1.Query/GetPrice",
+                GetPriceRequest {
+                    currency_pair: Some(CurrencyPair {
+                        base: base.to_string(),
+                        quote: quote.to_string(),
+                    }),
+                },
+            )
+            .await?;
+
+        OraclePrice::try_from(response)
+    }
+
+    /// returns the latest prices of every currency pair tracked by the oracle
+    pub async fn query_oracle_all_prices(&self) -> anyhow::Result<Vec<OraclePriceEntry>> {
+        let pairs_response: GetAllCurrencyPairsResponse = self
+            .query_grpc(
+                "/slinky.oracle.v1.Query/GetAllCurrencyPairs",
+                GetAllCurrencyPairsRequest {},
+            )
+            .await?;
+
+        let currency_pair_ids: Vec<String> = pairs_response
+            .currency_pairs
+            .iter()
+            .map(|pair| format!("{}/{}", pair.base, pair.quote))
+            .collect();
+
+        let prices_response: GetPricesResponse = self
+            .query_grpc(
+                "/slinky.oracle.v1.Query/GetPrices",
+                GetPricesRequest {
+                    currency_pair_ids: currency_pair_ids.clone(),
+                },
+            )
+            .await?;
+
+        // prices are returned in the order of the requested ids
+        currency_pair_ids
+            .into_iter()
+            .zip(prices_response.prices)
+            .map(|(denom, price)| {
+                Ok(OraclePriceEntry {
+                    denom,
+                    price: OraclePrice::try_from(price)?,
+                })
+            })
+            .collect()
+    }
+
+    /// returns the minimum fees to attach to interchain txs sent over the
+    /// connection, as required by neutron's `feerefunder` module to pay the
+    /// relayers of the ica packets. the fees are the same for every
+    /// connection, which is only checked to be open.
+    pub async fn query_interchain_gas_prices(
+        &self,
+        connection_id: &str,
+    ) -> anyhow::Result<IbcFeeParams> {
+        let connection_response: QueryConnectionResponse = self
+            .query_grpc(
+                "/ibc.core.connection.v1.Query/Connection",
+                QueryConnectionRequest {
+                    connection_id: connection_id.to_string(),
+                },
+            )
+            .await?;
+
+        let connection_state = connection_response
+            .connection
+            .map(|connection| connection.state())
+            .ok_or_else(|| anyhow::anyhow!("connection {connection_id} not found"))?;
+
+        anyhow::ensure!(
+            connection_state == State::Open,
+            "connection {connection_id} is not open"
+        );
+
+        let params_response: FeeRefunderParamsResponse = self
+            .query_grpc(
+                "/neutron.feerefunder.Query/Params",
+                FeeRefunderParamsRequest {},
+            )
+            .await?;
+
+        let min_fee = params_response
+            .params
+            .and_then(|params| params.min_fee)
+            .ok_or_else(|| anyhow::anyhow!("No feerefunder min fee returned"))?;
+
+        min_fee_to_fee_params(&min_fee)
+    }
+}
+
+/// oracle price of a currency pair
+#[derive(Debug, Clone, PartialEq)]
+pub struct OraclePrice {
+    pub price: f64,
+    /// unix timestamp (nanoseconds) of the block the price was updated in
+    pub block_time_ns: u64,
+    pub block_height: u64,
+}
+
+impl TryFrom<GetPriceResponse> for OraclePrice {
+    type Error = anyhow::Error;
+
+    fn try_from(value: GetPriceResponse) -> anyhow::Result<Self> {
+        let quote_price = value
+            .price
+            .ok_or_else(|| anyhow::anyhow!("no price returned for currency pair {}", value.id))?;
+
+        // prices are integers scaled by the decimals of the currency pair
+        let price = quote_price.price.parse::<f64>()? / 10f64.powi(value.decimals as i32);
+
+        let block_time_ns = quote_price
+            .block_timestamp
+            .map(|t| u64::try_from(t.seconds * 1_000_000_000 + t.nanos as i64))
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(OraclePrice {
+            price,
+            block_time_ns,
+            block_height: quote_price.block_height,
+        })
+    }
+}
+
+/// oracle price along with its `base/quote` currency pair id
+#[derive(Debug, Clone, PartialEq)]
+pub struct OraclePriceEntry {
+    pub denom: String,
+    pub price: OraclePrice,
+}
+
 #[async_trait]
 impl WasmClient for NeutronClient {}
 
@@ -227,11 +390,118 @@ impl GrpcSigningClient for NeutronClient {
         self.gas_price
     }
 
+    fn set_gas_price(&mut self, gas_price: f64) -> anyhow::Result<()> {
+        self.gas_price = gas_price;
+        Ok(())
+    }
+
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        Some(&self.channel_cache)
+    }
+
     fn gas_adjustment(&self) -> f64 {
         1.8
     }
 }
 
+// Proto definitions to interact with the slinky oracle module. neutron-std
+// ships these types but is built against an incompatible prost version.
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CurrencyPair {
+    #[prost(string, tag = "1")]
+    pub base: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub quote: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuotePrice {
+    #[prost(string, tag = "1")]
+    pub price: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub block_timestamp: ::core::option::Option<Timestamp>,
+    #[prost(uint64, tag = "3")]
+    pub block_height: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPriceRequest {
+    #[prost(message, optional, tag = "1")]
+    pub currency_pair: ::core::option::Option<CurrencyPair>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPriceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub price: ::core::option::Option<QuotePrice>,
+    #[prost(uint64, tag = "2")]
+    pub nonce: u64,
+    #[prost(uint64, tag = "3")]
+    pub decimals: u64,
+    #[prost(uint64, tag = "4")]
+    pub id: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAllCurrencyPairsRequest {}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAllCurrencyPairsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub currency_pairs: ::prost::alloc::vec::Vec<CurrencyPair>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPricesRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub currency_pair_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPricesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub prices: ::prost::alloc::vec::Vec<GetPriceResponse>,
+}
+
+/// converts the `feerefunder` min fee into the fees to attach to a packet
+fn min_fee_to_fee_params(min_fee: &Fee) -> anyhow::Result<IbcFeeParams> {
+    Ok(IbcFeeParams {
+        recv_fee: proto_coins_to_coins(&min_fee.recv_fee)?,
+        ack_fee: proto_coins_to_coins(&min_fee.ack_fee)?,
+        timeout_fee: proto_coins_to_coins(&min_fee.timeout_fee)?,
+    })
+}
+
+// Proto definitions of the feerefunder params query. its min fee has the
+// same layout as the ics-29 fee.
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeRefunderParams {
+    #[prost(message, optional, tag = "1")]
+    pub min_fee: ::core::option::Option<Fee>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeRefunderParamsRequest {}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeRefunderParamsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub params: ::core::option::Option<FeeRefunderParams>,
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::{Duration, SystemTime};
@@ -348,6 +618,33 @@ mod tests {
         assert_eq!(state.state, State::Active);
     }
 
+    #[tokio::test]
+    #[ignore = "requires local neutron grpc node active"]
+    async fn test_query_raw_contract_state() {
+        let client = NeutronClient::new(
+            LOCAL_GRPC_URL,
+            LOCAL_GRPC_PORT,
+            LOCAL_MNEMONIC,
+            LOCAL_CHAIN_ID,
+        )
+        .await
+        .unwrap();
+
+        let smart_response = client
+            .query_smart_contract(LOCAL_PROCESSOR_ADDR, br#"{"config":{}}"#)
+            .await
+            .unwrap();
+        assert!(!smart_response.is_empty());
+
+        // cw2 contract info is stored by every valence contract
+        let contract_info = client
+            .query_raw_contract_state(LOCAL_PROCESSOR_ADDR, b"contract_info")
+            .await
+            .unwrap();
+        let contract_info: serde_json::Value = serde_json::from_slice(&contract_info).unwrap();
+        assert!(contract_info["contract"].is_string());
+    }
+
     #[tokio::test]
     #[ignore = "requires local neutron grpc node active"]
     async fn test_transfer() {
@@ -380,6 +677,155 @@ mod tests {
         assert_eq!(pre_transfer_balance + 100_000, post_transfer_balance);
     }
 
+    #[test]
+    fn oracle_price_is_scaled_by_pair_decimals() {
+        let price = OraclePrice::try_from(GetPriceResponse {
+            price: Some(QuotePrice {
+                price: "6512345".to_string(),
+                block_timestamp: Some(Timestamp {
+                    seconds: 1_700_000_000,
+                    nanos: 5,
+                }),
+                block_height: 42,
+            }),
+            nonce: 1,
+            decimals: 6,
+            id: 0,
+        })
+        .unwrap();
+
+        assert_eq!(price.price, 6.512345);
+        assert_eq!(price.block_time_ns, 1_700_000_000_000_000_005);
+        assert_eq!(price.block_height, 42);
+    }
+
+    #[test]
+    fn feerefunder_min_fee_converts_to_fee_params() {
+        use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+
+        let coin = |amount: &str| ProtoCoin {
+            denom: CHAIN_DENOM.to_string(),
+            amount: amount.to_string(),
+        };
+
+        let fee_params = min_fee_to_fee_params(&Fee {
+            recv_fee: vec![],
+            ack_fee: vec![coin("1000")],
+            timeout_fee: vec![coin("2000")],
+        })
+        .unwrap();
+
+        assert!(fee_params.recv_fee.is_empty());
+        assert_eq!(fee_params.ack_fee[0].amount, 1000);
+        assert_eq!(fee_params.timeout_fee[0].amount, 2000);
+        assert_eq!(fee_params.timeout_fee[0].denom.to_string(), CHAIN_DENOM);
+
+        assert!(min_fee_to_fee_params(&Fee {
+            ack_fee: vec![coin("not a number")],
+            ..Default::default()
+        })
+        .is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires local neutron grpc node active"]
+    async fn test_simulate_multi_msg() {
+        use cosmrs::{bank::MsgSend, tx::Msg, Coin};
+
+        let client = NeutronClient::new(
+            LOCAL_GRPC_URL,
+            LOCAL_GRPC_PORT,
+            LOCAL_MNEMONIC,
+            LOCAL_CHAIN_ID,
+        )
+        .await
+        .unwrap();
+
+        let signing_client = client.get_signing_client().await.unwrap();
+
+        let send_msg = MsgSend {
+            from_address: signing_client.address.clone(),
+            to_address: LOCAL_ALT_ADDR.parse().unwrap(),
+            amount: vec![Coin {
+                denom: CHAIN_DENOM.parse().unwrap(),
+                amount: 1,
+            }],
+        }
+        .to_any()
+        .unwrap();
+
+        let single_sim = client.simulate_tx(send_msg.clone()).await.unwrap();
+        let batch_sim = client
+            .simulate_multi_msg(vec![send_msg.clone(), send_msg.clone()])
+            .await
+            .unwrap();
+
+        let single_gas = single_sim.gas_info.unwrap().gas_used;
+        let batch_gas = batch_sim.gas_info.unwrap().gas_used;
+        assert!(batch_gas > single_gas);
+        assert!(batch_gas < 2 * single_gas);
+
+        let fee = client
+            .estimate_fee_for_multi_msg(vec![send_msg.clone(), send_msg])
+            .await
+            .unwrap();
+        assert!(fee.gas_limit >= batch_gas);
+
+        // simulations must not consume the account sequence
+        let post_sim_signing_client = client.get_signing_client().await.unwrap();
+        assert_eq!(signing_client.sequence, post_sim_signing_client.sequence);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires local neutron grpc node active"]
+    async fn test_transaction_builder() {
+        use crate::cosmos::transaction_builder::TransactionBuilder;
+        use cosmrs::{bank::MsgSend, tx::Msg, Coin};
+
+        let client = NeutronClient::new(
+            LOCAL_GRPC_URL,
+            LOCAL_GRPC_PORT,
+            LOCAL_MNEMONIC,
+            LOCAL_CHAIN_ID,
+        )
+        .await
+        .unwrap();
+
+        let signing_client = client.get_signing_client().await.unwrap();
+
+        let send_msg = MsgSend {
+            from_address: signing_client.address.clone(),
+            to_address: LOCAL_ALT_ADDR.parse().unwrap(),
+            amount: vec![Coin {
+                denom: CHAIN_DENOM.parse().unwrap(),
+                amount: 1,
+            }],
+        }
+        .to_any()
+        .unwrap();
+
+        let pre_balance = client
+            .query_balance(LOCAL_ALT_ADDR, CHAIN_DENOM)
+            .await
+            .unwrap();
+
+        let tx_response = TransactionBuilder::new(&client)
+            .add_msg(send_msg.clone())
+            .add_msg(send_msg)
+            .with_memo("builder")
+            .with_fee_multiplier(1.2)
+            .execute()
+            .await
+            .unwrap();
+        assert!(tx_response.success);
+
+        let post_balance = client
+            .query_balance(LOCAL_ALT_ADDR, CHAIN_DENOM)
+            .await
+            .unwrap();
+        assert_eq!(pre_balance + 2, post_balance);
+    }
+
     #[tokio::test]
     #[ignore = "requires local neutron grpc node active"]
     async fn test_execute_wasm() {
@@ -594,4 +1040,61 @@ mod tests {
         // assert that the second transfer worked
         assert_eq!(osmo_balance_0, osmo_balance_2);
     }
+
+    #[tokio::test]
+    #[ignore = "requires local neutron & osmosis grpc nodes active"]
+    async fn test_simulate_ibc_transfer() {
+        let client = NeutronClient::new(
+            LOCAL_GRPC_URL,
+            LOCAL_GRPC_PORT,
+            LOCAL_MNEMONIC,
+            LOCAL_CHAIN_ID,
+        )
+        .await
+        .unwrap();
+
+        let osmosis_client =
+            OsmosisClient::new(LOCAL_GRPC_URL, "45355", LOCAL_MNEMONIC, "localosmosis-1")
+                .await
+                .unwrap();
+
+        let osmo_admin_addr = osmosis_client
+            .get_signing_client()
+            .await
+            .unwrap()
+            .address
+            .to_string();
+
+        let simulation = client
+            .simulate_ibc_transfer(
+                osmo_admin_addr.to_string(),
+                client.chain_denom(),
+                "100000".to_string(),
+                "channel-0".to_string(),
+                5,
+                None,
+            )
+            .await
+            .unwrap();
+        let simulated_gas = simulation.gas_info.unwrap().gas_used;
+
+        let tx_response = client
+            .ibc_transfer(
+                osmo_admin_addr,
+                client.chain_denom(),
+                "100000".to_string(),
+                "channel-0".to_string(),
+                5,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let confirmed_tx = client.poll_for_tx(&tx_response.hash).await.unwrap();
+
+        // simulation runs against a dummy signature, so the charged gas can
+        // differ slightly from the simulated one
+        let gas_used = u64::try_from(confirmed_tx.gas_used).unwrap();
+        assert!(gas_used.abs_diff(simulated_gas) < simulated_gas / 10);
+    }
 }
@@ -1,10 +1,20 @@
+use alloy::primitives::keccak256;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use cosmos_sdk_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
 use cosmrs::Any;
 use log::info;
+use serde_json::Value;
 use tonic::async_trait;
 
 use crate::{
-    common::transaction::TransactionResponse,
-    cosmos::{base_client::BaseClient, grpc_client::GrpcSigningClient, CosmosServiceClient},
+    common::transaction::{Event, TransactionResponse},
+    cosmos::{
+        base_client::BaseClient,
+        errors::CosmosError,
+        grpc_client::{GrpcChannelCache, GrpcSigningClient},
+        utils::collect_all_pages,
+        CosmosServiceClient,
+    },
 };
 
 const CHAIN_PREFIX: &str = "noble";
@@ -13,6 +23,12 @@ const CCTP_MODULE_NAME: &str = "cctp";
 // u128::max as str
 const ALLOWANCE: &str = "340282366920938463463374607431768211455";
 const DUMMY_ADDRESS: &[u8; 32] = &[0x01; 32];
+/// cctp domain id of noble
+const NOBLE_CCTP_DOMAIN: u32 = 4;
+/// circle attestation service for mainnet cctp transfers
+pub const CIRCLE_ATTESTATION_API_MAINNET: &str = "https://iris-api.circle.com";
+/// circle attestation service for testnet cctp transfers
+pub const CIRCLE_ATTESTATION_API_TESTNET: &str = "https://iris-api-sandbox.circle.com";
 
 /// client for interacting with the noble chain
 pub struct NobleClient {
@@ -22,6 +38,8 @@ pub struct NobleClient {
     chain_denom: String,
     chain_prefix: String,
     gas_price: f64,
+    channel_cache: GrpcChannelCache,
+    attestation_api_url: String,
 }
 
 impl NobleClient {
@@ -41,9 +59,135 @@ impl NobleClient {
             chain_denom: chain_denom.to_string(),
             chain_prefix: CHAIN_PREFIX.to_string(),
             gas_price: avg_gas_price,
+            channel_cache: GrpcChannelCache::default(),
+            attestation_api_url: CIRCLE_ATTESTATION_API_MAINNET.to_string(),
         })
     }
 
+    /// sets the circle attestation service to query, e.g.
+    /// `CIRCLE_ATTESTATION_API_TESTNET` for testnet cctp transfers
+    pub fn with_attestation_api_url(mut self, url: &str) -> Self {
+        self.attestation_api_url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// fetches the circle attestation of the cctp message emitted by the
+    /// given noble burn tx. returns `None` while the attestation is pending.
+    pub async fn query_pending_attestation(
+        &self,
+        burn_tx_hash: &str,
+    ) -> anyhow::Result<Option<Attestation>> {
+        let url = format!(
+            "{}/v2/messages/{NOBLE_CCTP_DOMAIN}?transactionHash={burn_tx_hash}",
+            self.attestation_api_url
+        );
+
+        let response = reqwest::get(&url).await?;
+
+        // the service only knows about the message once the burn is indexed
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let body: Value = response.error_for_status()?.json().await?;
+
+        Attestation::from_circle_response(&body)
+    }
+
+    /// polls the circle attestation service until the attestation of the
+    /// burn tx is available
+    pub async fn poll_for_attestation(
+        &self,
+        burn_tx_hash: &str,
+        interval_sec: u64,
+        max_attempts: u32,
+    ) -> anyhow::Result<Attestation> {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(interval_sec));
+
+        for attempt in 1..max_attempts + 1 {
+            interval.tick().await;
+
+            if let Some(attestation) = self.query_pending_attestation(burn_tx_hash).await? {
+                return Ok(attestation);
+            }
+
+            info!("attestation for {burn_tx_hash} pending, attempt {attempt}/{max_attempts}");
+        }
+
+        Err(anyhow::anyhow!(
+            "attestation for {burn_tx_hash} not available after {max_attempts} attempts"
+        ))
+    }
+
+    /// burns the signer's usdc via cctp so that it can be minted to
+    /// `mint_recipient` on the destination domain once attested
+    pub async fn burn_usdc(
+        &self,
+        amount: u128,
+        destination_domain: u32,
+        mint_recipient: &[u8; 32],
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let burn_msg = MsgDepositForBurn {
+            from: signing_client.address.to_string(),
+            amount: amount.to_string(),
+            destination_domain,
+            mint_recipient: mint_recipient.to_vec(),
+            burn_token: self.chain_denom.to_string(),
+        };
+
+        self.estimate_and_broadcast(Any::from_msg(&burn_msg)?, memo)
+            .await
+    }
+
+    /// looks up the cctp burn with the given nonce through the events of the
+    /// tx that emitted it
+    pub async fn query_burn_message(&self, nonce: u64) -> anyhow::Result<CctpBurnMessage> {
+        // typed event attributes are json encoded, u64s as strings
+        let events = [(
+            "circle.cctp.v1.DepositForBurn.nonce".to_string(),
+            format!("\"{nonce}\""),
+        )];
+
+        let (txs, _) = self.query_txs_by_events(&events, None).await?;
+
+        let tx = txs
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("no burn found for nonce {nonce}"))?;
+
+        CctpBurnMessage::from_tx(tx, nonce)
+    }
+
+    /// returns the 0x prefixed keccak256 hash of a cctp message, which
+    /// identifies the message in the circle attestation api
+    pub fn query_attestation_hash(message: &[u8]) -> String {
+        format!("0x{}", hex::encode(keccak256(message)))
+    }
+
+    /// lists the token messengers of remote domains registered in `x/cctp`
+    pub async fn query_cctp_remote_token_messengers(
+        &self,
+    ) -> anyhow::Result<Vec<RemoteTokenMessenger>> {
+        collect_all_pages(100, |page| async move {
+            let response: QueryRemoteTokenMessengersResponse = self
+                .query_grpc(
+                    "/circle.cctp.v1.Query/RemoteTokenMessengers",
+                    QueryRemoteTokenMessengersRequest {
+                        pagination: Some(page.into()),
+                    },
+                )
+                .await?;
+
+            Ok((
+                response.remote_token_messengers,
+                response.pagination.unwrap_or_default().into(),
+            ))
+        })
+        .await
+    }
+
     /// Sets up the noble client for testing the burn functionality by:
     /// - Configuring the module account as a minter controller.
     /// - Configuring the module account as a minter with a specified allowance.
@@ -136,7 +280,11 @@ impl NobleClient {
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         TransactionResponse::try_from(broadcast_tx_response.tx_response)
     }
@@ -166,7 +314,11 @@ impl NobleClient {
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         TransactionResponse::try_from(broadcast_tx_response.tx_response)
     }
@@ -200,7 +352,11 @@ impl NobleClient {
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         TransactionResponse::try_from(broadcast_tx_response.tx_response)
     }
@@ -230,7 +386,11 @@ impl NobleClient {
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         TransactionResponse::try_from(broadcast_tx_response.tx_response)
     }
@@ -262,12 +422,123 @@ impl NobleClient {
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         TransactionResponse::try_from(broadcast_tx_response.tx_response)
     }
 }
 
+/// signed attestation of a cctp message, required to mint on the destination
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attestation {
+    pub message: Vec<u8>,
+    pub attestation: Vec<u8>,
+}
+
+impl Attestation {
+    /// parses the first message of a circle `/v2/messages` response, `None`
+    /// if it is not attested yet
+    fn from_circle_response(body: &Value) -> anyhow::Result<Option<Self>> {
+        let message = match body["messages"].as_array().and_then(|m| m.first()) {
+            Some(message) => message,
+            None => return Ok(None),
+        };
+
+        if message["status"].as_str() != Some("complete") {
+            return Ok(None);
+        }
+
+        let decode = |field: &str| -> anyhow::Result<Vec<u8>> {
+            let value = message[field]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("attestation response has no {field}"))?;
+
+            Ok(hex::decode(value.trim_start_matches("0x"))?)
+        };
+
+        Ok(Some(Attestation {
+            message: decode("message")?,
+            attestation: decode("attestation")?,
+        }))
+    }
+}
+
+/// byte range of the nonce in a cctp message header
+const CCTP_MESSAGE_NONCE_RANGE: std::ops::Range<usize> = 12..20;
+
+/// cctp burn of usdc on noble along with the message sent to the
+/// destination domain
+#[derive(Debug, Clone, PartialEq)]
+pub struct CctpBurnMessage {
+    pub nonce: u64,
+    pub tx_hash: String,
+    pub depositor: String,
+    pub burn_token: String,
+    pub amount: u128,
+    pub destination_domain: u32,
+    pub mint_recipient: Vec<u8>,
+    /// empty if anyone may relay the message on the destination domain
+    pub destination_caller: Vec<u8>,
+    /// raw cctp message, as attested by circle
+    pub message: Vec<u8>,
+}
+
+impl CctpBurnMessage {
+    /// parses the burn with the given nonce out of the
+    /// `DepositForBurn` and `MessageSent` events of the tx
+    fn from_tx(tx: &TransactionResponse, nonce: u64) -> anyhow::Result<Self> {
+        let json_attr = |event: &Event, key: &str| -> anyhow::Result<Value> {
+            let value = event
+                .get_attribute(key)
+                .ok_or_else(|| anyhow::anyhow!("burn event has no {key} attribute"))?;
+
+            Ok(serde_json::from_str(value)?)
+        };
+        let string_attr = |event: &Event, key: &str| -> anyhow::Result<String> {
+            json_attr(event, key)?
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("burn event attribute {key} is not a string"))
+        };
+        let bytes_attr = |event: &Event, key: &str| -> anyhow::Result<Vec<u8>> {
+            Ok(BASE64.decode(string_attr(event, key)?)?)
+        };
+
+        let mut burns = tx.find_events("circle.cctp.v1.DepositForBurn").into_iter();
+        let burn = burns
+            .find(|event| string_attr(event, "nonce").is_ok_and(|n| n == nonce.to_string()))
+            .ok_or_else(|| anyhow::anyhow!("tx {} has no burn with nonce {nonce}", tx.hash))?;
+
+        // a tx may send several messages, which are matched by their nonce
+        let message = tx
+            .find_events("circle.cctp.v1.MessageSent")
+            .into_iter()
+            .filter_map(|event| bytes_attr(event, "message").ok())
+            .find(|message| message.get(CCTP_MESSAGE_NONCE_RANGE) == Some(&nonce.to_be_bytes()[..]))
+            .ok_or_else(|| anyhow::anyhow!("tx {} has no message with nonce {nonce}", tx.hash))?;
+
+        let destination_domain = json_attr(burn, "destination_domain")?
+            .as_u64()
+            .ok_or_else(|| anyhow::anyhow!("burn event has an invalid destination domain"))?;
+
+        Ok(CctpBurnMessage {
+            nonce,
+            tx_hash: tx.hash.to_string(),
+            depositor: string_attr(burn, "depositor")?,
+            burn_token: string_attr(burn, "burn_token")?,
+            amount: string_attr(burn, "amount")?.parse()?,
+            destination_domain: u32::try_from(destination_domain)?,
+            mint_recipient: bytes_attr(burn, "mint_recipient")?,
+            destination_caller: bytes_attr(burn, "destination_caller").unwrap_or_default(),
+            message,
+        })
+    }
+}
+
 /// noble is a base cosmos chain
 #[async_trait]
 impl BaseClient for NobleClient {}
@@ -298,6 +569,15 @@ impl GrpcSigningClient for NobleClient {
         self.gas_price
     }
 
+    fn set_gas_price(&mut self, gas_price: f64) -> anyhow::Result<()> {
+        self.gas_price = gas_price;
+        Ok(())
+    }
+
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        Some(&self.channel_cache)
+    }
+
     fn gas_adjustment(&self) -> f64 {
         1.8
     }
@@ -432,3 +712,157 @@ impl ::prost::Name for MsgLinkTokenPair {
         "/circle.cctp.v1.MsgLinkTokenPair".into()
     }
 }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgDepositForBurn {
+    /// the signer address
+    #[prost(string, tag = "1")]
+    pub from: ::prost::alloc::string::String,
+    /// the amount to burn
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+    /// the cctp domain to mint on
+    #[prost(uint32, tag = "3")]
+    pub destination_domain: u32,
+    /// the recipient on the destination domain, left padded to 32 bytes
+    #[prost(bytes, tag = "4")]
+    pub mint_recipient: ::prost::alloc::vec::Vec<u8>,
+    /// the local denom to burn
+    #[prost(string, tag = "5")]
+    pub burn_token: ::prost::alloc::string::String,
+}
+
+impl ::prost::Name for MsgDepositForBurn {
+    const NAME: &'static str = "MsgDepositForBurn";
+    const PACKAGE: &'static str = "circle.cctp.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "circle.cctp.v1.MsgDepositForBurn".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/circle.cctp.v1.MsgDepositForBurn".into()
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoteTokenMessenger {
+    #[prost(uint32, tag = "1")]
+    pub domain_id: u32,
+    #[prost(bytes, tag = "2")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryRemoteTokenMessengersRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<PageRequest>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryRemoteTokenMessengersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub remote_token_messengers: ::prost::alloc::vec::Vec<RemoteTokenMessenger>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<PageResponse>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn burn_message_is_parsed_from_tx_events() {
+        use crate::common::transaction::EventAttribute;
+
+        let event = |event_type: &str, attributes: &[(&str, String)]| Event {
+            event_type: event_type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| EventAttribute {
+                    key: key.to_string(),
+                    value: value.clone(),
+                })
+                .collect(),
+        };
+
+        let mut message = vec![0u8; 116];
+        message[CCTP_MESSAGE_NONCE_RANGE].copy_from_slice(&42u64.to_be_bytes());
+        let mint_recipient = [0x02; 32];
+
+        let tx = TransactionResponse {
+            hash: "ABCD".to_string(),
+            success: true,
+            block_height: 1,
+            gas_used: 1,
+            events: vec![
+                event(
+                    "circle.cctp.v1.MessageSent",
+                    &[("message", format!("\"{}\"", BASE64.encode(&message)))],
+                ),
+                event(
+                    "circle.cctp.v1.DepositForBurn",
+                    &[
+                        ("nonce", "\"42\"".to_string()),
+                        ("burn_token", "\"uusdc\"".to_string()),
+                        ("amount", "\"1000\"".to_string()),
+                        ("depositor", "\"noble1depositor\"".to_string()),
+                        (
+                            "mint_recipient",
+                            format!("\"{}\"", BASE64.encode(mint_recipient)),
+                        ),
+                        ("destination_domain", "0".to_string()),
+                    ],
+                ),
+            ],
+        };
+
+        let burn = CctpBurnMessage::from_tx(&tx, 42).unwrap();
+        assert_eq!(burn.amount, 1000);
+        assert_eq!(burn.burn_token, "uusdc");
+        assert_eq!(burn.depositor, "noble1depositor");
+        assert_eq!(burn.destination_domain, 0);
+        assert_eq!(burn.mint_recipient, mint_recipient.to_vec());
+        assert!(burn.destination_caller.is_empty());
+        assert_eq!(burn.message, message);
+
+        assert!(CctpBurnMessage::from_tx(&tx, 43).is_err());
+    }
+
+    #[test]
+    fn attestation_hash_is_keccak_of_message() {
+        assert_eq!(
+            NobleClient::query_attestation_hash(&[]),
+            "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+
+    #[test]
+    fn attestation_is_none_until_complete() {
+        let pending = json!({
+            "messages": [{
+                "message": "0x",
+                "attestation": "PENDING",
+                "status": "pending_confirmations",
+            }]
+        });
+        assert_eq!(Attestation::from_circle_response(&pending).unwrap(), None);
+
+        let complete = json!({
+            "messages": [{
+                "message": "0x0102",
+                "attestation": "0xaabb",
+                "status": "complete",
+            }]
+        });
+        let attestation = Attestation::from_circle_response(&complete)
+            .unwrap()
+            .unwrap();
+        assert_eq!(attestation.message, vec![0x01, 0x02]);
+        assert_eq!(attestation.attestation, vec![0xaa, 0xbb]);
+    }
+}
@@ -0,0 +1,167 @@
+use alloy::{
+    consensus::{SignableTransaction, TypedTransaction},
+    primitives::{address, Address, Bytes, U256},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    sol,
+};
+use alloy_signer_local::coins_bip39::English;
+use alloy_signer_local::{MnemonicBuilder, PrivateKeySigner};
+use tonic::async_trait;
+
+use crate::evm::base_client::EvmBaseClient;
+use crate::evm::request_provider_client::{ConnectionConfig, RequestProviderClient};
+
+/// `GasPriceOracle` predeploy of op stack chains, pricing the l1 data
+/// availability cost of txs
+pub const GAS_PRICE_ORACLE_ADDRESS: Address = address!("420000000000000000000000000000000000000F");
+
+sol!(
+    #[sol(rpc)]
+    interface GasPriceOracle {
+        function l1BaseFee() external view returns (uint256);
+        function getL1Fee(bytes memory _data) external view returns (uint256);
+    }
+);
+
+/// client for interacting with optimism and other op stack chains.
+/// on top of the l2 execution fee, op stack txs pay for posting their data
+/// to l1, which is priced by the `GasPriceOracle` predeploy.
+pub struct OptimismClient {
+    rpc_url: String,
+    signer: PrivateKeySigner,
+    connection_config: ConnectionConfig,
+}
+
+impl OptimismClient {
+    pub fn new(
+        rpc_url: &str,
+        mnemonic: &str,
+        mnemonic_derivation_index: Option<u32>,
+    ) -> anyhow::Result<Self> {
+        let builder = MnemonicBuilder::<English>::default().phrase(mnemonic);
+
+        let derivation_index = mnemonic_derivation_index.unwrap_or_default();
+
+        let signer = builder.index(derivation_index)?.build()?;
+
+        Ok(Self {
+            rpc_url: rpc_url.to_string(),
+            signer,
+            connection_config: ConnectionConfig::default(),
+        })
+    }
+
+    /// returns the l1 base fee as last reported to the l2, in wei
+    pub async fn get_l1_gas_price(&self) -> anyhow::Result<U256> {
+        let client = self.get_request_provider().await?;
+
+        let oracle = GasPriceOracle::new(GAS_PRICE_ORACLE_ADDRESS, &client);
+
+        Ok(self.query(oracle.l1BaseFee()).await?._0)
+    }
+
+    /// returns the l1 data fee, in wei, of a tx with the given unsigned rlp
+    /// encoding. the oracle accounts for the missing signature.
+    pub async fn get_l1_data_fee(&self, tx: &Bytes) -> anyhow::Result<U256> {
+        let client = self.get_request_provider().await?;
+
+        let oracle = GasPriceOracle::new(GAS_PRICE_ORACLE_ADDRESS, &client);
+
+        Ok(self.query(oracle.getL1Fee(tx.clone())).await?._0)
+    }
+
+    /// estimates the total fee, in wei, of sending the tx from the signer:
+    /// the estimated l2 execution gas at the current gas price plus the l1
+    /// data fee of the tx
+    pub async fn estimate_total_fee(&self, tx: &TransactionRequest) -> anyhow::Result<U256> {
+        let client = self.get_request_provider().await?;
+
+        let mut tx = tx.clone();
+        let from = *tx.from.get_or_insert(self.signer.address());
+
+        let gas = client.estimate_gas(&tx).await?;
+        let gas_price = client.get_gas_price().await?;
+
+        tx.gas = Some(gas);
+        if tx.nonce.is_none() {
+            tx.nonce = Some(client.get_transaction_count(from).await?);
+        }
+        if tx.chain_id.is_none() {
+            tx.chain_id = Some(client.get_chain_id().await?);
+        }
+        if tx.gas_price.is_none() && tx.max_fee_per_gas.is_none() {
+            tx.gas_price = Some(gas_price);
+        }
+
+        let typed_tx = tx
+            .build_typed_tx()
+            .map_err(|_| anyhow::anyhow!("failed to build a typed tx from the request"))?;
+
+        let l1_data_fee = self.get_l1_data_fee(&encode_unsigned(&typed_tx)).await?;
+
+        Ok(U256::from(gas) * U256::from(gas_price) + l1_data_fee)
+    }
+}
+
+/// rlp encodes the tx the way it is hashed for signing
+fn encode_unsigned(tx: &TypedTransaction) -> Bytes {
+    let mut encoded = vec![];
+
+    match tx {
+        TypedTransaction::Legacy(tx) => tx.encode_for_signing(&mut encoded),
+        TypedTransaction::Eip2930(tx) => tx.encode_for_signing(&mut encoded),
+        TypedTransaction::Eip1559(tx) => tx.encode_for_signing(&mut encoded),
+        TypedTransaction::Eip4844(tx) => tx.encode_for_signing(&mut encoded),
+        TypedTransaction::Eip7702(tx) => tx.encode_for_signing(&mut encoded),
+    }
+
+    encoded.into()
+}
+
+#[async_trait]
+impl EvmBaseClient for OptimismClient {}
+
+#[async_trait]
+impl RequestProviderClient for OptimismClient {
+    fn rpc_url(&self) -> String {
+        self.rpc_url.clone()
+    }
+
+    fn signer(&self) -> PrivateKeySigner {
+        self.signer.clone()
+    }
+
+    fn connection_config(&self) -> Option<&ConnectionConfig> {
+        Some(&self.connection_config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{primitives::keccak256, sol_types::SolCall};
+
+    use super::*;
+
+    #[test]
+    fn gas_price_oracle_calls_are_abi_encoded() {
+        assert_eq!(
+            GasPriceOracle::l1BaseFeeCall::SELECTOR,
+            keccak256("l1BaseFee()")[..4]
+        );
+        assert_eq!(GasPriceOracle::l1BaseFeeCall {}.abi_encode().len(), 4);
+
+        let data = Bytes::from(vec![0xab; 40]);
+        let encoded = GasPriceOracle::getL1FeeCall {
+            _data: data.clone(),
+        }
+        .abi_encode();
+
+        assert_eq!(encoded[..4], keccak256("getL1Fee(bytes)")[..4]);
+        // offset, length and the data padded to a full word
+        assert_eq!(U256::from_be_slice(&encoded[4..36]), U256::from(32));
+        assert_eq!(U256::from_be_slice(&encoded[36..68]), U256::from(40));
+        assert_eq!(encoded[68..108], data[..]);
+        assert_eq!(encoded.len(), 4 + 32 * 4);
+    }
+}
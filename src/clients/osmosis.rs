@@ -1,7 +1,21 @@
+use cosmos_sdk_proto::{
+    cosmos::base::{abci::v1beta1::TxMsgData, v1beta1::Coin as ProtoCoin},
+    tendermint::google::protobuf::{Duration, Timestamp},
+};
+use cosmrs::{Any, Coin};
+use prost::{Message, Name};
 use tonic::async_trait;
 
-use crate::cosmos::{
-    base_client::BaseClient, grpc_client::GrpcSigningClient, wasm_client::WasmClient,
+use crate::{
+    common::transaction::TransactionResponse,
+    cosmos::{
+        base_client::BaseClient,
+        errors::CosmosError,
+        grpc_client::{GrpcChannelCache, GrpcSigningClient},
+        types::{parse_dec, proto_coins_to_coins},
+        wasm_client::WasmClient,
+        CosmosServiceClient,
+    },
 };
 
 const CHAIN_PREFIX: &str = "osmo";
@@ -15,6 +29,7 @@ pub struct OsmosisClient {
     chain_denom: String,
     chain_prefix: String,
     gas_price: f64,
+    channel_cache: GrpcChannelCache,
 }
 
 impl OsmosisClient {
@@ -33,6 +48,727 @@ impl OsmosisClient {
             chain_denom: CHAIN_DENOM.to_string(),
             chain_prefix: CHAIN_PREFIX.to_string(),
             gas_price: avg_gas_price,
+            channel_cache: GrpcChannelCache::default(),
+        })
+    }
+
+    /// locks the given lp shares and superfluid delegates them to the
+    /// validator. locks are created with the chain's unbonding duration.
+    /// returns the id of the created lock along with the tx response.
+    pub async fn superfluid_lock_and_delegate(
+        &self,
+        coins: &[Coin],
+        validator_address: &str,
+        memo: Option<&str>,
+    ) -> anyhow::Result<(u64, TransactionResponse)> {
+        let signing_client = self.get_signing_client().await?;
+
+        let lock_and_delegate_msg = MsgLockAndSuperfluidDelegate {
+            sender: signing_client.address.to_string(),
+            coins: coins.iter().cloned().map(Into::into).collect(),
+            val_addr: validator_address.to_string(),
+        };
+
+        let tx_response = self
+            .estimate_and_broadcast(Any::from_msg(&lock_and_delegate_msg)?, memo)
+            .await?;
+
+        let lock_id = self
+            .query_first_msg_response::<MsgLockAndSuperfluidDelegateResponse>(&tx_response.hash)
+            .await?
+            .id;
+
+        Ok((lock_id, tx_response))
+    }
+
+    /// superfluid undelegates and starts unbonding the entire lock
+    pub async fn superfluid_undelegate_and_unbond_lock(
+        &self,
+        lock_id: u64,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let lock = self.query_lock_by_id(lock_id).await?;
+
+        // superfluid locks only ever hold a single lp share denom
+        let coin = lock
+            .coins
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("lock {lock_id} holds no coins"))?;
+
+        let signing_client = self.get_signing_client().await?;
+        let channel = self.get_grpc_channel().await?;
+
+        let undelegate_msg = MsgSuperfluidUndelegateAndUnbondLock {
+            sender: signing_client.address.to_string(),
+            lock_id,
+            coin: Some(coin.into()),
+        };
+
+        let any_msg = Any::from_msg(&undelegate_msg)?;
+
+        let simulation_response = self.simulate_tx(any_msg.clone()).await?;
+        let fee = self.get_tx_fee(simulation_response)?;
+
+        let raw_tx = signing_client.create_tx(any_msg, fee, memo).await?;
+
+        let mut grpc_client = CosmosServiceClient::new(channel);
+
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        TransactionResponse::try_from(broadcast_tx_response.tx_response)
+    }
+
+    /// returns the superfluid delegations of the delegator, one per lock.
+    /// locks of the delegator which are not superfluid delegated are skipped.
+    pub async fn query_superfluid_delegations(
+        &self,
+        delegator: &str,
+    ) -> anyhow::Result<Vec<SuperfluidDelegation>> {
+        // zero duration matches every lock of the owner
+        let locks_response: AccountLockedLongerDurationResponse = self
+            .query_grpc(
+                "/osmosis.lockup.Query/AccountLockedLongerDuration",
+                AccountLockedLongerDurationRequest {
+                    owner: delegator.to_string(),
+                    duration: Some(Duration::default()),
+                },
+            )
+            .await?;
+
+        let mut delegations = vec![];
+
+        for lock in locks_response.locks {
+            let intermediary: ConnectedIntermediaryAccountResponse = self
+                .query_grpc(
+                    "/osmosis.superfluid.Query/ConnectedIntermediaryAccount",
+                    ConnectedIntermediaryAccountRequest { lock_id: lock.id },
+                )
+                .await?;
+
+            let account = match intermediary.account {
+                Some(account) if !account.val_addr.is_empty() => account,
+                _ => continue,
+            };
+
+            for coin in proto_coins_to_coins(&lock.coins)? {
+                delegations.push(SuperfluidDelegation {
+                    lock_id: lock.id,
+                    validator_address: account.val_addr.clone(),
+                    denom: coin.denom.to_string(),
+                    amount: coin.amount,
+                });
+            }
+        }
+
+        Ok(delegations)
+    }
+
+    /// returns the osmo equivalent multiplier of a superfluid asset
+    pub async fn query_superfluid_asset_multiplier(&self, denom: &str) -> anyhow::Result<f64> {
+        let response: AssetMultiplierResponse = self
+            .query_grpc(
+                "/osmosis.superfluid.Query/AssetMultiplier",
+                AssetMultiplierRequest {
+                    denom: denom.to_string(),
+                },
+            )
+            .await?;
+
+        let record = response
+            .osmo_equivalent_multiplier
+            .ok_or_else(|| anyhow::anyhow!("no multiplier found for {denom}"))?;
+
+        parse_dec(&record.multiplier)
+    }
+
+    /// joins a gamm pool for the given amount of lp shares, providing at
+    /// most `token_in_maxs` of the pool assets
+    pub async fn gamm_join_pool(
+        &self,
+        pool_id: u64,
+        share_out_amount: u128,
+        token_in_maxs: &[Coin],
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let join_pool_msg = MsgJoinPool {
+            sender: signing_client.address.to_string(),
+            pool_id,
+            share_out_amount: share_out_amount.to_string(),
+            token_in_maxs: token_in_maxs.iter().cloned().map(Into::into).collect(),
+        };
+
+        self.estimate_and_broadcast(Any::from_msg(&join_pool_msg)?, memo)
+            .await
+    }
+
+    /// exits a gamm pool by burning the given amount of lp shares, receiving
+    /// at least `token_out_mins` of the pool assets
+    pub async fn gamm_exit_pool(
+        &self,
+        pool_id: u64,
+        share_in_amount: u128,
+        token_out_mins: &[Coin],
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let exit_pool_msg = MsgExitPool {
+            sender: signing_client.address.to_string(),
+            pool_id,
+            share_in_amount: share_in_amount.to_string(),
+            token_out_mins: token_out_mins.iter().cloned().map(Into::into).collect(),
+        };
+
+        self.estimate_and_broadcast(Any::from_msg(&exit_pool_msg)?, memo)
+            .await
+    }
+
+    /// joins a gamm pool with a single asset, which is partially swapped
+    /// into the other pool assets
+    pub async fn gamm_join_swap_extern_amount_in(
+        &self,
+        pool_id: u64,
+        token_in: Coin,
+        share_out_min: u128,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let join_swap_msg = MsgJoinSwapExternAmountIn {
+            sender: signing_client.address.to_string(),
+            pool_id,
+            token_in: Some(token_in.into()),
+            share_out_min_amount: share_out_min.to_string(),
+        };
+
+        self.estimate_and_broadcast(Any::from_msg(&join_swap_msg)?, memo)
+            .await
+    }
+
+    /// returns the assets of a balancer pool along with their weights
+    pub async fn query_gamm_pool_assets(&self, pool_id: u64) -> anyhow::Result<Vec<PoolAsset>> {
+        self.query_balancer_pool(pool_id)
+            .await?
+            .pool_assets
+            .into_iter()
+            .map(PoolAsset::try_from)
+            .collect()
+    }
+
+    /// returns the value of a single lp share of a balancer pool, denominated
+    /// in the first pool asset
+    pub async fn query_gamm_pool_share_price(&self, pool_id: u64) -> anyhow::Result<f64> {
+        self.query_balancer_pool(pool_id).await?.share_price()
+    }
+
+    async fn query_balancer_pool(&self, pool_id: u64) -> anyhow::Result<BalancerPool> {
+        let response: QueryPoolResponse = self
+            .query_grpc(
+                "/osmosis.gamm.v1beta1.Query/Pool",
+                QueryPoolRequest { pool_id },
+            )
+            .await?;
+
+        let pool = response
+            .pool
+            .ok_or_else(|| anyhow::anyhow!("pool {pool_id} not found"))?;
+
+        if pool.type_url != BalancerPool::type_url() {
+            return Err(anyhow::anyhow!(
+                "pool {pool_id} is not a balancer pool: {}",
+                pool.type_url
+            ));
+        }
+
+        Ok(BalancerPool::decode(pool.value.as_slice())?)
+    }
+
+    /// estimates the amount of `token_out_denom` received for swapping
+    /// `token_in` through the pool
+    pub async fn simulate_swap_exact_in(
+        &self,
+        pool_id: u64,
+        token_in: Coin,
+        token_out_denom: &str,
+    ) -> anyhow::Result<Coin> {
+        let response: EstimateSwapExactAmountInResponse = self
+            .query_grpc(
+                "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn",
+                EstimateSwapExactAmountInRequest {
+                    pool_id,
+                    token_in: token_in.to_string(),
+                    routes: vec![SwapAmountInRoute {
+                        pool_id,
+                        token_out_denom: token_out_denom.to_string(),
+                    }],
+                },
+            )
+            .await?;
+
+        Ok(Coin {
+            denom: token_out_denom
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Failed to parse denom: {e}"))?,
+            amount: response.token_out_amount.parse()?,
+        })
+    }
+
+    /// estimates the amount of `token_in_denom` required to receive
+    /// `token_out` from the pool
+    pub async fn simulate_swap_exact_out(
+        &self,
+        pool_id: u64,
+        token_out: Coin,
+        token_in_denom: &str,
+    ) -> anyhow::Result<Coin> {
+        let response: EstimateSwapExactAmountOutResponse = self
+            .query_grpc(
+                "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountOut",
+                EstimateSwapExactAmountOutRequest {
+                    pool_id,
+                    routes: vec![SwapAmountOutRoute {
+                        pool_id,
+                        token_in_denom: token_in_denom.to_string(),
+                    }],
+                    token_out: token_out.to_string(),
+                },
+            )
+            .await?;
+
+        Ok(Coin {
+            denom: token_in_denom
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Failed to parse denom: {e}"))?,
+            amount: response.token_in_amount.parse()?,
+        })
+    }
+
+    /// returns the spot price of the pool for the denom pair as the decimal
+    /// string reported by the chain
+    pub async fn query_pool_spot_price(
+        &self,
+        pool_id: u64,
+        base_denom: &str,
+        quote_denom: &str,
+    ) -> anyhow::Result<String> {
+        let response: SpotPriceResponse = self
+            .query_grpc(
+                "/osmosis.poolmanager.v1beta1.Query/SpotPrice",
+                SpotPriceRequest {
+                    pool_id,
+                    base_asset_denom: base_denom.to_string(),
+                    quote_asset_denom: quote_denom.to_string(),
+                },
+            )
+            .await?;
+
+        Ok(response.spot_price)
+    }
+
+    /// returns the state of a concentrated liquidity pool
+    pub async fn query_cl_pool(&self, pool_id: u64) -> anyhow::Result<ConcentratedLiquidityPool> {
+        let response: PoolmanagerPoolResponse = self
+            .query_grpc(
+                "/osmosis.poolmanager.v1beta1.Query/Pool",
+                PoolmanagerPoolRequest { pool_id },
+            )
+            .await?;
+
+        let pool = response
+            .pool
+            .ok_or_else(|| anyhow::anyhow!("pool {pool_id} not found"))?;
+
+        if pool.type_url != ProtoConcentratedLiquidityPool::type_url() {
+            return Err(anyhow::anyhow!(
+                "pool {pool_id} is not a concentrated liquidity pool: {}",
+                pool.type_url
+            ));
+        }
+
+        Ok(ProtoConcentratedLiquidityPool::decode(pool.value.as_slice())?.into())
+    }
+
+    /// returns the net liquidity of the initialized ticks of a concentrated
+    /// liquidity pool within `[lower_tick, upper_tick]`, in ascending order
+    pub async fn query_tick_data(
+        &self,
+        pool_id: u64,
+        lower_tick: i64,
+        upper_tick: i64,
+    ) -> anyhow::Result<Vec<TickInfo>> {
+        anyhow::ensure!(
+            lower_tick <= upper_tick,
+            "lower tick {lower_tick} is above upper tick {upper_tick}"
+        );
+
+        let pool = self.query_cl_pool(pool_id).await?;
+
+        // swapping in token1 moves the price up, so the ticks are walked
+        // from the lower towards the upper bound
+        let response: LiquidityNetInDirectionResponse = self
+            .query_grpc(
+                "/osmosis.concentratedliquidity.v1beta1.Query/LiquidityNetInDirection",
+                LiquidityNetInDirectionRequest {
+                    pool_id,
+                    token_in: pool.token1,
+                    start_tick: lower_tick,
+                    use_cur_tick: false,
+                    bound_tick: upper_tick,
+                    use_no_bound: false,
+                },
+            )
+            .await?;
+
+        Ok(response
+            .liquidity_depths
+            .into_iter()
+            .filter(|tick| (lower_tick..=upper_tick).contains(&tick.tick_index))
+            .map(TickInfo::from)
+            .collect())
+    }
+
+    /// returns a concentrated liquidity position along with its underlying
+    /// assets and unclaimed rewards
+    pub async fn query_position(&self, position_id: u64) -> anyhow::Result<PositionInfo> {
+        let response: PositionByIdResponse = self
+            .query_grpc(
+                "/osmosis.concentratedliquidity.v1beta1.Query/PositionById",
+                PositionByIdRequest { position_id },
+            )
+            .await?;
+
+        response
+            .position
+            .ok_or_else(|| anyhow::anyhow!("position {position_id} not found"))?
+            .try_into()
+    }
+
+    /// waits for the tx to be included and decodes the response of its first
+    /// msg, e.g. to learn the id of a created lock
+    async fn query_first_msg_response<R: Message + Default>(
+        &self,
+        tx_hash: &str,
+    ) -> anyhow::Result<R> {
+        let confirmed_tx = self.poll_for_tx(tx_hash).await?;
+
+        let tx_msg_data = TxMsgData::decode(hex::decode(&confirmed_tx.data)?.as_slice())?;
+
+        let msg_response = tx_msg_data
+            .msg_responses
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("no msg response found in tx {tx_hash}"))?;
+
+        Ok(R::decode(msg_response.value.as_slice())?)
+    }
+
+    /// locks the given coins for the duration, e.g. to earn lp incentives.
+    /// returns the id of the created lock along with the tx response.
+    pub async fn lock_tokens(
+        &self,
+        duration_seconds: u64,
+        coins: &[Coin],
+        memo: Option<&str>,
+    ) -> anyhow::Result<(u64, TransactionResponse)> {
+        let signing_client = self.get_signing_client().await?;
+
+        let lock_msg = MsgLockTokens {
+            owner: signing_client.address.to_string(),
+            duration: Some(Duration {
+                seconds: i64::try_from(duration_seconds)?,
+                nanos: 0,
+            }),
+            coins: coins.iter().cloned().map(Into::into).collect(),
+        };
+
+        let tx_response = self
+            .estimate_and_broadcast(Any::from_msg(&lock_msg)?, memo)
+            .await?;
+
+        let lock_id = self
+            .query_first_msg_response::<MsgLockTokensResponse>(&tx_response.hash)
+            .await?
+            .id;
+
+        Ok((lock_id, tx_response))
+    }
+
+    /// starts unlocking the entire lock. the coins are released by the
+    /// lockup module once the lock duration has passed.
+    pub async fn begin_unlocking(
+        &self,
+        lock_id: u64,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        // no coins unlocks the whole lock
+        let unlock_msg = MsgBeginUnlocking {
+            owner: signing_client.address.to_string(),
+            id: lock_id,
+            coins: vec![],
+        };
+
+        self.estimate_and_broadcast(Any::from_msg(&unlock_msg)?, memo)
+            .await
+    }
+
+    pub async fn query_account_locked_coins(&self, address: &str) -> anyhow::Result<Vec<Coin>> {
+        let response: AccountLockedCoinsResponse = self
+            .query_grpc(
+                "/osmosis.lockup.Query/AccountLockedCoins",
+                AccountLockedCoinsRequest {
+                    owner: address.to_string(),
+                },
+            )
+            .await?;
+
+        proto_coins_to_coins(&response.coins)
+    }
+
+    /// returns the locks of the owner with a duration of at least
+    /// `duration_seconds`
+    pub async fn query_account_locked_longer_duration(
+        &self,
+        address: &str,
+        duration_seconds: u64,
+    ) -> anyhow::Result<Vec<OsmosisLock>> {
+        let response: AccountLockedLongerDurationResponse = self
+            .query_grpc(
+                "/osmosis.lockup.Query/AccountLockedLongerDuration",
+                AccountLockedLongerDurationRequest {
+                    owner: address.to_string(),
+                    duration: Some(Duration {
+                        seconds: i64::try_from(duration_seconds)?,
+                        nanos: 0,
+                    }),
+                },
+            )
+            .await?;
+
+        response.locks.into_iter().map(TryInto::try_into).collect()
+    }
+
+    pub async fn query_lock_by_id(&self, lock_id: u64) -> anyhow::Result<OsmosisLock> {
+        let response: LockByIdResponse = self
+            .query_grpc(
+                "/osmosis.lockup.Query/LockByID",
+                LockByIdRequest { lock_id },
+            )
+            .await?;
+
+        response
+            .lock
+            .ok_or_else(|| anyhow::anyhow!("lock {lock_id} not found"))?
+            .try_into()
+    }
+}
+
+/// superfluid delegation backed by a single lock
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuperfluidDelegation {
+    pub lock_id: u64,
+    pub validator_address: String,
+    pub denom: String,
+    pub amount: u128,
+}
+
+/// lockup module lock
+#[derive(Debug, Clone, PartialEq)]
+pub struct OsmosisLock {
+    pub id: u64,
+    pub owner: String,
+    pub duration_seconds: u64,
+    /// unix timestamp (seconds) at which the lock matures. `None` while the
+    /// lock has not started unlocking.
+    pub end_time: Option<i64>,
+    pub coins: Vec<Coin>,
+}
+
+impl TryFrom<PeriodLock> for OsmosisLock {
+    type Error = anyhow::Error;
+
+    fn try_from(value: PeriodLock) -> anyhow::Result<Self> {
+        let duration_seconds = value
+            .duration
+            .map(|d| u64::try_from(d.seconds))
+            .transpose()?
+            .unwrap_or_default();
+
+        // unlocking has not started if the end time is unset (zero value)
+        let end_time = value
+            .end_time
+            .map(|t| t.seconds)
+            .filter(|seconds| *seconds > 0);
+
+        Ok(OsmosisLock {
+            id: value.id,
+            owner: value.owner,
+            duration_seconds,
+            end_time,
+            coins: proto_coins_to_coins(&value.coins)?,
+        })
+    }
+}
+
+/// weighted asset of a gamm balancer pool
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolAsset {
+    pub token: Coin,
+    pub weight: String,
+}
+
+impl TryFrom<ProtoPoolAsset> for PoolAsset {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ProtoPoolAsset) -> anyhow::Result<Self> {
+        let token = value
+            .token
+            .ok_or_else(|| anyhow::anyhow!("pool asset has no token"))?;
+
+        Ok(PoolAsset {
+            token: Coin::try_from(token)
+                .map_err(|e| anyhow::anyhow!("Failed to parse coin: {e}"))?,
+            weight: value.weight,
+        })
+    }
+}
+
+impl BalancerPool {
+    /// each asset holds its weight's share of the pool value, so the value of
+    /// the whole pool in units of the first asset is its balance divided by
+    /// its weight fraction
+    fn share_price(&self) -> anyhow::Result<f64> {
+        let total_shares: f64 = self
+            .total_shares
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("pool {} has no total shares", self.id))?
+            .amount
+            .parse()?;
+
+        let total_weight: f64 = self
+            .pool_assets
+            .iter()
+            .map(|asset| asset.weight.parse::<f64>())
+            .sum::<Result<f64, _>>()?;
+
+        let first_asset = self
+            .pool_assets
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("pool {} has no assets", self.id))?;
+        let first_amount: f64 = first_asset
+            .token
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("pool asset has no token"))?
+            .amount
+            .parse()?;
+        let first_weight: f64 = first_asset.weight.parse()?;
+
+        if total_shares == 0.0 || first_weight == 0.0 {
+            return Err(anyhow::anyhow!("pool {} is empty", self.id));
+        }
+
+        Ok(first_amount * total_weight / first_weight / total_shares)
+    }
+}
+
+/// concentrated liquidity pool. prices and liquidity are kept as the
+/// decimal strings reported by the chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConcentratedLiquidityPool {
+    pub id: u64,
+    pub address: String,
+    pub token0: String,
+    pub token1: String,
+    pub current_tick: i64,
+    pub current_tick_liquidity: String,
+    pub current_sqrt_price: String,
+    pub tick_spacing: u64,
+    pub spread_factor: String,
+}
+
+impl From<ProtoConcentratedLiquidityPool> for ConcentratedLiquidityPool {
+    fn from(value: ProtoConcentratedLiquidityPool) -> Self {
+        ConcentratedLiquidityPool {
+            id: value.id,
+            address: value.address,
+            token0: value.token0,
+            token1: value.token1,
+            current_tick: value.current_tick,
+            current_tick_liquidity: value.current_tick_liquidity,
+            current_sqrt_price: value.current_sqrt_price,
+            tick_spacing: value.tick_spacing,
+            spread_factor: value.spread_factor,
+        }
+    }
+}
+
+/// initialized tick of a concentrated liquidity pool
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickInfo {
+    pub tick_index: i64,
+    /// liquidity added (or removed, if negative) when the tick is crossed
+    /// from below
+    pub liquidity_net: String,
+}
+
+impl From<TickLiquidityNet> for TickInfo {
+    fn from(value: TickLiquidityNet) -> Self {
+        TickInfo {
+            tick_index: value.tick_index,
+            liquidity_net: value.liquidity_net,
+        }
+    }
+}
+
+/// concentrated liquidity position
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionInfo {
+    pub position_id: u64,
+    pub owner: String,
+    pub pool_id: u64,
+    pub lower_tick: i64,
+    pub upper_tick: i64,
+    pub liquidity: String,
+    /// amounts of the pool tokens backing the position
+    pub asset0: Coin,
+    pub asset1: Coin,
+    pub claimable_spread_rewards: Vec<Coin>,
+    pub claimable_incentives: Vec<Coin>,
+}
+
+impl TryFrom<FullPositionBreakdown> for PositionInfo {
+    type Error = anyhow::Error;
+
+    fn try_from(value: FullPositionBreakdown) -> anyhow::Result<Self> {
+        let position = value
+            .position
+            .ok_or_else(|| anyhow::anyhow!("position breakdown has no position"))?;
+
+        let parse_asset = |coin: Option<ProtoCoin>| -> anyhow::Result<Coin> {
+            let coin = coin
+                .ok_or_else(|| anyhow::anyhow!("position {} has no asset", position.position_id))?;
+
+            Coin::try_from(coin).map_err(|e| anyhow::anyhow!("Failed to parse coin: {e}"))
+        };
+
+        Ok(PositionInfo {
+            position_id: position.position_id,
+            owner: position.address.clone(),
+            pool_id: position.pool_id,
+            lower_tick: position.lower_tick,
+            upper_tick: position.upper_tick,
+            liquidity: position.liquidity.clone(),
+            asset0: parse_asset(value.asset0)?,
+            asset1: parse_asset(value.asset1)?,
+            claimable_spread_rewards: proto_coins_to_coins(&value.claimable_spread_rewards)?,
+            claimable_incentives: proto_coins_to_coins(&value.claimable_incentives)?,
         })
     }
 }
@@ -71,7 +807,579 @@ impl GrpcSigningClient for OsmosisClient {
         self.gas_price
     }
 
+    fn set_gas_price(&mut self, gas_price: f64) -> anyhow::Result<()> {
+        self.gas_price = gas_price;
+        Ok(())
+    }
+
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        Some(&self.channel_cache)
+    }
+
     fn gas_adjustment(&self) -> f64 {
         1.8
     }
 }
+
+// Proto definitions to interact with the osmosis superfluid and lockup modules
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgLockAndSuperfluidDelegate {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub coins: ::prost::alloc::vec::Vec<ProtoCoin>,
+    #[prost(string, tag = "3")]
+    pub val_addr: ::prost::alloc::string::String,
+}
+
+impl ::prost::Name for MsgLockAndSuperfluidDelegate {
+    const NAME: &'static str = "MsgLockAndSuperfluidDelegate";
+    const PACKAGE: &'static str = "osmosis.superfluid";
+    fn full_name() -> ::prost::alloc::string::String {
+        "osmosis.superfluid.MsgLockAndSuperfluidDelegate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/osmosis.superfluid.MsgLockAndSuperfluidDelegate".into()
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgLockAndSuperfluidDelegateResponse {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgSuperfluidUndelegateAndUnbondLock {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub lock_id: u64,
+    /// amount of the lock to unbond
+    #[prost(message, optional, tag = "3")]
+    pub coin: ::core::option::Option<ProtoCoin>,
+}
+
+impl ::prost::Name for MsgSuperfluidUndelegateAndUnbondLock {
+    const NAME: &'static str = "MsgSuperfluidUndelegateAndUnbondLock";
+    const PACKAGE: &'static str = "osmosis.superfluid";
+    fn full_name() -> ::prost::alloc::string::String {
+        "osmosis.superfluid.MsgSuperfluidUndelegateAndUnbondLock".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/osmosis.superfluid.MsgSuperfluidUndelegateAndUnbondLock".into()
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConnectedIntermediaryAccountRequest {
+    #[prost(uint64, tag = "1")]
+    pub lock_id: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuperfluidIntermediaryAccountInfo {
+    #[prost(string, tag = "1")]
+    pub denom: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub val_addr: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub gauge_id: u64,
+    #[prost(string, tag = "4")]
+    pub address: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConnectedIntermediaryAccountResponse {
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<SuperfluidIntermediaryAccountInfo>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMultiplierRequest {
+    #[prost(string, tag = "1")]
+    pub denom: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OsmoEquivalentMultiplierRecord {
+    #[prost(int64, tag = "1")]
+    pub epoch_number: i64,
+    #[prost(string, tag = "2")]
+    pub denom: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub multiplier: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMultiplierResponse {
+    #[prost(message, optional, tag = "1")]
+    pub osmo_equivalent_multiplier: ::core::option::Option<OsmoEquivalentMultiplierRecord>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PeriodLock {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(string, tag = "2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub duration: ::core::option::Option<Duration>,
+    #[prost(message, optional, tag = "4")]
+    pub end_time: ::core::option::Option<Timestamp>,
+    #[prost(message, repeated, tag = "5")]
+    pub coins: ::prost::alloc::vec::Vec<ProtoCoin>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LockByIdRequest {
+    #[prost(uint64, tag = "1")]
+    pub lock_id: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LockByIdResponse {
+    #[prost(message, optional, tag = "1")]
+    pub lock: ::core::option::Option<PeriodLock>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountLockedLongerDurationRequest {
+    #[prost(string, tag = "1")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub duration: ::core::option::Option<Duration>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountLockedLongerDurationResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub locks: ::prost::alloc::vec::Vec<PeriodLock>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountLockedCoinsRequest {
+    #[prost(string, tag = "1")]
+    pub owner: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountLockedCoinsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub coins: ::prost::alloc::vec::Vec<ProtoCoin>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgLockTokens {
+    #[prost(string, tag = "1")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub duration: ::core::option::Option<Duration>,
+    #[prost(message, repeated, tag = "3")]
+    pub coins: ::prost::alloc::vec::Vec<ProtoCoin>,
+}
+
+impl ::prost::Name for MsgLockTokens {
+    const NAME: &'static str = "MsgLockTokens";
+    const PACKAGE: &'static str = "osmosis.lockup";
+    fn full_name() -> ::prost::alloc::string::String {
+        "osmosis.lockup.MsgLockTokens".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/osmosis.lockup.MsgLockTokens".into()
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgLockTokensResponse {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgBeginUnlocking {
+    #[prost(string, tag = "1")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub id: u64,
+    /// amount of the lock to unlock, empty unlocks all coins of the lock
+    #[prost(message, repeated, tag = "3")]
+    pub coins: ::prost::alloc::vec::Vec<ProtoCoin>,
+}
+
+impl ::prost::Name for MsgBeginUnlocking {
+    const NAME: &'static str = "MsgBeginUnlocking";
+    const PACKAGE: &'static str = "osmosis.lockup";
+    fn full_name() -> ::prost::alloc::string::String {
+        "osmosis.lockup.MsgBeginUnlocking".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/osmosis.lockup.MsgBeginUnlocking".into()
+    }
+}
+
+// Proto definitions to interact with the osmosis gamm module
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgJoinPool {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub pool_id: u64,
+    #[prost(string, tag = "3")]
+    pub share_out_amount: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub token_in_maxs: ::prost::alloc::vec::Vec<ProtoCoin>,
+}
+
+impl ::prost::Name for MsgJoinPool {
+    const NAME: &'static str = "MsgJoinPool";
+    const PACKAGE: &'static str = "osmosis.gamm.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "osmosis.gamm.v1beta1.MsgJoinPool".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/osmosis.gamm.v1beta1.MsgJoinPool".into()
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgExitPool {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub pool_id: u64,
+    #[prost(string, tag = "3")]
+    pub share_in_amount: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub token_out_mins: ::prost::alloc::vec::Vec<ProtoCoin>,
+}
+
+impl ::prost::Name for MsgExitPool {
+    const NAME: &'static str = "MsgExitPool";
+    const PACKAGE: &'static str = "osmosis.gamm.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "osmosis.gamm.v1beta1.MsgExitPool".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/osmosis.gamm.v1beta1.MsgExitPool".into()
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgJoinSwapExternAmountIn {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub pool_id: u64,
+    #[prost(message, optional, tag = "3")]
+    pub token_in: ::core::option::Option<ProtoCoin>,
+    #[prost(string, tag = "4")]
+    pub share_out_min_amount: ::prost::alloc::string::String,
+}
+
+impl ::prost::Name for MsgJoinSwapExternAmountIn {
+    const NAME: &'static str = "MsgJoinSwapExternAmountIn";
+    const PACKAGE: &'static str = "osmosis.gamm.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "osmosis.gamm.v1beta1.MsgJoinSwapExternAmountIn".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/osmosis.gamm.v1beta1.MsgJoinSwapExternAmountIn".into()
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryPoolRequest {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryPoolResponse {
+    #[prost(message, optional, tag = "1")]
+    pub pool: ::core::option::Option<Any>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoPoolAsset {
+    #[prost(message, optional, tag = "1")]
+    pub token: ::core::option::Option<ProtoCoin>,
+    #[prost(string, tag = "2")]
+    pub weight: ::prost::alloc::string::String,
+}
+
+/// balancer pool, limited to the fields used by the client
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalancerPool {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub id: u64,
+    #[prost(message, optional, tag = "5")]
+    pub total_shares: ::core::option::Option<ProtoCoin>,
+    #[prost(message, repeated, tag = "6")]
+    pub pool_assets: ::prost::alloc::vec::Vec<ProtoPoolAsset>,
+    #[prost(string, tag = "7")]
+    pub total_weight: ::prost::alloc::string::String,
+}
+
+impl ::prost::Name for BalancerPool {
+    const NAME: &'static str = "Pool";
+    const PACKAGE: &'static str = "osmosis.gamm.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "osmosis.gamm.v1beta1.Pool".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/osmosis.gamm.v1beta1.Pool".into()
+    }
+}
+
+// Proto definitions to interact with the osmosis poolmanager and
+// concentratedliquidity modules
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolmanagerPoolRequest {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolmanagerPoolResponse {
+    #[prost(message, optional, tag = "1")]
+    pub pool: ::core::option::Option<Any>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapAmountInRoute {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+    #[prost(string, tag = "2")]
+    pub token_out_denom: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapAmountOutRoute {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+    #[prost(string, tag = "2")]
+    pub token_in_denom: ::prost::alloc::string::String,
+}
+
+/// field 1 (sender) is reserved
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateSwapExactAmountInRequest {
+    #[prost(uint64, tag = "2")]
+    pub pool_id: u64,
+    #[prost(string, tag = "3")]
+    pub token_in: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub routes: ::prost::alloc::vec::Vec<SwapAmountInRoute>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateSwapExactAmountInResponse {
+    #[prost(string, tag = "1")]
+    pub token_out_amount: ::prost::alloc::string::String,
+}
+
+/// field 1 (sender) is reserved
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateSwapExactAmountOutRequest {
+    #[prost(uint64, tag = "2")]
+    pub pool_id: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub routes: ::prost::alloc::vec::Vec<SwapAmountOutRoute>,
+    #[prost(string, tag = "4")]
+    pub token_out: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateSwapExactAmountOutResponse {
+    #[prost(string, tag = "1")]
+    pub token_in_amount: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotPriceRequest {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+    #[prost(string, tag = "2")]
+    pub base_asset_denom: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub quote_asset_denom: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotPriceResponse {
+    #[prost(string, tag = "1")]
+    pub spot_price: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoConcentratedLiquidityPool {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub incentives_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub spread_rewards_address: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "4")]
+    pub id: u64,
+    #[prost(string, tag = "5")]
+    pub current_tick_liquidity: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub token0: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub token1: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub current_sqrt_price: ::prost::alloc::string::String,
+    #[prost(int64, tag = "9")]
+    pub current_tick: i64,
+    #[prost(uint64, tag = "10")]
+    pub tick_spacing: u64,
+    #[prost(int64, tag = "11")]
+    pub exponent_at_price_one: i64,
+    #[prost(string, tag = "12")]
+    pub spread_factor: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "13")]
+    pub last_liquidity_update: ::core::option::Option<Timestamp>,
+}
+
+impl ::prost::Name for ProtoConcentratedLiquidityPool {
+    const NAME: &'static str = "Pool";
+    const PACKAGE: &'static str = "osmosis.concentratedliquidity.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "osmosis.concentratedliquidity.v1beta1.Pool".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/osmosis.concentratedliquidity.v1beta1.Pool".into()
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityNetInDirectionRequest {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+    #[prost(string, tag = "2")]
+    pub token_in: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub start_tick: i64,
+    #[prost(bool, tag = "4")]
+    pub use_cur_tick: bool,
+    #[prost(int64, tag = "5")]
+    pub bound_tick: i64,
+    #[prost(bool, tag = "6")]
+    pub use_no_bound: bool,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TickLiquidityNet {
+    #[prost(string, tag = "1")]
+    pub liquidity_net: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub tick_index: i64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityNetInDirectionResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub liquidity_depths: ::prost::alloc::vec::Vec<TickLiquidityNet>,
+    #[prost(int64, tag = "2")]
+    pub current_tick: i64,
+    #[prost(string, tag = "3")]
+    pub current_liquidity: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub current_sqrt_price: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoPosition {
+    #[prost(uint64, tag = "1")]
+    pub position_id: u64,
+    #[prost(string, tag = "2")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub pool_id: u64,
+    #[prost(int64, tag = "4")]
+    pub lower_tick: i64,
+    #[prost(int64, tag = "5")]
+    pub upper_tick: i64,
+    #[prost(message, optional, tag = "6")]
+    pub join_time: ::core::option::Option<Timestamp>,
+    #[prost(string, tag = "7")]
+    pub liquidity: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FullPositionBreakdown {
+    #[prost(message, optional, tag = "1")]
+    pub position: ::core::option::Option<ProtoPosition>,
+    #[prost(message, optional, tag = "2")]
+    pub asset0: ::core::option::Option<ProtoCoin>,
+    #[prost(message, optional, tag = "3")]
+    pub asset1: ::core::option::Option<ProtoCoin>,
+    #[prost(message, repeated, tag = "4")]
+    pub claimable_spread_rewards: ::prost::alloc::vec::Vec<ProtoCoin>,
+    #[prost(message, repeated, tag = "5")]
+    pub claimable_incentives: ::prost::alloc::vec::Vec<ProtoCoin>,
+    #[prost(message, repeated, tag = "6")]
+    pub forfeited_incentives: ::prost::alloc::vec::Vec<ProtoCoin>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionByIdRequest {
+    #[prost(uint64, tag = "1")]
+    pub position_id: u64,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionByIdResponse {
+    #[prost(message, optional, tag = "1")]
+    pub position: ::core::option::Option<FullPositionBreakdown>,
+}
@@ -0,0 +1,230 @@
+use alloy::{
+    primitives::{address, Address, Bytes, U256},
+    rpc::types::{TransactionReceipt, TransactionRequest},
+    sol,
+    sol_types::SolCall,
+};
+use alloy_signer_local::coins_bip39::English;
+use alloy_signer_local::{MnemonicBuilder, PrivateKeySigner};
+use tonic::async_trait;
+
+use crate::evm::base_client::EvmBaseClient;
+use crate::evm::chains::constants::{
+    POLYGON_AMOY_CHAIN_ID, POLYGON_AMOY_RPC_URL, POLYGON_MAINNET_CHAIN_ID, POLYGON_MAINNET_RPC_URL,
+};
+use crate::evm::request_provider_client::{ConnectionConfig, RequestProviderClient};
+
+/// `MRC20` contract of the native token on polygon pos chains. burning the
+/// native token through it starts a withdrawal to the root chain.
+pub const NATIVE_TOKEN_ADDRESS: Address = address!("0000000000000000000000000000000000001010");
+
+/// `RootChainManager` proxy of the pos bridge on ethereum mainnet
+pub const MAINNET_ROOT_CHAIN_MANAGER_ADDRESS: Address =
+    address!("A0c68C638235ee32657e8f720a23ceC1bFc77C77");
+
+/// `RootChainManager` proxy of the pos bridge on sepolia, the root chain of amoy
+pub const AMOY_ROOT_CHAIN_MANAGER_ADDRESS: Address =
+    address!("34F5A25B627f50Bb3f5cAb72807c4D4F405a9232");
+
+sol!(
+    #[sol(rpc)]
+    interface IMRC20 {
+        function withdraw(uint256 amount) external payable;
+    }
+
+    #[sol(rpc)]
+    interface IRootChainManager {
+        function exit(bytes calldata inputData) external;
+    }
+);
+
+/// polygon pos network the client connects to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolygonNetwork {
+    Mainnet,
+    Amoy,
+}
+
+impl PolygonNetwork {
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            PolygonNetwork::Mainnet => POLYGON_MAINNET_CHAIN_ID,
+            PolygonNetwork::Amoy => POLYGON_AMOY_CHAIN_ID,
+        }
+    }
+
+    /// public rpc endpoint of the network
+    pub fn default_rpc_url(&self) -> &'static str {
+        match self {
+            PolygonNetwork::Mainnet => POLYGON_MAINNET_RPC_URL,
+            PolygonNetwork::Amoy => POLYGON_AMOY_RPC_URL,
+        }
+    }
+
+    /// pos bridge `RootChainManager` on the root chain of the network
+    pub fn root_chain_manager(&self) -> Address {
+        match self {
+            PolygonNetwork::Mainnet => MAINNET_ROOT_CHAIN_MANAGER_ADDRESS,
+            PolygonNetwork::Amoy => AMOY_ROOT_CHAIN_MANAGER_ADDRESS,
+        }
+    }
+}
+
+/// client for interacting with the polygon pos chain, including withdrawals
+/// of the native token (matic, now pol) to ethereum over the pos bridge
+pub struct PolygonClient {
+    rpc_url: String,
+    network: PolygonNetwork,
+    signer: PrivateKeySigner,
+    connection_config: ConnectionConfig,
+}
+
+impl PolygonClient {
+    /// builds a client for the given network, connecting to `rpc_url` or the
+    /// public rpc endpoint of the network if none is given
+    pub fn new(
+        network: PolygonNetwork,
+        rpc_url: Option<&str>,
+        mnemonic: &str,
+        mnemonic_derivation_index: Option<u32>,
+    ) -> anyhow::Result<Self> {
+        let builder = MnemonicBuilder::<English>::default().phrase(mnemonic);
+
+        let derivation_index = mnemonic_derivation_index.unwrap_or_default();
+
+        let signer = builder.index(derivation_index)?.build()?;
+
+        Ok(Self {
+            rpc_url: rpc_url.unwrap_or(network.default_rpc_url()).to_string(),
+            network,
+            signer,
+            connection_config: ConnectionConfig::default(),
+        })
+    }
+
+    pub fn network(&self) -> PolygonNetwork {
+        self.network
+    }
+
+    /// returns the native token balance of the address, in wei
+    pub async fn get_matic_balance(&self, address: &Address) -> anyhow::Result<U256> {
+        self.query_balance(&address.to_string()).await
+    }
+
+    /// burns `amount` of the native token on polygon, starting a pos bridge
+    /// withdrawal. the withdrawal is completed on ethereum once the burn is
+    /// checkpointed, see [`PolygonClient::build_exit_tx`].
+    ///
+    /// pos bridge exits always pay out to the account that burned the tokens,
+    /// so `recipient` must be the signer of this client.
+    pub async fn bridge_matic_to_ethereum(
+        &self,
+        amount: U256,
+        recipient: &Address,
+    ) -> anyhow::Result<TransactionReceipt> {
+        anyhow::ensure!(
+            *recipient == self.signer.address(),
+            "pos bridge withdrawals are paid out to the signer {}, not {recipient}",
+            self.signer.address()
+        );
+
+        let client = self.get_request_provider().await?;
+
+        let token = IMRC20::new(NATIVE_TOKEN_ADDRESS, &client);
+
+        let withdraw_tx = token
+            .withdraw(amount)
+            .value(amount)
+            .into_transaction_request();
+
+        self.sign_and_send(withdraw_tx).await
+    }
+
+    /// builds the root chain tx completing a withdrawal. `exit_payload` is the
+    /// proof of the checkpointed burn as returned by the polygon proof
+    /// generation api. the tx is meant to be sent with an ethereum client.
+    pub fn build_exit_tx(&self, exit_payload: Bytes) -> TransactionRequest {
+        let exit_call = IRootChainManager::exitCall {
+            inputData: exit_payload,
+        };
+
+        TransactionRequest::default()
+            .to(self.network.root_chain_manager())
+            .input(exit_call.abi_encode().into())
+    }
+}
+
+#[async_trait]
+impl EvmBaseClient for PolygonClient {}
+
+#[async_trait]
+impl RequestProviderClient for PolygonClient {
+    fn rpc_url(&self) -> String {
+        self.rpc_url.clone()
+    }
+
+    fn signer(&self) -> PrivateKeySigner {
+        self.signer.clone()
+    }
+
+    fn connection_config(&self) -> Option<&ConnectionConfig> {
+        Some(&self.connection_config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::keccak256;
+
+    use crate::evm::chains::EvmChain;
+
+    use super::*;
+
+    const TEST_MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+    #[test]
+    fn networks_match_evm_chain_defaults() {
+        assert_eq!(
+            PolygonNetwork::Mainnet.chain_id(),
+            EvmChain::PolygonMainnet.chain_id()
+        );
+        assert_eq!(PolygonNetwork::Amoy.chain_id(), 80002);
+        assert_eq!(
+            PolygonNetwork::Amoy.default_rpc_url(),
+            EvmChain::PolygonAmoy.default_rpc_url()
+        );
+    }
+
+    #[test]
+    fn bridge_calls_are_abi_encoded() {
+        assert_eq!(
+            IMRC20::withdrawCall::SELECTOR,
+            keccak256("withdraw(uint256)")[..4]
+        );
+
+        let client = PolygonClient::new(PolygonNetwork::Amoy, None, TEST_MNEMONIC, None).unwrap();
+
+        let exit_tx = client.build_exit_tx(Bytes::from(vec![0xab; 40]));
+        let input = exit_tx.input.input().unwrap();
+
+        assert_eq!(exit_tx.to, Some(AMOY_ROOT_CHAIN_MANAGER_ADDRESS.into()));
+        assert_eq!(input[..4], keccak256("exit(bytes)")[..4]);
+        assert_eq!(
+            IRootChainManager::exitCall::abi_decode(input, true)
+                .unwrap()
+                .inputData,
+            Bytes::from(vec![0xab; 40])
+        );
+    }
+
+    #[tokio::test]
+    async fn bridging_rejects_foreign_recipients() {
+        let client =
+            PolygonClient::new(PolygonNetwork::Mainnet, None, TEST_MNEMONIC, None).unwrap();
+
+        assert!(client
+            .bridge_matic_to_ethereum(U256::from(1), &Address::ZERO)
+            .await
+            .is_err());
+    }
+}
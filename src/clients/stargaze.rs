@@ -0,0 +1,309 @@
+use std::str::FromStr;
+
+use cosmrs::{cosmwasm::MsgExecuteContract, tx::Msg, AccountId, Any, Coin};
+use serde::Deserialize;
+use serde_json::json;
+use tonic::async_trait;
+
+use crate::{
+    common::transaction::TransactionResponse,
+    cosmos::{
+        base_client::BaseClient,
+        grpc_client::{GrpcChannelCache, GrpcSigningClient},
+        wasm_client::WasmClient,
+    },
+};
+
+const CHAIN_NAME: &str = "stargaze";
+const CHAIN_PREFIX: &str = "stars";
+const CHAIN_DENOM: &str = "ustars";
+
+/// client for interacting with the stargaze chain. marketplace operations
+/// require the marketplace (v2) contract to be configured with
+/// [`StargazeClient::with_marketplace`].
+pub struct StargazeClient {
+    grpc_url: String,
+    mnemonic: String,
+    chain_id: String,
+    chain_denom: String,
+    gas_price: f64,
+    channel_cache: GrpcChannelCache,
+    marketplace_addr: Option<String>,
+}
+
+impl StargazeClient {
+    pub async fn new(
+        rpc_url: &str,
+        rpc_port: &str,
+        mnemonic: &str,
+        chain_id: &str,
+    ) -> anyhow::Result<Self> {
+        let avg_gas_price = Self::query_chain_gas_config(CHAIN_NAME, CHAIN_DENOM).await?;
+
+        Ok(Self {
+            grpc_url: format!("{rpc_url}:{rpc_port}"),
+            mnemonic: mnemonic.to_string(),
+            chain_id: chain_id.to_string(),
+            chain_denom: CHAIN_DENOM.to_string(),
+            gas_price: avg_gas_price,
+            channel_cache: GrpcChannelCache::default(),
+            marketplace_addr: None,
+        })
+    }
+
+    /// sets the marketplace contract used for listing queries and sales
+    pub fn with_marketplace(mut self, marketplace_addr: &str) -> Self {
+        self.marketplace_addr = Some(marketplace_addr.to_string());
+        self
+    }
+
+    fn marketplace(&self) -> anyhow::Result<&str> {
+        self.marketplace_addr
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("no marketplace contract configured"))
+    }
+
+    pub async fn query_nft_owner(
+        &self,
+        collection: &str,
+        token_id: &str,
+    ) -> anyhow::Result<String> {
+        let response: OwnerOfResponse = self
+            .query_contract_state(collection, json!({ "owner_of": { "token_id": token_id } }))
+            .await?;
+
+        Ok(response.owner)
+    }
+
+    pub async fn query_nft_info(
+        &self,
+        collection: &str,
+        token_id: &str,
+    ) -> anyhow::Result<NftInfo> {
+        let response: AllNftInfoResponse = self
+            .query_contract_state(
+                collection,
+                json!({ "all_nft_info": { "token_id": token_id } }),
+            )
+            .await?;
+
+        Ok(NftInfo {
+            token_id: token_id.to_string(),
+            owner: response.access.owner,
+            token_uri: response.info.token_uri,
+            extension: response.info.extension,
+        })
+    }
+
+    pub async fn transfer_nft(
+        &self,
+        collection: &str,
+        token_id: &str,
+        recipient: &str,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let transfer_msg = json!({
+            "transfer_nft": {
+                "recipient": recipient,
+                "token_id": token_id,
+            }
+        });
+
+        let msgs = vec![self.execute_msg(collection, transfer_msg, vec![]).await?];
+
+        self.estimate_and_broadcast_multi(msgs, memo).await
+    }
+
+    /// returns the active marketplace ask for the nft, if it is listed
+    pub async fn query_marketplace_listing(
+        &self,
+        collection: &str,
+        token_id: &str,
+    ) -> anyhow::Result<Option<MarketplaceListing>> {
+        let ask: Option<Ask> = self
+            .query_contract_state(
+                self.marketplace()?,
+                json!({ "ask": { "collection": collection, "token_id": token_id } }),
+            )
+            .await?;
+
+        ask.map(MarketplaceListing::try_from).transpose()
+    }
+
+    /// approves the marketplace to escrow the nft and lists it for the given
+    /// price within a single transaction
+    pub async fn list_nft_for_sale(
+        &self,
+        collection: &str,
+        token_id: &str,
+        price: Coin,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let marketplace = self.marketplace()?;
+
+        let approve_msg = json!({
+            "approve": {
+                "spender": marketplace,
+                "token_id": token_id,
+            }
+        });
+
+        let set_ask_msg = json!({
+            "set_ask": {
+                "collection": collection,
+                "token_id": token_id,
+                "details": {
+                    "price": {
+                        "denom": price.denom.to_string(),
+                        "amount": price.amount.to_string(),
+                    },
+                },
+            }
+        });
+
+        let msgs = vec![
+            self.execute_msg(collection, approve_msg, vec![]).await?,
+            self.execute_msg(marketplace, set_ask_msg, vec![]).await?,
+        ];
+
+        self.estimate_and_broadcast_multi(msgs, memo).await
+    }
+
+    async fn execute_msg(
+        &self,
+        contract: &str,
+        msg: serde_json::Value,
+        funds: Vec<Coin>,
+    ) -> anyhow::Result<Any> {
+        let signing_client = self.get_signing_client().await?;
+
+        MsgExecuteContract {
+            sender: signing_client.address,
+            contract: AccountId::from_str(contract).map_err(|e| {
+                anyhow::anyhow!("failed to parse contract addr into AccountId: {e}")
+            })?,
+            msg: serde_json::to_vec(&msg)?,
+            funds,
+        }
+        .to_any()
+        .map_err(|e| anyhow::anyhow!("failed to convert MsgExecuteContract to proto Any: {e}"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NftInfo {
+    pub token_id: String,
+    pub owner: String,
+    pub token_uri: Option<String>,
+    /// collection specific metadata
+    pub extension: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketplaceListing {
+    pub price: Coin,
+    pub seller: String,
+}
+
+impl TryFrom<Ask> for MarketplaceListing {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Ask) -> anyhow::Result<Self> {
+        Ok(MarketplaceListing {
+            price: Coin {
+                denom: value
+                    .details
+                    .price
+                    .denom
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Failed to parse denom: {e}"))?,
+                amount: value.details.price.amount.parse()?,
+            },
+            seller: value.creator,
+        })
+    }
+}
+
+// cw721 and marketplace v2 query responses. only the fields used by the
+// client are deserialized.
+
+#[derive(Deserialize)]
+struct OwnerOfResponse {
+    owner: String,
+}
+
+#[derive(Deserialize)]
+struct AllNftInfoResponse {
+    access: OwnerOfResponse,
+    info: NftInfoResponse,
+}
+
+#[derive(Deserialize)]
+struct NftInfoResponse {
+    token_uri: Option<String>,
+    #[serde(default)]
+    extension: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct Ask {
+    creator: String,
+    details: OrderDetails,
+}
+
+#[derive(Deserialize)]
+struct OrderDetails {
+    price: JsonCoin,
+}
+
+#[derive(Deserialize)]
+struct JsonCoin {
+    denom: String,
+    amount: String,
+}
+
+#[async_trait]
+impl BaseClient for StargazeClient {}
+
+#[async_trait]
+impl WasmClient for StargazeClient {}
+
+#[async_trait]
+impl GrpcSigningClient for StargazeClient {
+    fn grpc_url(&self) -> String {
+        self.grpc_url.to_string()
+    }
+
+    fn mnemonic(&self) -> String {
+        self.mnemonic.to_string()
+    }
+
+    fn chain_prefix(&self) -> String {
+        CHAIN_PREFIX.to_string()
+    }
+
+    fn chain_id(&self) -> String {
+        self.chain_id.to_string()
+    }
+
+    fn chain_denom(&self) -> String {
+        self.chain_denom.to_string()
+    }
+
+    fn gas_price(&self) -> f64 {
+        self.gas_price
+    }
+
+    fn set_gas_price(&mut self, gas_price: f64) -> anyhow::Result<()> {
+        self.gas_price = gas_price;
+        Ok(())
+    }
+
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        Some(&self.channel_cache)
+    }
+
+    fn gas_adjustment(&self) -> f64 {
+        1.8
+    }
+}
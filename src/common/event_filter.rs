@@ -0,0 +1,204 @@
+#[cfg(feature = "evm")]
+use alloy::{
+    primitives::{Address, B256},
+    rpc::types::{Log, TransactionReceipt},
+};
+
+use super::transaction::{Event, TransactionResponse};
+
+/// predicate over cosmos events and evm logs.
+/// filters only match the kind of events they were built for, so the
+/// default implementations match nothing.
+pub trait EventFilter {
+    fn matches_cosmos(&self, _event: &Event) -> bool {
+        false
+    }
+
+    #[cfg(feature = "evm")]
+    fn matches_evm_log(&self, _log: &Log) -> bool {
+        false
+    }
+}
+
+/// matches cosmos events by type and attribute. unset fields match anything.
+/// if both `attribute_key` and `attribute_value` are set, they have to match
+/// the same attribute.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CosmosEventFilter {
+    pub event_type: Option<String>,
+    pub attribute_key: Option<String>,
+    pub attribute_value: Option<String>,
+}
+
+impl EventFilter for CosmosEventFilter {
+    fn matches_cosmos(&self, event: &Event) -> bool {
+        if self
+            .event_type
+            .as_ref()
+            .is_some_and(|event_type| *event_type != event.event_type)
+        {
+            return false;
+        }
+
+        if self.attribute_key.is_none() && self.attribute_value.is_none() {
+            return true;
+        }
+
+        event.attributes.iter().any(|attr| {
+            self.attribute_key
+                .as_ref()
+                .is_none_or(|key| *key == attr.key)
+                && self
+                    .attribute_value
+                    .as_ref()
+                    .is_none_or(|value| *value == attr.value)
+        })
+    }
+}
+
+/// matches evm logs by emitting contract and event signature.
+/// unset fields match anything.
+#[cfg(feature = "evm")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EvmEventFilter {
+    pub contract: Option<Address>,
+    pub topic0: Option<B256>,
+}
+
+#[cfg(feature = "evm")]
+impl EventFilter for EvmEventFilter {
+    fn matches_evm_log(&self, log: &Log) -> bool {
+        self.contract
+            .is_none_or(|contract| contract == log.address())
+            && self
+                .topic0
+                .as_ref()
+                .is_none_or(|topic0| Some(topic0) == log.topic0())
+    }
+}
+
+/// how a `CompositeFilter` combines its filters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterMode {
+    /// every filter has to match
+    All,
+    /// at least one filter has to match
+    Any,
+}
+
+/// combines multiple filters into one
+pub struct CompositeFilter {
+    pub filters: Vec<Box<dyn EventFilter + Send + Sync>>,
+    pub mode: FilterMode,
+}
+
+impl CompositeFilter {
+    fn combine(&self, matches: impl Fn(&dyn EventFilter) -> bool) -> bool {
+        match self.mode {
+            FilterMode::All => self.filters.iter().all(|f| matches(f.as_ref())),
+            FilterMode::Any => self.filters.iter().any(|f| matches(f.as_ref())),
+        }
+    }
+}
+
+impl EventFilter for CompositeFilter {
+    fn matches_cosmos(&self, event: &Event) -> bool {
+        self.combine(|filter| filter.matches_cosmos(event))
+    }
+
+    #[cfg(feature = "evm")]
+    fn matches_evm_log(&self, log: &Log) -> bool {
+        self.combine(|filter| filter.matches_evm_log(log))
+    }
+}
+
+impl TransactionResponse {
+    /// returns all events matching the filter
+    pub fn filter_events<F: EventFilter>(&self, filter: &F) -> Vec<&Event> {
+        self.events
+            .iter()
+            .filter(|event| filter.matches_cosmos(event))
+            .collect()
+    }
+}
+
+/// returns all logs of the receipt matching the filter
+#[cfg(feature = "evm")]
+pub fn filter_receipt_logs<'a, F: EventFilter>(
+    receipt: &'a TransactionReceipt,
+    filter: &F,
+) -> Vec<&'a Log> {
+    receipt
+        .inner
+        .logs()
+        .iter()
+        .filter(|log| filter.matches_evm_log(log))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::transaction::EventAttribute;
+
+    fn event(event_type: &str, attributes: &[(&str, &str)]) -> Event {
+        Event {
+            event_type: event_type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| EventAttribute {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn cosmos_filters_match_type_and_attribute_pairs() {
+        let tx = TransactionResponse {
+            hash: "hash".to_string(),
+            success: true,
+            block_height: 1,
+            gas_used: 1,
+            events: vec![
+                event("transfer", &[("recipient", "neutron1a"), ("amount", "5")]),
+                event("transfer", &[("recipient", "neutron1b"), ("amount", "7")]),
+                event("wasm", &[("action", "swap")]),
+            ],
+        };
+
+        let to_b = CosmosEventFilter {
+            event_type: Some("transfer".to_string()),
+            attribute_key: Some("recipient".to_string()),
+            attribute_value: Some("neutron1b".to_string()),
+        };
+        assert_eq!(tx.filter_events(&to_b).len(), 1);
+
+        // key and value of different attributes do not match
+        let mismatched = CosmosEventFilter {
+            attribute_key: Some("recipient".to_string()),
+            attribute_value: Some("7".to_string()),
+            ..Default::default()
+        };
+        assert!(tx.filter_events(&mismatched).is_empty());
+
+        let any = CompositeFilter {
+            filters: vec![
+                Box::new(to_b),
+                Box::new(CosmosEventFilter {
+                    event_type: Some("wasm".to_string()),
+                    ..Default::default()
+                }),
+            ],
+            mode: FilterMode::Any,
+        };
+        assert_eq!(tx.filter_events(&any).len(), 2);
+
+        let all = CompositeFilter {
+            mode: FilterMode::All,
+            ..any
+        };
+        assert!(tx.filter_events(&all).is_empty());
+    }
+}
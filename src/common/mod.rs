@@ -1 +1,5 @@
+pub mod event_filter;
+pub mod portfolio;
+#[cfg(feature = "tls")]
+pub mod tls;
 pub mod transaction;
@@ -0,0 +1,151 @@
+use std::{collections::HashMap, future::Future};
+
+#[cfg(feature = "evm")]
+use alloy::primitives::Address;
+
+#[cfg(feature = "cosmos")]
+use crate::cosmos::{base_client::BaseClient, utils::collect_all_pages};
+#[cfg(feature = "evm")]
+use crate::evm::base_client::EvmBaseClient;
+
+/// denom under which native evm balances (in wei) are reported
+pub const EVM_NATIVE_DENOM: &str = "wei";
+
+/// balance of a single asset held by an address
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetBalance {
+    pub denom: String,
+    pub amount: String,
+    pub usd_value: Option<f64>,
+}
+
+/// balances held by an address on a chain
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortfolioEntry {
+    pub chain: String,
+    pub address: String,
+    pub balances: Vec<AssetBalance>,
+}
+
+/// holdings across multiple chains and addresses
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PortfolioSnapshot {
+    pub entries: Vec<PortfolioEntry>,
+}
+
+impl PortfolioSnapshot {
+    /// runs all entry queries concurrently, failing if any of them fails.
+    /// queries of different client types can be mixed by boxing them.
+    ///
+    /// ```ignore
+    /// use futures::FutureExt;
+    ///
+    /// let snapshot = PortfolioSnapshot::collect(vec![
+    ///     query_cosmos_entry(&neutron, "neutron", neutron_addr).boxed(),
+    ///     query_evm_entry(&ethereum, "ethereum", eth_addr).boxed(),
+    /// ])
+    /// .await?;
+    /// ```
+    pub async fn collect<Fut>(queries: Vec<Fut>) -> anyhow::Result<Self>
+    where
+        Fut: Future<Output = anyhow::Result<PortfolioEntry>>,
+    {
+        let entries = futures::future::try_join_all(queries).await?;
+
+        Ok(PortfolioSnapshot { entries })
+    }
+
+    /// sums the balances of every entry per denom. amounts that do not
+    /// parse as integers are skipped.
+    pub fn total_by_denom(&self) -> HashMap<String, u128> {
+        let mut totals: HashMap<String, u128> = HashMap::new();
+
+        for balance in self.entries.iter().flat_map(|entry| &entry.balances) {
+            if let Ok(amount) = balance.amount.parse::<u128>() {
+                *totals.entry(balance.denom.clone()).or_default() += amount;
+            }
+        }
+
+        totals
+    }
+}
+
+/// queries all bank balances of the address
+#[cfg(feature = "cosmos")]
+pub async fn query_cosmos_entry<C: BaseClient + Sync>(
+    client: &C,
+    chain: &str,
+    address: &str,
+) -> anyhow::Result<PortfolioEntry> {
+    let balances = collect_all_pages(100, |page| client.query_all_balances_paged(address, page))
+        .await?
+        .into_iter()
+        .map(|coin| AssetBalance {
+            denom: coin.denom.to_string(),
+            amount: coin.amount.to_string(),
+            usd_value: None,
+        })
+        .collect();
+
+    Ok(PortfolioEntry {
+        chain: chain.to_string(),
+        address: address.to_string(),
+        balances,
+    })
+}
+
+/// queries the native balance of the address, reported as `wei`
+#[cfg(feature = "evm")]
+pub async fn query_evm_entry<C: EvmBaseClient + Sync>(
+    client: &C,
+    chain: &str,
+    address: Address,
+) -> anyhow::Result<PortfolioEntry> {
+    let balance = client.query_balance(&address.to_string()).await?;
+
+    Ok(PortfolioEntry {
+        chain: chain.to_string(),
+        address: address.to_string(),
+        balances: vec![AssetBalance {
+            denom: EVM_NATIVE_DENOM.to_string(),
+            amount: balance.to_string(),
+            usd_value: None,
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(chain: &str, balances: &[(&str, &str)]) -> PortfolioEntry {
+        PortfolioEntry {
+            chain: chain.to_string(),
+            address: format!("{chain}1addr"),
+            balances: balances
+                .iter()
+                .map(|(denom, amount)| AssetBalance {
+                    denom: denom.to_string(),
+                    amount: amount.to_string(),
+                    usd_value: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn snapshot_totals_balances_per_denom() {
+        let snapshot = PortfolioSnapshot::collect(vec![
+            futures::future::ready(Ok(entry("neutron", &[("uusdc", "100"), ("untrn", "5")]))),
+            futures::future::ready(Ok(entry("osmosis", &[("uusdc", "250")]))),
+        ])
+        .await
+        .unwrap();
+
+        let totals = snapshot.total_by_denom();
+
+        assert_eq!(snapshot.entries.len(), 2);
+        assert_eq!(totals["uusdc"], 350);
+        assert_eq!(totals["untrn"], 5);
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// tls material for connecting to rpc nodes which use private certificate
+/// authorities or require client (mtls) authentication.
+///
+/// all certificates and keys are expected to be pem encoded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// certificate authority to trust in addition to the native roots
+    pub ca_cert: Option<Vec<u8>>,
+    /// client certificate presented for mtls
+    pub client_cert: Option<Vec<u8>>,
+    /// private key (pkcs8) of the client certificate
+    pub client_key: Option<Vec<u8>>,
+    /// disables server certificate verification. only available with the
+    /// `danger-skip-tls-verify` feature to prevent accidental production use.
+    #[cfg(feature = "danger-skip-tls-verify")]
+    pub skip_verify: bool,
+}
+
+impl TlsConfig {
+    #[cfg(any(feature = "cosmos", feature = "evm"))]
+    fn client_identity(&self) -> anyhow::Result<Option<(&[u8], &[u8])>> {
+        match (&self.client_cert, &self.client_key) {
+            (Some(cert), Some(key)) => Ok(Some((cert, key))),
+            (None, None) => Ok(None),
+            _ => Err(anyhow::anyhow!(
+                "client_cert and client_key must be provided together"
+            )),
+        }
+    }
+
+    /// builds the tls config for tonic grpc channels
+    #[cfg(feature = "cosmos")]
+    pub fn client_tls_config(&self) -> anyhow::Result<tonic::transport::ClientTlsConfig> {
+        use tonic::transport::{Certificate, ClientTlsConfig, Identity};
+
+        #[cfg(feature = "danger-skip-tls-verify")]
+        if self.skip_verify {
+            return Err(anyhow::anyhow!(
+                "skip_verify is not supported for grpc channels"
+            ));
+        }
+
+        let mut tls_config = ClientTlsConfig::new().with_native_roots();
+
+        if let Some(ca_cert) = &self.ca_cert {
+            tls_config = tls_config.ca_certificate(Certificate::from_pem(ca_cert));
+        }
+
+        if let Some((cert, key)) = self.client_identity()? {
+            tls_config = tls_config.identity(Identity::from_pem(cert, key));
+        }
+
+        Ok(tls_config)
+    }
+
+    /// builds a reqwest client for http json-rpc providers
+    #[cfg(feature = "evm")]
+    pub fn reqwest_client(&self) -> anyhow::Result<alloy::transports::http::reqwest::Client> {
+        use alloy::transports::http::reqwest::{Certificate, Client, Identity};
+
+        let mut builder = Client::builder();
+
+        if let Some(ca_cert) = &self.ca_cert {
+            builder = builder.add_root_certificate(Certificate::from_pem(ca_cert)?);
+        }
+
+        if let Some((cert, key)) = self.client_identity()? {
+            builder = builder.identity(Identity::from_pkcs8_pem(cert, key)?);
+        }
+
+        #[cfg(feature = "danger-skip-tls-verify")]
+        if self.skip_verify {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+/// tls config held by a client for its connection setup. it is set through
+/// the `with_tls_config` builders of the client traits and shared by the
+/// clones of the client.
+#[derive(Debug, Clone, Default)]
+pub struct SharedTlsConfig(Arc<RwLock<Option<TlsConfig>>>);
+
+impl SharedTlsConfig {
+    pub fn get(&self) -> Option<TlsConfig> {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub fn set(&self, tls_config: TlsConfig) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = Some(tls_config);
+    }
+}
+
+#[cfg(all(test, feature = "cosmos"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_client_identity_fails_before_connecting() {
+        let tls_config = TlsConfig {
+            client_cert: Some(b"not a certificate".to_vec()),
+            client_key: Some(b"not a key".to_vec()),
+            ..Default::default()
+        };
+
+        let endpoint = tonic::transport::Channel::from_static("https://localhost:9090");
+
+        // the identity is parsed when the endpoint is configured, so a bad
+        // certificate surfaces before any connection or rpc is attempted
+        assert!(endpoint
+            .tls_config(tls_config.client_tls_config().unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn client_cert_requires_key() {
+        let tls_config = TlsConfig {
+            client_cert: Some(b"cert".to_vec()),
+            ..Default::default()
+        };
+
+        assert!(tls_config.client_tls_config().is_err());
+    }
+}
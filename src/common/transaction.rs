@@ -7,6 +7,70 @@ pub struct TransactionResponse {
     pub success: bool,
     pub block_height: u64,
     pub gas_used: u64,
+    /// events emitted by the transaction. only populated for responses of
+    /// included transactions (e.g. after polling), not for sync broadcasts.
+    pub events: Vec<Event>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub event_type: String,
+    pub attributes: Vec<EventAttribute>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventAttribute {
+    pub key: String,
+    pub value: String,
+}
+
+impl Event {
+    /// returns the value of the first attribute with the given key
+    pub fn get_attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    }
+}
+
+impl TransactionResponse {
+    /// returns the first event of the given type
+    pub fn find_event(&self, event_type: &str) -> Option<&Event> {
+        self.events.iter().find(|e| e.event_type == event_type)
+    }
+
+    /// returns all events of the given type
+    pub fn find_events(&self, event_type: &str) -> Vec<&Event> {
+        self.events
+            .iter()
+            .filter(|e| e.event_type == event_type)
+            .collect()
+    }
+
+    /// returns the address of the first contract instantiated by the tx.
+    /// wasmd emits it as `_contract_address`, while some older versions used
+    /// `contract_address` and an `instantiate_contract` event type.
+    pub fn get_contract_address(&self) -> Option<String> {
+        self.events
+            .iter()
+            .filter(|e| e.event_type == "instantiate" || e.event_type == "instantiate_contract")
+            .find_map(|e| {
+                e.get_attribute("_contract_address")
+                    .or_else(|| e.get_attribute("contract_address"))
+            })
+            .map(str::to_string)
+    }
+
+    /// returns the value of the first attribute with the given key emitted by
+    /// a contract, scanning both `wasm` and custom `wasm-*` events
+    pub fn get_wasm_attribute(&self, key: &str) -> Option<String> {
+        self.events
+            .iter()
+            .filter(|e| e.event_type == "wasm" || e.event_type.starts_with("wasm-"))
+            .find_map(|e| e.get_attribute(key))
+            .map(str::to_string)
+    }
 }
 
 #[cfg(feature = "cosmos")]
@@ -14,11 +78,48 @@ impl TryFrom<TxResponse> for TransactionResponse {
     type Error = anyhow::Error;
 
     fn try_from(value: TxResponse) -> anyhow::Result<Self> {
+        let mut events: Vec<Event> = value
+            .events
+            .into_iter()
+            .map(|e| Event {
+                event_type: e.r#type,
+                attributes: e
+                    .attributes
+                    .into_iter()
+                    .map(|a| EventAttribute {
+                        key: a.key,
+                        value: a.value,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        // sdk versions prior to v0.50 only populate the per-message logs
+        if events.is_empty() {
+            events = value
+                .logs
+                .into_iter()
+                .flat_map(|log| log.events)
+                .map(|e| Event {
+                    event_type: e.r#type,
+                    attributes: e
+                        .attributes
+                        .into_iter()
+                        .map(|a| EventAttribute {
+                            key: a.key,
+                            value: a.value,
+                        })
+                        .collect(),
+                })
+                .collect();
+        }
+
         Ok(Self {
             hash: value.txhash,
             success: value.code == 0, // 0 is success
             block_height: u64::try_from(value.height)?,
             gas_used: u64::try_from(value.gas_used)?,
+            events,
         })
     }
 }
@@ -34,3 +135,48 @@ impl TryFrom<Option<TxResponse>> for TransactionResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event_type: &str, attributes: &[(&str, &str)]) -> Event {
+        Event {
+            event_type: event_type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| EventAttribute {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn event_helpers_find_contract_data() {
+        let tx = TransactionResponse {
+            hash: "hash".to_string(),
+            success: true,
+            block_height: 1,
+            gas_used: 1,
+            events: vec![
+                event("message", &[("action", "instantiate")]),
+                event("instantiate", &[("_contract_address", "neutron1abc")]),
+                event(
+                    "wasm-custom",
+                    &[("_contract_address", "neutron1abc"), ("id", "7")],
+                ),
+            ],
+        };
+
+        assert_eq!(tx.get_contract_address().as_deref(), Some("neutron1abc"));
+        assert_eq!(tx.get_wasm_attribute("id").as_deref(), Some("7"));
+        assert_eq!(tx.get_wasm_attribute("missing"), None);
+        assert_eq!(tx.find_events("message").len(), 1);
+        assert_eq!(
+            tx.find_event("message").unwrap().get_attribute("action"),
+            Some("instantiate")
+        );
+    }
+}
@@ -64,6 +64,13 @@ pub trait CoprocessorBaseClient {
     /// Returns statistics of the running instance.
     async fn stats(&self) -> anyhow::Result<Value>;
 
+    /// Checks if the co-processor service is reachable and responding.
+    ///
+    /// Defaults to querying the stats, as the service has no dedicated health route.
+    async fn health_check(&self) -> anyhow::Result<bool> {
+        Ok(self.stats().await.is_ok())
+    }
+
     /// Co-processor historical root.
     async fn root(&self) -> anyhow::Result<String>;
 
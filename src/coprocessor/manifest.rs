@@ -120,6 +120,30 @@ impl Manifest {
     }
 }
 
+impl Manifest {
+    /// Reads the built circuit and controller artifacts of the named circuit.
+    ///
+    /// The artifacts dir is resolved relative to the manifest parent dir.
+    pub fn load_artifacts<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        name: &str,
+    ) -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
+        let artifacts = dir
+            .as_ref()
+            .join(&self.valence.artifacts)
+            .join(name)
+            .canonicalize()?;
+
+        info!("Loading artifacts from `{}`...", artifacts.display());
+
+        let circuit = fs::read(artifacts.join("circuit.bin"))?;
+        let controller = fs::read(artifacts.join("controller.bin"))?;
+
+        Ok((circuit, controller))
+    }
+}
+
 #[test]
 fn parse_manifest_works() {
     let manifest = r#"[valence]
@@ -1,28 +1,76 @@
-use std::str::FromStr;
+use std::{collections::HashSet, str::FromStr};
 
 use async_trait::async_trait;
 use cosmos_sdk_proto::cosmos::{
     auth::v1beta1::{
-        ModuleAccount, QueryModuleAccountByNameRequest, QueryModuleAccountByNameResponse,
+        ModuleAccount, QueryAccountRequest, QueryModuleAccountByNameRequest,
+        QueryModuleAccountByNameResponse,
+    },
+    bank::v1beta1::{
+        QueryAllBalancesRequest, QueryBalanceRequest, QueryBalanceResponse,
+        QueryDenomMetadataRequest, QueryParamsRequest as QueryBankParamsRequest,
+        QuerySendEnabledRequest, QuerySpendableBalanceByDenomRequest, QuerySupplyOfRequest,
+        QueryTotalSupplyRequest,
     },
-    bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse},
     base::{abci::v1beta1::TxResponse, tendermint::v1beta1::Header},
-    tx::v1beta1::GetTxRequest,
+    distribution::v1beta1::{
+        QueryCommunityPoolRequest, QueryParamsRequest as QueryDistributionParamsRequest,
+        QueryValidatorCommissionRequest, QueryValidatorOutstandingRewardsRequest,
+        QueryValidatorSlashesRequest, ValidatorSlashEvent,
+    },
+    evidence::v1beta1::{MsgSubmitEvidence, QueryAllEvidenceRequest, QueryEvidenceRequest},
+    gov::v1beta1::{MsgVote, QueryProposalRequest, QueryProposalsRequest},
+    mint::v1beta1::{
+        QueryAnnualProvisionsRequest, QueryInflationRequest,
+        QueryParamsRequest as QueryMintParamsRequest,
+    },
+    params::v1beta1::{QueryParamsRequest, QuerySubspacesRequest},
+    slashing::v1beta1::{
+        QueryParamsRequest as QuerySlashingParamsRequest, QuerySigningInfoRequest,
+    },
+    staking::v1beta1::{
+        BondStatus, DelegationResponse, QueryDelegatorDelegationsRequest,
+        QueryParamsRequest as QueryStakingParamsRequest, QueryValidatorRequest,
+        QueryValidatorsRequest, Validator,
+    },
+    tx::v1beta1::{
+        BroadcastMode, BroadcastTxRequest, GetTxRequest, GetTxsEventRequest, SimulateResponse, Tx,
+    },
+    upgrade::v1beta1::{
+        QueryAppliedPlanRequest, QueryCurrentPlanRequest, QueryModuleVersionsRequest,
+    },
 };
 
 use cosmrs::{
     bank::MsgSend,
+    distribution::MsgSetWithdrawAddress,
     rpc::{Client, HttpClient},
-    tendermint::block::Height,
-    tx::Msg,
+    tendermint::{block::Height, Hash},
+    tx::{Fee, Msg},
     AccountId, Coin, Denom,
 };
 use cosmrs::{
     proto::cosmos::base::tendermint::v1beta1::{
         service_client::ServiceClient as TendermintServiceClient, GetLatestBlockRequest,
+        GetNodeInfoRequest,
     },
     Any,
 };
+use ibc_proto::ibc::{
+    applications::fee::v1::{
+        MsgPayPacketFee, MsgPayPacketFeeAsync, PacketFee, QueryFeeEnabledChannelsRequest,
+        QueryIncentivizedPacketsForChannelRequest,
+    },
+    applications::interchain_accounts::controller::v1::{
+        MsgRegisterInterchainAccount, QueryInterchainAccountRequest,
+    },
+    applications::transfer::v1::QueryParamsRequest as QueryIbcTransferParamsRequest,
+    core::channel::v1::{
+        Order, PacketId, QueryChannelsRequest, QueryPacketAcknowledgementsRequest,
+        QueryPacketCommitmentsRequest, QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
+        State,
+    },
+};
 use log::{info, warn};
 use prost::Message;
 use tonic::Request;
@@ -30,8 +78,25 @@ use tonic::Request;
 use crate::common::transaction::TransactionResponse;
 
 use super::{
-    grpc_client::GrpcSigningClient, proto_timestamp::ProtoTimestamp, AuthQueryClient,
-    BankQueryClient, CosmosServiceClient,
+    errors::CosmosError,
+    grpc_client::GrpcSigningClient,
+    proto_timestamp::ProtoTimestamp,
+    types::{
+        dec_coins_to_coins, multi_send_msg, parse_dec, proto_coins_to_coins, BankParams,
+        CosmosBlockResults, CosmosDelegation, CosmosProposal, CosmosValidator, DenomMetadata,
+        DistributionParams, EvidenceInfo, FeeMarketGasPriceRequest, FeeMarketGasPriceResponse,
+        FeeMarketParams, FeeMarketParamsRequest, FeeMarketParamsResponse, HealthStatus,
+        IbcFeeParams, IbcTransferParams, IcaChannel, IncentivisedPacket, MintParams, ModuleVersion,
+        MultiHopTransferParams, MultiSendInput, MultiSendOutput, NodeInfo, PacketState,
+        PageRequest, PageResponse, ParamSubspace, PollConfig, ProposalStatus, QueryOptions,
+        SigningInfo, SlashEvent, SlashingParams, StakingParams, UpgradePlan, VestingInfo,
+        VoteOption,
+    },
+    utils::{collect_all_pages, convert_bech32_prefix},
+    AuthQueryClient, BankQueryClient, CosmosServiceClient, DistributionQueryClient,
+    EvidenceQueryClient, GovQueryClient, IbcChannelQueryClient, IbcFeeQueryClient,
+    IbcTransferQueryClient, IcaControllerQueryClient, MintQueryClient, ParamsQueryClient,
+    SlashingQueryClient, StakingQueryClient, UpgradeQueryClient,
 };
 
 /// base client trait with default implementations for cosmos-sdk based clients.
@@ -83,11 +148,199 @@ pub trait BaseClient: GrpcSigningClient {
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         TransactionResponse::try_from(broadcast_tx_response.tx_response)
     }
 
+    /// same as `transfer`, but first re-encodes the recipient with
+    /// `target_prefix`, e.g. to send to the osmosis account of a `cosmos1..`
+    /// address
+    async fn transfer_cross_prefix(
+        &self,
+        to_address: &str,
+        target_prefix: &str,
+        amount: u128,
+        denom: &str,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let to = convert_bech32_prefix(to_address, target_prefix)?;
+
+        self.transfer(&to, amount, denom, memo).await
+    }
+
+    /// sends multiple denoms to the recipient within a single `MsgSend`
+    async fn transfer_multi_denom(
+        &self,
+        to: &str,
+        amounts: &[Coin],
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let transfer_msg = multi_denom_send_msg(&signing_client.address, to, amounts)?;
+
+        self.sign_and_broadcast_tx(transfer_msg, None, memo).await
+    }
+
+    /// moves coins from the inputs to the outputs in a single `MsgMultiSend`.
+    /// every input address has to be the signer, as it is the only one
+    /// signing the tx. recent sdk versions also allow just one input.
+    async fn multi_send(
+        &self,
+        inputs: Vec<MultiSendInput>,
+        outputs: Vec<MultiSendOutput>,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let multi_send = multi_send_msg(inputs, outputs)?
+            .to_any()
+            .map_err(|e| anyhow::anyhow!("Failed to convert to Any: {e}"))?;
+
+        self.estimate_and_broadcast(multi_send, memo).await
+    }
+
+    /// sends coins to multiple recipients with one `MsgSend` per recipient,
+    /// all included in a single tx
+    async fn transfer_to_many(
+        &self,
+        recipients: &[(&str, &[Coin])],
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        if recipients.is_empty() {
+            return Err(anyhow::anyhow!("no transfer recipients provided"));
+        }
+
+        let signing_client = self.get_signing_client().await?;
+
+        let msgs = recipients
+            .iter()
+            .map(|(to, amounts)| multi_denom_send_msg(&signing_client.address, to, amounts))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        self.estimate_and_broadcast_multi(msgs, memo).await
+    }
+
+    /// simulates the msg, derives the fee from the simulated gas (scaled by
+    /// the client's gas adjustment) and broadcasts the signed tx
+    async fn estimate_and_broadcast(
+        &self,
+        msg: Any,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        self.estimate_and_broadcast_multi(vec![msg], memo).await
+    }
+
+    /// same as `estimate_and_broadcast` for a tx with multiple msgs
+    async fn estimate_and_broadcast_multi(
+        &self,
+        msgs: Vec<Any>,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let fee = self.estimate_fee_for_multi_msg(msgs.clone()).await?;
+
+        TransactionResponse::try_from(self.broadcast_multi_with_fee(msgs, fee, memo).await?)
+    }
+
+    /// signs the msgs with the given fee and broadcasts the tx in sync mode,
+    /// returning the raw CheckTx response
+    async fn broadcast_multi_with_fee(
+        &self,
+        msgs: Vec<Any>,
+        fee: Fee,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TxResponse> {
+        let signing_client = self.get_signing_client().await?;
+        let channel = self.get_grpc_channel().await?;
+
+        let raw_tx = signing_client.create_multi_msg_tx(msgs, fee, memo).await?;
+
+        let mut grpc_client = CosmosServiceClient::new(channel);
+
+        grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner()
+            .tx_response
+            .ok_or_else(|| anyhow::anyhow!("failed to find tx_response"))
+    }
+
+    /// broadcasts the msg via `estimate_and_broadcast` and waits for the tx
+    /// to be included, returning the response of the confirmed tx
+    async fn broadcast_and_wait(
+        &self,
+        msg: Any,
+        memo: Option<&str>,
+        poll_config: PollConfig,
+    ) -> anyhow::Result<TransactionResponse> {
+        self.broadcast_multi_and_wait(vec![msg], memo, poll_config)
+            .await
+    }
+
+    /// same as `broadcast_and_wait` for a tx with multiple msgs
+    async fn broadcast_multi_and_wait(
+        &self,
+        msgs: Vec<Any>,
+        memo: Option<&str>,
+        poll_config: PollConfig,
+    ) -> anyhow::Result<TransactionResponse> {
+        let fee = self.estimate_fee_for_multi_msg(msgs.clone()).await?;
+
+        self.broadcast_multi_with_fee_and_wait(msgs, fee, memo, poll_config)
+            .await
+    }
+
+    /// broadcasts the msgs with the given fee and waits for the tx to be
+    /// included. txs rejected by CheckTx fail right away instead of being
+    /// polled for until the timeout.
+    async fn broadcast_multi_with_fee_and_wait(
+        &self,
+        msgs: Vec<Any>,
+        fee: Fee,
+        memo: Option<&str>,
+        poll_config: PollConfig,
+    ) -> anyhow::Result<TransactionResponse> {
+        let tx_response = self.broadcast_multi_with_fee(msgs, fee, memo).await?;
+
+        if tx_response.code != 0 {
+            let err = CosmosError::from_tx_result(tx_response.code, &tx_response.raw_log);
+            return Err(
+                anyhow::Error::from(err).context(format!("tx {} was rejected", tx_response.txhash))
+            );
+        }
+
+        let confirmed_tx = self
+            .poll_for_tx_with_config(&tx_response.txhash, poll_config)
+            .await?;
+
+        TransactionResponse::try_from(confirmed_tx)
+    }
+
+    /// errors if the signer cannot cover the fee, e.g. to fail early before
+    /// broadcasting a tx that would be rejected by the ante handler
+    async fn verify_funds_for_fee(&self, fee: &Fee) -> anyhow::Result<()> {
+        let signing_client = self.get_signing_client().await?;
+        let address = signing_client.address.to_string();
+
+        for coin in &fee.amount {
+            let balance = self.query_balance(&address, coin.denom.as_ref()).await?;
+
+            if balance < coin.amount {
+                return Err(anyhow::anyhow!(
+                    "Insufficient balance for fee: {address} has {balance}{}, fee requires {}",
+                    coin.denom,
+                    coin
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     async fn latest_block_header(&self) -> anyhow::Result<Header> {
         let channel = self.get_grpc_channel().await?;
 
@@ -95,7 +348,8 @@ pub trait BaseClient: GrpcSigningClient {
 
         let response = tendermint_client
             .get_latest_block(GetLatestBlockRequest {})
-            .await?
+            .await
+            .map_err(CosmosError::from)?
             .into_inner();
 
         let sdk_block = response
@@ -109,6 +363,55 @@ pub trait BaseClient: GrpcSigningClient {
         Ok(block_header)
     }
 
+    /// verifies that the client can reach the chain by fetching the latest block
+    async fn health_check(&self) -> anyhow::Result<HealthStatus> {
+        let start = std::time::Instant::now();
+
+        let header = self.latest_block_header().await?;
+
+        Ok(HealthStatus {
+            block_height: u64::try_from(header.height)?,
+            chain_id: header.chain_id,
+            latency_ms: start.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// returns the p2p info of the connected node
+    async fn get_node_info(&self) -> anyhow::Result<NodeInfo> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut tendermint_client = TendermintServiceClient::new(channel);
+
+        let response = tendermint_client
+            .get_node_info(GetNodeInfoRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let node_info = response
+            .default_node_info
+            .ok_or_else(|| anyhow::anyhow!("no node info in response"))?;
+
+        Ok(NodeInfo::from(node_info))
+    }
+
+    /// errors if the connected node is not part of the configured chain.
+    /// guards against sending txs to the wrong network through a
+    /// misconfigured endpoint.
+    async fn verify_chain_id(&self) -> anyhow::Result<()> {
+        let node_info = self.get_node_info().await?;
+
+        if node_info.network != self.chain_id() {
+            return Err(anyhow::anyhow!(
+                "chain id mismatch: expected {}, node is on {}",
+                self.chain_id(),
+                node_info.network
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn block_results(
         &self,
         rpc_addr: &str,
@@ -123,6 +426,152 @@ pub trait BaseClient: GrpcSigningClient {
         Ok(results)
     }
 
+    /// fetches the block at the given height along with its raw txs and
+    /// execution results
+    async fn get_block_by_height(
+        &self,
+        rpc_addr: &str,
+        height: u32,
+    ) -> anyhow::Result<CosmosBlockResults> {
+        let client = HttpClient::new(rpc_addr)?;
+
+        let block_response = client.block(Height::from(height)).await?;
+
+        let results = self.block_results(rpc_addr, height).await?;
+
+        Ok(CosmosBlockResults {
+            height: block_response.block.header.height.value(),
+            hash: block_response.block_id.hash.to_string(),
+            txs: block_response.block.data,
+            results,
+        })
+    }
+
+    /// fetches the block with the given hex encoded hash along with its raw
+    /// txs and execution results
+    async fn get_block_by_hash(
+        &self,
+        rpc_addr: &str,
+        hash: &str,
+    ) -> anyhow::Result<CosmosBlockResults> {
+        let client = HttpClient::new(rpc_addr)?;
+
+        let block_hash =
+            Hash::from_str(hash).map_err(|e| anyhow::anyhow!("invalid block hash {hash}: {e}"))?;
+
+        let block_response = client.block_by_hash(block_hash).await?;
+
+        let block = block_response
+            .block
+            .ok_or_else(|| anyhow::anyhow!("no block found with hash {hash}"))?;
+
+        let height = u32::try_from(block.header.height.value())?;
+
+        let results = self.block_results(rpc_addr, height).await?;
+
+        Ok(CosmosBlockResults {
+            height: block.header.height.value(),
+            hash: block_response.block_id.hash.to_string(),
+            txs: block.data,
+            results,
+        })
+    }
+
+    /// decodes raw tx bytes (e.g. from [`CosmosBlockResults::txs`]) into the
+    /// tx body, auth info and signatures
+    fn decode_tx_bytes(&self, tx_bytes: &[u8]) -> anyhow::Result<Tx> {
+        Tx::decode(tx_bytes).map_err(|e| anyhow::anyhow!("failed to decode tx: {e}"))
+    }
+
+    /// returns the begin, end and finalize block events emitted at the given
+    /// height. pre-cometbft v0.38 chains report begin/end block events while
+    /// newer chains report all of them as finalize block events.
+    async fn query_block_events(
+        &self,
+        rpc_addr: &str,
+        height: u32,
+    ) -> anyhow::Result<Vec<cosmrs::tendermint::abci::Event>> {
+        let results = self.block_results(rpc_addr, height).await?;
+
+        let events = results
+            .begin_block_events
+            .unwrap_or_default()
+            .into_iter()
+            .chain(results.end_block_events.unwrap_or_default())
+            .chain(results.finalize_block_events)
+            .collect();
+
+        Ok(events)
+    }
+
+    /// searches for txs matching all of the given `(event key, value)` pairs,
+    /// e.g. `("recv_packet.packet_dst_channel", "channel-0")`.
+    /// returns the matching txs along with the total number of matches.
+    async fn query_txs_by_events(
+        &self,
+        events: &[(String, String)],
+        pagination: Option<PageRequest>,
+    ) -> anyhow::Result<(Vec<TransactionResponse>, u64)> {
+        let (txs, page) = self
+            .query_txs_by_events_paged(events, pagination.unwrap_or_default())
+            .await?;
+
+        Ok((txs, page.total.unwrap_or_default()))
+    }
+
+    /// paged variant of [`BaseClient::query_txs_by_events`]. nodes running
+    /// sdk v0.50+ page txs by offset and do not return a `next_key`.
+    async fn query_txs_by_events_paged(
+        &self,
+        events: &[(String, String)],
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<TransactionResponse>, PageResponse)> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = CosmosServiceClient::new(channel);
+
+        let conditions: Vec<String> = events
+            .iter()
+            .map(|(key, value)| format!("{key}='{value}'"))
+            .collect();
+
+        // sdk v0.50+ pages with page/limit, older versions with the pagination
+        // request. both are set so either node version is served.
+        let offset = page.offset.unwrap_or_default();
+        let (page_number, limit) = match page.limit {
+            0 => (1, 0),
+            limit => (offset / limit + 1, limit),
+        };
+
+        #[allow(deprecated)]
+        let request = GetTxsEventRequest {
+            events: conditions.clone(),
+            pagination: Some(page.into()),
+            order_by: 0,
+            page: page_number,
+            limit,
+            query: conditions.join(" AND "),
+        };
+
+        let response = grpc_client
+            .get_txs_event(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let txs = response
+            .tx_responses
+            .into_iter()
+            .map(TransactionResponse::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        #[allow(deprecated)]
+        let mut page_response = PageResponse::from(response.pagination.unwrap_or_default());
+        page_response.total = Some(response.total);
+
+        Ok((txs, page_response))
+    }
+
     async fn query_balance(&self, address: &str, denom: &str) -> anyhow::Result<u128> {
         let channel = self.get_grpc_channel().await?;
 
@@ -135,7 +584,8 @@ pub trait BaseClient: GrpcSigningClient {
 
         let response: QueryBalanceResponse = grpc_client
             .balance(Request::new(request))
-            .await?
+            .await
+            .map_err(CosmosError::from)?
             .into_inner();
 
         let coin = response
@@ -147,116 +597,446 @@ pub trait BaseClient: GrpcSigningClient {
         Ok(amount)
     }
 
-    async fn query_module_account(&self, name: &str) -> anyhow::Result<ModuleAccount> {
+    /// returns the balance of the address as of the given block height.
+    /// requires an archive node for heights outside the pruning window.
+    async fn get_balance_at_height(
+        &self,
+        address: &str,
+        denom: &str,
+        height: u64,
+    ) -> anyhow::Result<u128> {
         let channel = self.get_grpc_channel().await?;
 
-        let mut grpc_client = AuthQueryClient::new(channel);
+        let mut grpc_client = BankQueryClient::new(channel);
 
-        let request = QueryModuleAccountByNameRequest {
-            name: name.to_string(),
-        };
+        let mut request = Request::new(QueryBalanceRequest {
+            address: address.to_string(),
+            denom: denom.to_string(),
+        });
+        QueryOptions::WithBlockHeight(height).apply(&mut request);
 
-        let response: QueryModuleAccountByNameResponse = grpc_client
-            .module_account_by_name(Request::new(request))
-            .await?
+        let response = grpc_client
+            .balance(request)
+            .await
+            .map_err(CosmosError::from)?
             .into_inner();
 
-        let module_account_any = response
-            .account
-            .ok_or_else(|| anyhow::anyhow!("No module account returned"))?;
+        let coin = response
+            .balance
+            .ok_or_else(|| anyhow::anyhow!("No balance returned at height {height}"))?;
 
-        // Decode the bytes into a ModuleAccount
-        let module_account = ModuleAccount::decode(&*module_account_any.value)
-            .map_err(|e| anyhow::anyhow!("Failed to decode ModuleAccount: {e}"))?;
+        Ok(coin.amount.parse::<u128>()?)
+    }
 
-        Ok(module_account)
+    /// returns `(height, balance)` pairs for each of the given heights,
+    /// querying all heights concurrently
+    async fn query_balance_history(
+        &self,
+        address: &str,
+        denom: &str,
+        heights: &[u64],
+    ) -> anyhow::Result<Vec<(u64, u128)>> {
+        let queries = heights.iter().map(|height| async move {
+            let balance = self.get_balance_at_height(address, denom, *height).await?;
+            anyhow::Ok((*height, balance))
+        });
+
+        futures::future::try_join_all(queries).await
     }
 
-    async fn poll_for_tx(&self, tx_hash: &str) -> anyhow::Result<TxResponse> {
+    async fn query_all_balances_paged(
+        &self,
+        address: &str,
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<Coin>, PageResponse)> {
         let channel = self.get_grpc_channel().await?;
 
-        let mut grpc_client = CosmosServiceClient::new(channel);
+        let mut grpc_client = BankQueryClient::new(channel);
 
-        let request = GetTxRequest {
-            hash: tx_hash.to_string(),
+        let request = QueryAllBalancesRequest {
+            address: address.to_string(),
+            pagination: Some(page.into()),
+            resolve_denom: false,
         };
 
-        // using tokio for timing utils instead of system to not block the entire thread.
-        //
-        // for 5 seconds it will repeatedly fire tx polling requests to the node.
-        // if 100ms turns out to hit the node too hard, increase it. maybe this can be
-        // passed in as an arg.
-        let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(200));
-        for _ in 1..50 {
-            interval.tick().await;
-            let rx = grpc_client.get_tx(request.clone()).await;
-            match rx {
-                Ok(response) => {
-                    if let Some(tx_response) = response.into_inner().tx_response {
-                        // ensure the tx actually succeeded
-                        if tx_response.code == 0 {
-                            return Ok(tx_response);
-                        } else {
-                            return Err(anyhow::anyhow!(
-                                "tx {tx_hash} confirmation failed with response code: {}",
-                                tx_response.code
-                            ));
-                        }
-                    }
-                }
-                Err(tonic_status) => match tonic_status.code() {
-                    // if tx code not found, continue polling
-                    tonic::Code::NotFound => {
-                        continue;
-                    }
-                    // otherwise return the error
-                    _ => break,
-                },
-            };
-        }
+        let response = grpc_client
+            .all_balances(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
-        Err(anyhow::anyhow!("failed to confirm tx with hash: {tx_hash}"))
+        Ok((
+            proto_coins_to_coins(&response.balances)?,
+            response.pagination.unwrap_or_default().into(),
+        ))
     }
 
-    async fn query_tx_hash(&self, tx_hash: &str) -> anyhow::Result<TxResponse> {
+    async fn query_supply(&self, denom: &str) -> anyhow::Result<u128> {
         let channel = self.get_grpc_channel().await?;
 
-        let mut grpc_client = CosmosServiceClient::new(channel);
+        let mut grpc_client = BankQueryClient::new(channel);
 
-        let request = GetTxRequest {
-            hash: tx_hash.to_string(),
+        let request = QuerySupplyOfRequest {
+            denom: denom.to_string(),
         };
 
-        let rx = grpc_client.get_tx(request.clone()).await?;
+        let amount = grpc_client
+            .supply_of(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner()
+            .amount
+            .ok_or_else(|| anyhow::anyhow!("No supply returned for {denom}"))?;
 
-        match rx.into_inner().tx_response {
-            Some(r) => Ok(r),
-            None => Err(anyhow::anyhow!("no tx found with given hash")),
-        }
+        Ok(amount.amount.parse()?)
     }
 
-    async fn poll_until_expected_balance(
+    async fn query_total_supply_paged(
         &self,
-        address: &str,
-        denom: &str,
-        min_amount: u128,
-        interval_sec: u64,
-        max_attempts: u32,
-    ) -> anyhow::Result<u128> {
-        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(interval_sec));
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<Coin>, PageResponse)> {
+        let channel = self.get_grpc_channel().await?;
 
-        info!("Polling {address} balance to exceed {denom}{min_amount}");
+        let mut grpc_client = BankQueryClient::new(channel);
 
-        for attempt in 1..max_attempts + 1 {
-            interval.tick().await;
+        let request = QueryTotalSupplyRequest {
+            pagination: Some(page.into()),
+        };
 
-            match self.query_balance(address, denom).await {
-                Ok(balance) => {
-                    if balance >= min_amount {
-                        return Ok(balance);
-                    }
-                    info!(
-                        "Balance polling attempt {attempt}/{max_attempts}: current={balance}, target={min_amount}"
+        let response = grpc_client
+            .total_supply(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok((
+            proto_coins_to_coins(&response.supply)?,
+            response.pagination.unwrap_or_default().into(),
+        ))
+    }
+
+    /// returns the supply of every denom on the chain
+    async fn query_total_supply(&self) -> anyhow::Result<Vec<Coin>> {
+        collect_all_pages(100, |page| self.query_total_supply_paged(page)).await
+    }
+
+    async fn query_denom_metadata(&self, denom: &str) -> anyhow::Result<DenomMetadata> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = BankQueryClient::new(channel);
+
+        let request = QueryDenomMetadataRequest {
+            denom: denom.to_string(),
+        };
+
+        let metadata = grpc_client
+            .denom_metadata(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner()
+            .metadata
+            .ok_or_else(|| anyhow::anyhow!("No metadata returned for {denom}"))?;
+
+        Ok(metadata.into())
+    }
+
+    /// lists validators with the given bond status, e.g. `BOND_STATUS_BONDED`.
+    /// an empty status lists validators of every status.
+    async fn query_validators_paged(
+        &self,
+        status: &str,
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<Validator>, PageResponse)> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = StakingQueryClient::new(channel);
+
+        let request = QueryValidatorsRequest {
+            status: status.to_string(),
+            pagination: Some(page.into()),
+        };
+
+        let response = grpc_client
+            .validators(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok((
+            response.validators,
+            response.pagination.unwrap_or_default().into(),
+        ))
+    }
+
+    async fn query_delegations_paged(
+        &self,
+        delegator: &str,
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<DelegationResponse>, PageResponse)> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = StakingQueryClient::new(channel);
+
+        let request = QueryDelegatorDelegationsRequest {
+            delegator_addr: delegator.to_string(),
+            pagination: Some(page.into()),
+        };
+
+        let response = grpc_client
+            .delegator_delegations(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok((
+            response.delegation_responses,
+            response.pagination.unwrap_or_default().into(),
+        ))
+    }
+
+    /// returns all delegations of the delegator
+    async fn query_delegations(&self, delegator: &str) -> anyhow::Result<Vec<CosmosDelegation>> {
+        collect_all_pages(100, |page| self.query_delegations_paged(delegator, page))
+            .await?
+            .into_iter()
+            .map(CosmosDelegation::try_from)
+            .collect()
+    }
+
+    async fn query_validator(&self, validator_addr: &str) -> anyhow::Result<CosmosValidator> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = StakingQueryClient::new(channel);
+
+        let request = QueryValidatorRequest {
+            validator_addr: validator_addr.to_string(),
+        };
+
+        let response = grpc_client
+            .validator(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        response
+            .validator
+            .ok_or_else(|| anyhow::anyhow!("validator {validator_addr} not found"))?
+            .try_into()
+    }
+
+    /// returns all validators with the given bond status.
+    /// `BondStatus::Unspecified` returns validators of every status.
+    async fn query_bonded_validators(
+        &self,
+        status: BondStatus,
+    ) -> anyhow::Result<Vec<CosmosValidator>> {
+        let status = match status {
+            BondStatus::Unspecified => "",
+            status => status.as_str_name(),
+        };
+
+        collect_all_pages(100, |page| self.query_validators_paged(status, page))
+            .await?
+            .into_iter()
+            .map(CosmosValidator::try_from)
+            .collect()
+    }
+
+    async fn query_module_account(&self, name: &str) -> anyhow::Result<ModuleAccount> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = AuthQueryClient::new(channel);
+
+        let request = QueryModuleAccountByNameRequest {
+            name: name.to_string(),
+        };
+
+        let response: QueryModuleAccountByNameResponse = grpc_client
+            .module_account_by_name(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let module_account_any = response
+            .account
+            .ok_or_else(|| anyhow::anyhow!("No module account returned"))?;
+
+        // Decode the bytes into a ModuleAccount
+        let module_account = ModuleAccount::decode(&*module_account_any.value)
+            .map_err(|e| anyhow::anyhow!("Failed to decode ModuleAccount: {e}"))?;
+
+        Ok(module_account)
+    }
+
+    /// returns the vesting schedule of the given account, or `None` if it
+    /// is not a vesting account
+    async fn query_account_vesting(&self, address: &str) -> anyhow::Result<Option<VestingInfo>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = AuthQueryClient::new(channel);
+
+        let account = grpc_client
+            .account(QueryAccountRequest {
+                address: address.to_string(),
+            })
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner()
+            .account
+            .ok_or_else(|| anyhow::anyhow!("No account returned"))?;
+
+        VestingInfo::from_account(&account)
+    }
+
+    /// returns the balance of `denom` that is not locked by vesting
+    async fn query_spendable_balance(&self, address: &str, denom: &str) -> anyhow::Result<u128> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = BankQueryClient::new(channel);
+
+        let balance = grpc_client
+            .spendable_balance_by_denom(QuerySpendableBalanceByDenomRequest {
+                address: address.to_string(),
+                denom: denom.to_string(),
+            })
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner()
+            .balance;
+
+        match balance {
+            Some(coin) => Ok(coin.amount.parse()?),
+            None => Ok(0),
+        }
+    }
+
+    async fn poll_for_tx(&self, tx_hash: &str) -> anyhow::Result<TxResponse> {
+        self.poll_for_tx_with_config(tx_hash, PollConfig::default())
+            .await
+    }
+
+    /// polls for the tx until it is included, using the given poll interval
+    /// and number of attempts
+    async fn poll_for_tx_with_config(
+        &self,
+        tx_hash: &str,
+        config: PollConfig,
+    ) -> anyhow::Result<TxResponse> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = CosmosServiceClient::new(channel);
+
+        let request = GetTxRequest {
+            hash: tx_hash.to_string(),
+        };
+
+        // using tokio for timing utils instead of system to not block the entire thread.
+        let mut interval = tokio::time::interval(config.interval);
+        for _ in 0..config.max_attempts {
+            interval.tick().await;
+            let rx = grpc_client.get_tx(request.clone()).await;
+            match rx {
+                Ok(response) => {
+                    if let Some(tx_response) = response.into_inner().tx_response {
+                        // ensure the tx actually succeeded
+                        if tx_response.code == 0 {
+                            return Ok(tx_response);
+                        } else {
+                            let err =
+                                CosmosError::from_tx_result(tx_response.code, &tx_response.raw_log);
+                            return Err(anyhow::Error::from(err)
+                                .context(format!("tx {tx_hash} confirmation failed")));
+                        }
+                    }
+                }
+                Err(tonic_status) => match tonic_status.code() {
+                    // if tx code not found, continue polling
+                    tonic::Code::NotFound => {
+                        continue;
+                    }
+                    // otherwise return the error
+                    _ => break,
+                },
+            };
+        }
+
+        Err(anyhow::anyhow!("failed to confirm tx with hash: {tx_hash}"))
+    }
+
+    async fn query_tx_hash(&self, tx_hash: &str) -> anyhow::Result<TxResponse> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = CosmosServiceClient::new(channel);
+
+        let request = GetTxRequest {
+            hash: tx_hash.to_string(),
+        };
+
+        let rx = grpc_client
+            .get_tx(request.clone())
+            .await
+            .map_err(CosmosError::from)?;
+
+        match rx.into_inner().tx_response {
+            Some(r) => Ok(r),
+            None => Err(anyhow::anyhow!("no tx found with given hash")),
+        }
+    }
+
+    /// returns the messages that were included in the tx with the given hash
+    async fn decode_tx_msgs(&self, tx_hash: &str) -> anyhow::Result<Vec<Any>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = CosmosServiceClient::new(channel);
+
+        let request = GetTxRequest {
+            hash: tx_hash.to_string(),
+        };
+
+        let body = grpc_client
+            .get_tx(request)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner()
+            .tx
+            .and_then(|tx| tx.body)
+            .ok_or_else(|| anyhow::anyhow!("no tx body found with given hash"))?;
+
+        Ok(body.messages)
+    }
+
+    async fn decode_msg_type_url(&self, tx_hash: &str) -> anyhow::Result<Vec<String>> {
+        let msgs = self.decode_tx_msgs(tx_hash).await?;
+
+        Ok(msgs.into_iter().map(|msg| msg.type_url).collect())
+    }
+
+    async fn count_msgs(&self, tx_hash: &str) -> anyhow::Result<usize> {
+        Ok(self.decode_tx_msgs(tx_hash).await?.len())
+    }
+
+    async fn poll_until_expected_balance(
+        &self,
+        address: &str,
+        denom: &str,
+        min_amount: u128,
+        interval_sec: u64,
+        max_attempts: u32,
+    ) -> anyhow::Result<u128> {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(interval_sec));
+
+        info!("Polling {address} balance to exceed {denom}{min_amount}");
+
+        for attempt in 1..max_attempts + 1 {
+            interval.tick().await;
+
+            match self.query_balance(address, denom).await {
+                Ok(balance) => {
+                    if balance >= min_amount {
+                        return Ok(balance);
+                    }
+                    info!(
+                        "Balance polling attempt {attempt}/{max_attempts}: current={balance}, target={min_amount}"
                     );
                 }
                 Err(e) => {
@@ -270,50 +1050,1351 @@ pub trait BaseClient: GrpcSigningClient {
         ))
     }
 
-    async fn ibc_transfer(
+    /// signs and broadcasts the message in commit (`BROADCAST_MODE_BLOCK`) mode,
+    /// returning only after the tx is included in a block.
+    ///
+    /// commit mode keeps the request open until the block is committed, so it
+    /// takes considerably longer than sync broadcasts and may time out on
+    /// congested chains. nodes running cosmos-sdk v0.47+ no longer support it;
+    /// for those the tx is broadcast in sync mode and polled for instead.
+    async fn sign_and_broadcast_tx_commit(
         &self,
-        to: String,
-        denom: String,
-        amount: String,
-        channel_id: String,
-        timeout_seconds: u64,
-        memo: Option<String>,
+        msg: Any,
+        fee: Fee,
+        memo: Option<&str>,
     ) -> anyhow::Result<TransactionResponse> {
-        // first we query the latest block header to respect the chain time for timeouts
-        let latest_block_header = self.latest_block_header().await?;
+        let signing_client = self.get_signing_client().await?;
+        let channel = self.get_grpc_channel().await?;
 
-        let mut current_time = ProtoTimestamp::try_from(latest_block_header)?;
+        let mut raw_tx = signing_client.create_tx(msg, fee, memo).await?;
 
-        current_time.extend_by_seconds(timeout_seconds)?;
+        let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let timeout_nanos = current_time.to_nanos()?;
+        let commit_request = BroadcastTxRequest {
+            tx_bytes: raw_tx.tx_bytes.clone(),
+            mode: BroadcastMode::Block.into(),
+        };
 
-        let signing_client = self.get_signing_client().await?;
+        match grpc_client.broadcast_tx(commit_request).await {
+            Ok(response) => {
+                return TransactionResponse::try_from(response.into_inner().tx_response);
+            }
+            // the node rejected the broadcast mode itself, so the tx never
+            // entered the mempool and can safely be rebroadcast
+            Err(status) if status.code() == tonic::Code::InvalidArgument => {
+                warn!("commit broadcast mode unsupported, falling back to sync: {status}");
+            }
+            Err(status) => return Err(status.into()),
+        }
 
-        let ibc_transfer_msg = ibc::apps::transfer::types::proto::transfer::v1::MsgTransfer {
-            source_port: "transfer".to_string(),
-            source_channel: channel_id,
-            token: Some(cosmos_sdk_proto::cosmos::base::v1beta1::Coin { denom, amount }),
-            sender: signing_client.address.to_string(),
-            receiver: to,
-            timeout_height: None,
-            timeout_timestamp: timeout_nanos,
-            memo: memo.unwrap_or_default(),
+        raw_tx.mode = BroadcastMode::Sync.into();
+
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let tx_response = TransactionResponse::try_from(broadcast_tx_response.tx_response)?;
+
+        let confirmed_tx = self.poll_for_tx(&tx_response.hash).await?;
+
+        TransactionResponse::try_from(confirmed_tx)
+    }
+
+    /// signs and broadcasts a tx in sync mode. if no fee is given, it is
+    /// estimated by simulating the tx.
+    ///
+    /// txs rejected with an account sequence mismatch (e.g. because another
+    /// process sent a tx from the same account in the meantime) are re-signed
+    /// with the refreshed account state and rebroadcast.
+    async fn sign_and_broadcast_tx(
+        &self,
+        msg: Any,
+        fee: Option<Fee>,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        const MAX_SEQUENCE_RETRIES: u32 = 3;
+
+        let mut signing_client = self.get_signing_client().await?;
+        let channel = self.get_grpc_channel().await?;
+
+        let fee = match fee {
+            Some(fee) => fee,
+            None => self.get_tx_fee(self.simulate_tx(msg.clone()).await?)?,
         };
 
-        let any_msg = Any::from_msg(&ibc_transfer_msg)?;
+        let mut grpc_client = CosmosServiceClient::new(channel.clone());
 
-        let simulation_response = self.simulate_tx(any_msg.clone()).await?;
-        let fee = self.get_tx_fee(simulation_response)?;
+        let mut attempt = 0;
+        loop {
+            let raw_tx = signing_client
+                .create_tx(msg.clone(), fee.clone(), memo)
+                .await?;
 
-        let raw_tx = signing_client.create_tx(any_msg, fee, None).await?;
+            let tx_response = grpc_client
+                .broadcast_tx(raw_tx)
+                .await
+                .map_err(CosmosError::from)?
+                .into_inner()
+                .tx_response
+                .ok_or_else(|| anyhow::anyhow!("failed to find tx_response"))?;
+
+            if tx_response.code == 0 {
+                return TransactionResponse::try_from(tx_response);
+            }
+
+            let err = CosmosError::from_tx_result(tx_response.code, &tx_response.raw_log);
+
+            match err {
+                CosmosError::SequenceMismatch { expected, got }
+                    if attempt < MAX_SEQUENCE_RETRIES =>
+                {
+                    warn!("account sequence mismatch (expected {expected}, got {got}), refreshing account");
+                    signing_client.refresh_account(channel.clone()).await?;
+                    attempt += 1;
+                }
+                _ => return Err(err.into()),
+            }
+        }
+    }
 
+    /// broadcasts already signed tx bytes in async mode, returning the tx hash
+    /// without waiting for the tx to pass mempool validation.
+    async fn broadcast_tx_async(&self, tx_bytes: Vec<u8>) -> anyhow::Result<String> {
         let channel = self.get_grpc_channel().await?;
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let request = BroadcastTxRequest {
+            tx_bytes,
+            mode: BroadcastMode::Async.into(),
+        };
+
+        let response = grpc_client
+            .broadcast_tx(request)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let tx_response = response
+            .tx_response
+            .ok_or_else(|| anyhow::anyhow!("failed to find tx_response"))?;
 
-        TransactionResponse::try_from(broadcast_tx_response.tx_response)
+        Ok(tx_response.txhash)
+    }
+
+    async fn ibc_transfer(
+        &self,
+        to: String,
+        denom: String,
+        amount: String,
+        channel_id: String,
+        timeout_seconds: u64,
+        memo: Option<String>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let any_msg = self
+            .ibc_transfer_msg(to, denom, amount, channel_id, timeout_seconds, memo)
+            .await?;
+
+        let signing_client = self.get_signing_client().await?;
+
+        let simulation_response = self.simulate_tx(any_msg.clone()).await?;
+        let fee = self.get_tx_fee(simulation_response)?;
+
+        let raw_tx = signing_client.create_tx(any_msg, fee, None).await?;
+
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = CosmosServiceClient::new(channel);
+
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        TransactionResponse::try_from(broadcast_tx_response.tx_response)
+    }
+
+    /// simulates the `MsgTransfer` that `ibc_transfer` would broadcast with
+    /// the same arguments
+    async fn simulate_ibc_transfer(
+        &self,
+        to: String,
+        denom: String,
+        amount: String,
+        channel_id: String,
+        timeout_seconds: u64,
+        memo: Option<String>,
+    ) -> anyhow::Result<SimulateResponse> {
+        let any_msg = self
+            .ibc_transfer_msg(to, denom, amount, channel_id, timeout_seconds, memo)
+            .await?;
+
+        self.simulate_tx(any_msg).await
+    }
+
+    /// returns the fee `ibc_transfer` would pay with the same arguments
+    async fn estimate_ibc_transfer_fee(
+        &self,
+        to: String,
+        denom: String,
+        amount: String,
+        channel_id: String,
+        timeout_seconds: u64,
+        memo: Option<String>,
+    ) -> anyhow::Result<Fee> {
+        let simulation_response = self
+            .simulate_ibc_transfer(to, denom, amount, channel_id, timeout_seconds, memo)
+            .await?;
+
+        self.get_tx_fee(simulation_response)
+    }
+
+    /// builds a `MsgTransfer` from the signer timing out `timeout_seconds`
+    /// after the latest block
+    async fn ibc_transfer_msg(
+        &self,
+        to: String,
+        denom: String,
+        amount: String,
+        channel_id: String,
+        timeout_seconds: u64,
+        memo: Option<String>,
+    ) -> anyhow::Result<Any> {
+        // first we query the latest block header to respect the chain time for timeouts
+        let latest_block_header = self.latest_block_header().await?;
+
+        let mut current_time = ProtoTimestamp::try_from(latest_block_header)?;
+
+        current_time.extend_by_seconds(timeout_seconds)?;
+
+        let timeout_nanos = current_time.to_nanos()?;
+
+        let signing_client = self.get_signing_client().await?;
+
+        let ibc_transfer_msg = ibc::apps::transfer::types::proto::transfer::v1::MsgTransfer {
+            source_port: "transfer".to_string(),
+            source_channel: channel_id,
+            token: Some(cosmos_sdk_proto::cosmos::base::v1beta1::Coin { denom, amount }),
+            sender: signing_client.address.to_string(),
+            receiver: to,
+            timeout_height: None,
+            timeout_timestamp: timeout_nanos,
+            memo: memo.unwrap_or_default(),
+        };
+
+        Ok(Any::from_msg(&ibc_transfer_msg)?)
+    }
+
+    /// checks that a multi-hop route is well formed before anything is broadcast
+    fn validate_multi_hop_path(&self, params: &MultiHopTransferParams) -> anyhow::Result<()> {
+        if params.hops.is_empty() {
+            return Err(anyhow::anyhow!(
+                "multi-hop transfer requires at least one hop"
+            ));
+        }
+
+        for (i, hop) in params.hops.iter().enumerate() {
+            let valid_channel = hop
+                .channel
+                .strip_prefix("channel-")
+                .is_some_and(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()));
+
+            if !valid_channel {
+                return Err(anyhow::anyhow!(
+                    "hop {i} has invalid channel id: {}",
+                    hop.channel
+                ));
+            }
+            if hop.port.is_empty() {
+                return Err(anyhow::anyhow!("hop {i} has an empty port id"));
+            }
+        }
+
+        // the initial transfer is always sent over the transfer port
+        if params.hops[0].port != "transfer" {
+            return Err(anyhow::anyhow!(
+                "first hop must use the transfer port, got: {}",
+                params.hops[0].port
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// transfers funds over several chains by attaching a packet forward
+    /// middleware memo to a regular ibc transfer over the first hop
+    async fn ibc_multi_hop_transfer(
+        &self,
+        params: MultiHopTransferParams,
+    ) -> anyhow::Result<TransactionResponse> {
+        self.validate_multi_hop_path(&params)?;
+
+        self.ibc_transfer(
+            params.first_hop_receiver(),
+            params.denom.clone(),
+            params.amount.to_string(),
+            params.hops[0].channel.clone(),
+            params.timeout_seconds,
+            params.pfm_memo(),
+        )
+        .await
+    }
+
+    async fn query_community_pool(&self) -> anyhow::Result<Vec<Coin>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = DistributionQueryClient::new(channel);
+
+        let response = grpc_client
+            .community_pool(QueryCommunityPoolRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        dec_coins_to_coins(response.pool)
+    }
+
+    async fn query_distribution_params(&self) -> anyhow::Result<DistributionParams> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = DistributionQueryClient::new(channel);
+
+        let response = grpc_client
+            .params(QueryDistributionParamsRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let params = response
+            .params
+            .ok_or_else(|| anyhow::anyhow!("No distribution params returned"))?;
+
+        DistributionParams::try_from(params)
+    }
+
+    async fn query_validator_outstanding_rewards(
+        &self,
+        validator_address: &str,
+    ) -> anyhow::Result<Vec<Coin>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = DistributionQueryClient::new(channel);
+
+        let request = QueryValidatorOutstandingRewardsRequest {
+            validator_address: validator_address.to_string(),
+        };
+
+        let response = grpc_client
+            .validator_outstanding_rewards(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let rewards = response.rewards.map(|r| r.rewards).unwrap_or_default();
+
+        dec_coins_to_coins(rewards)
+    }
+
+    async fn query_validator_commission(
+        &self,
+        validator_address: &str,
+    ) -> anyhow::Result<Vec<Coin>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = DistributionQueryClient::new(channel);
+
+        let request = QueryValidatorCommissionRequest {
+            validator_address: validator_address.to_string(),
+        };
+
+        let response = grpc_client
+            .validator_commission(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let commission = response
+            .commission
+            .map(|c| c.commission)
+            .unwrap_or_default();
+
+        dec_coins_to_coins(commission)
+    }
+
+    async fn query_slashing_params(&self) -> anyhow::Result<SlashingParams> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = SlashingQueryClient::new(channel);
+
+        let response = grpc_client
+            .params(QuerySlashingParamsRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let params = response
+            .params
+            .ok_or_else(|| anyhow::anyhow!("No slashing params returned"))?;
+
+        SlashingParams::try_from(params)
+    }
+
+    /// returns the liveness tracking of the validator with the given consensus
+    /// address (e.g. `cosmosvalcons1...`)
+    async fn query_signing_info(&self, consensus_address: &str) -> anyhow::Result<SigningInfo> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = SlashingQueryClient::new(channel);
+
+        let request = QuerySigningInfoRequest {
+            cons_address: consensus_address.to_string(),
+        };
+
+        let response = grpc_client
+            .signing_info(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let signing_info = response
+            .val_signing_info
+            .ok_or_else(|| anyhow::anyhow!("No signing info returned for {consensus_address}"))?;
+
+        SigningInfo::try_from(signing_info)
+    }
+
+    async fn query_slashes_paged(
+        &self,
+        validator: &str,
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<ValidatorSlashEvent>, PageResponse)> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = DistributionQueryClient::new(channel);
+
+        let request = QueryValidatorSlashesRequest {
+            validator_address: validator.to_string(),
+            starting_height: 0,
+            ending_height: u64::MAX,
+            pagination: Some(page.into()),
+        };
+
+        let response = grpc_client
+            .validator_slashes(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok((
+            response.slashes,
+            response.pagination.unwrap_or_default().into(),
+        ))
+    }
+
+    /// returns all slashes of the validator (`cosmosvaloper1...`). slashes are
+    /// recorded by the distribution module.
+    async fn query_slashes(&self, validator: &str) -> anyhow::Result<Vec<SlashEvent>> {
+        collect_all_pages(100, |page| self.query_slashes_paged(validator, page))
+            .await?
+            .into_iter()
+            .map(SlashEvent::try_from)
+            .collect()
+    }
+
+    async fn set_withdraw_address(
+        &self,
+        withdraw_address: &str,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+        let channel = self.get_grpc_channel().await?;
+
+        let set_withdraw_address_msg = MsgSetWithdrawAddress {
+            delegator_address: signing_client.address.clone(),
+            withdraw_address: AccountId::from_str(withdraw_address)
+                .map_err(|e| anyhow::anyhow!("Failed to parse address: {e}"))?,
+        }
+        .to_any()
+        .map_err(|e| anyhow::anyhow!("Failed to convert to Any: {e}"))?;
+
+        let simulation_response = self.simulate_tx(set_withdraw_address_msg.clone()).await?;
+        let fee = self.get_tx_fee(simulation_response)?;
+
+        let raw_tx = signing_client
+            .create_tx(set_withdraw_address_msg, fee, memo)
+            .await?;
+
+        let mut grpc_client = CosmosServiceClient::new(channel);
+
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        TransactionResponse::try_from(broadcast_tx_response.tx_response)
+    }
+
+    /// ibc transfer with ics-29 relayer fees escrowed in the same transaction.
+    /// the fee payment message is placed ahead of the transfer so that it
+    /// applies to the packet sent by the transfer.
+    #[allow(clippy::too_many_arguments)]
+    async fn ibc_transfer_with_fee(
+        &self,
+        to: String,
+        denom: String,
+        amount: String,
+        channel_id: String,
+        timeout_seconds: u64,
+        memo: Option<String>,
+        fee_params: IbcFeeParams,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let pay_packet_fee_msg = MsgPayPacketFee {
+            fee: Some(fee_params.into()),
+            source_port_id: "transfer".to_string(),
+            source_channel_id: channel_id.clone(),
+            signer: signing_client.address.to_string(),
+            relayers: vec![],
+        };
+
+        let ibc_transfer_msg = self
+            .ibc_transfer_msg(to, denom, amount, channel_id, timeout_seconds, memo)
+            .await?;
+
+        let msgs = vec![Any::from_msg(&pay_packet_fee_msg)?, ibc_transfer_msg];
+
+        self.estimate_and_broadcast_multi(msgs, None).await
+    }
+
+    /// escrows ics-29 relayer fees for an already sent packet identified by
+    /// its source port, channel and sequence
+    #[allow(clippy::too_many_arguments)]
+    async fn pay_packet_fee(
+        &self,
+        source_port: &str,
+        source_channel: &str,
+        sequence: u64,
+        recv_fee: &[Coin],
+        ack_fee: &[Coin],
+        timeout_fee: &[Coin],
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+        let channel = self.get_grpc_channel().await?;
+
+        let fee_params = IbcFeeParams {
+            recv_fee: recv_fee.to_vec(),
+            ack_fee: ack_fee.to_vec(),
+            timeout_fee: timeout_fee.to_vec(),
+        };
+
+        let pay_packet_fee_msg = MsgPayPacketFeeAsync {
+            packet_id: Some(PacketId {
+                port_id: source_port.to_string(),
+                channel_id: source_channel.to_string(),
+                sequence,
+            }),
+            packet_fee: Some(PacketFee {
+                fee: Some(fee_params.into()),
+                refund_address: signing_client.address.to_string(),
+                relayers: vec![],
+            }),
+        };
+
+        let any_msg = Any::from_msg(&pay_packet_fee_msg)?;
+
+        let simulation_response = self.simulate_tx(any_msg.clone()).await?;
+        let fee = self.get_tx_fee(simulation_response)?;
+
+        let raw_tx = signing_client.create_tx(any_msg, fee, memo).await?;
+
+        let mut grpc_client = CosmosServiceClient::new(channel);
+
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        TransactionResponse::try_from(broadcast_tx_response.tx_response)
+    }
+
+    async fn query_incentivised_packets(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> anyhow::Result<Vec<IncentivisedPacket>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = IbcFeeQueryClient::new(channel);
+
+        let request = QueryIncentivizedPacketsForChannelRequest {
+            pagination: None,
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            query_height: 0,
+        };
+
+        let response = grpc_client
+            .incentivized_packets_for_channel(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        response
+            .incentivized_packets
+            .into_iter()
+            .map(IncentivisedPacket::try_from)
+            .collect()
+    }
+
+    /// returns the commitments of all packets sent over the channel that
+    /// have not been acknowledged or timed out yet
+    async fn query_packet_commitments(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> anyhow::Result<Vec<PacketState>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let grpc_client = IbcChannelQueryClient::new(channel);
+
+        collect_all_pages(100, |page| {
+            let mut grpc_client = grpc_client.clone();
+            let request = QueryPacketCommitmentsRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                pagination: Some(page.into()),
+            };
+
+            async move {
+                let response = grpc_client
+                    .packet_commitments(Request::new(request))
+                    .await
+                    .map_err(CosmosError::from)?
+                    .into_inner();
+
+                Ok((
+                    response.commitments.into_iter().map(Into::into).collect(),
+                    response.pagination.unwrap_or_default().into(),
+                ))
+            }
+        })
+        .await
+    }
+
+    /// returns the host chain address of the owner's interchain account on
+    /// the connection, or `None` if no account is registered
+    async fn query_interchain_account_address(
+        &self,
+        owner: &str,
+        connection_id: &str,
+    ) -> anyhow::Result<Option<String>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = IcaControllerQueryClient::new(channel);
+
+        let request = QueryInterchainAccountRequest {
+            owner: owner.to_string(),
+            connection_id: connection_id.to_string(),
+        };
+
+        match grpc_client.interchain_account(Request::new(request)).await {
+            Ok(response) => Ok(Some(response.into_inner().address)),
+            Err(status) if status.code() == tonic::Code::NotFound => Ok(None),
+            Err(status) => Err(status.into()),
+        }
+    }
+
+    /// lists the open interchain account channels controlled by the owner
+    async fn query_active_channels(&self, owner: &str) -> anyhow::Result<Vec<IcaChannel>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let grpc_client = IbcChannelQueryClient::new(channel);
+
+        let channels = collect_all_pages(100, |page| {
+            let mut grpc_client = grpc_client.clone();
+            let request = QueryChannelsRequest {
+                pagination: Some(page.into()),
+            };
+
+            async move {
+                let response = grpc_client
+                    .channels(Request::new(request))
+                    .await
+                    .map_err(CosmosError::from)?
+                    .into_inner();
+
+                Ok((
+                    response.channels,
+                    response.pagination.unwrap_or_default().into(),
+                ))
+            }
+        })
+        .await?;
+
+        let port_id = format!("{}{owner}", IcaChannel::CONTROLLER_PORT_PREFIX);
+
+        Ok(channels
+            .into_iter()
+            .filter(|channel| channel.port_id == port_id && channel.state == State::Open as i32)
+            .map(IcaChannel::from_channel)
+            .collect())
+    }
+
+    /// registers an interchain account for the signer on the connection and
+    /// polls until its host chain address is available
+    async fn register_ica_and_wait(
+        &self,
+        connection_id: &str,
+        memo: Option<&str>,
+        poll_config: PollConfig,
+    ) -> anyhow::Result<String> {
+        let signing_client = self.get_signing_client().await?;
+        let owner = signing_client.address.to_string();
+
+        // an empty version lets the controller negotiate the default metadata
+        let register_msg = MsgRegisterInterchainAccount {
+            owner: owner.clone(),
+            connection_id: connection_id.to_string(),
+            version: String::new(),
+            ordering: Order::Ordered as i32,
+        };
+
+        self.broadcast_and_wait(Any::from_msg(&register_msg)?, memo, poll_config)
+            .await?;
+
+        // the address is only set once relayers complete the channel handshake
+        let mut interval = tokio::time::interval(poll_config.interval);
+        for _ in 0..poll_config.max_attempts {
+            interval.tick().await;
+
+            if let Some(address) = self
+                .query_interchain_account_address(&owner, connection_id)
+                .await?
+            {
+                return Ok(address);
+            }
+        }
+
+        Err(anyhow::anyhow!(
+            "interchain account of {owner} on {connection_id} not available"
+        ))
+    }
+
+    /// returns the acknowledgements written for packets received over the channel
+    async fn query_packet_acknowledgements(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> anyhow::Result<Vec<PacketState>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let grpc_client = IbcChannelQueryClient::new(channel);
+
+        collect_all_pages(100, |page| {
+            let mut grpc_client = grpc_client.clone();
+            let request = QueryPacketAcknowledgementsRequest {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                pagination: Some(page.into()),
+                packet_commitment_sequences: vec![],
+            };
+
+            async move {
+                let response = grpc_client
+                    .packet_acknowledgements(Request::new(request))
+                    .await
+                    .map_err(CosmosError::from)?
+                    .into_inner();
+
+                Ok((
+                    response
+                        .acknowledgements
+                        .into_iter()
+                        .map(Into::into)
+                        .collect(),
+                    response.pagination.unwrap_or_default().into(),
+                ))
+            }
+        })
+        .await
+    }
+
+    /// queried on the destination chain: filters the given sent packet
+    /// sequences down to the ones that have not been received yet
+    async fn query_unreceived_packets(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        sequences: &[u64],
+    ) -> anyhow::Result<Vec<u64>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = IbcChannelQueryClient::new(channel);
+
+        let request = QueryUnreceivedPacketsRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            packet_commitment_sequences: sequences.to_vec(),
+        };
+
+        let response = grpc_client
+            .unreceived_packets(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok(response.sequences)
+    }
+
+    /// queried on the source chain: filters the given acknowledged packet
+    /// sequences down to the ones whose acks have not been relayed back yet
+    async fn query_unreceived_acks(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        sequences: &[u64],
+    ) -> anyhow::Result<Vec<u64>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = IbcChannelQueryClient::new(channel);
+
+        let request = QueryUnreceivedAcksRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            packet_ack_sequences: sequences.to_vec(),
+        };
+
+        let response = grpc_client
+            .unreceived_acks(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok(response.sequences)
+    }
+
+    /// returns the ids of all channels with ics-29 fee middleware enabled
+    async fn query_fee_enabled_channels(&self) -> anyhow::Result<Vec<String>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = IbcFeeQueryClient::new(channel);
+
+        let request = QueryFeeEnabledChannelsRequest {
+            pagination: None,
+            query_height: 0,
+        };
+
+        let response = grpc_client
+            .fee_enabled_channels(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok(response
+            .fee_enabled_channels
+            .into_iter()
+            .map(|c| c.channel_id)
+            .collect())
+    }
+
+    /// returns the currently scheduled upgrade plan, if any
+    async fn query_current_plan(&self) -> anyhow::Result<Option<UpgradePlan>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = UpgradeQueryClient::new(channel);
+
+        let response = grpc_client
+            .current_plan(QueryCurrentPlanRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok(response.plan.map(UpgradePlan::from))
+    }
+
+    /// returns the height at which the named upgrade was applied, if it was
+    async fn query_applied_plan(&self, name: &str) -> anyhow::Result<Option<i64>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = UpgradeQueryClient::new(channel);
+
+        let request = QueryAppliedPlanRequest {
+            name: name.to_string(),
+        };
+
+        let response = grpc_client
+            .applied_plan(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        // height 0 is returned for upgrades that were never applied
+        Ok(Some(response.height).filter(|height| *height > 0))
+    }
+
+    async fn query_module_versions(&self) -> anyhow::Result<Vec<ModuleVersion>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = UpgradeQueryClient::new(channel);
+
+        let request = QueryModuleVersionsRequest {
+            module_name: String::new(),
+        };
+
+        let response = grpc_client
+            .module_versions(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok(response
+            .module_versions
+            .into_iter()
+            .map(ModuleVersion::from)
+            .collect())
+    }
+
+    /// returns true if an upgrade is scheduled within the next `blocks_ahead`
+    /// blocks (or its height has already been reached)
+    async fn is_upgrade_scheduled(&self, blocks_ahead: u64) -> anyhow::Result<bool> {
+        let plan = match self.query_current_plan().await? {
+            Some(plan) => plan,
+            None => return Ok(false),
+        };
+
+        let current_height = self.latest_block_header().await?.height;
+
+        let blocks_until_upgrade = plan.height.saturating_sub(current_height);
+
+        Ok(blocks_until_upgrade <= i64::try_from(blocks_ahead).unwrap_or(i64::MAX))
+    }
+
+    async fn query_mint_params(&self) -> anyhow::Result<MintParams> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = MintQueryClient::new(channel);
+
+        let response = grpc_client
+            .params(QueryMintParamsRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let params = response
+            .params
+            .ok_or_else(|| anyhow::anyhow!("No mint params returned"))?;
+
+        MintParams::try_from(params)
+    }
+
+    /// returns the current annual inflation rate
+    async fn query_inflation(&self) -> anyhow::Result<f64> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = MintQueryClient::new(channel);
+
+        let response = grpc_client
+            .inflation(QueryInflationRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        // the decimal is returned as the bytes of its string representation
+        parse_dec(std::str::from_utf8(&response.inflation)?)
+    }
+
+    /// returns the current annual provisions, denominated in the mint denom
+    async fn query_annual_provisions(&self) -> anyhow::Result<f64> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = MintQueryClient::new(channel);
+
+        let response = grpc_client
+            .annual_provisions(QueryAnnualProvisionsRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        // the decimal is returned as the bytes of its string representation
+        parse_dec(std::str::from_utf8(&response.annual_provisions)?)
+    }
+
+    async fn query_staking_params(&self) -> anyhow::Result<StakingParams> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = StakingQueryClient::new(channel);
+
+        let response = grpc_client
+            .params(QueryStakingParamsRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let params = response
+            .params
+            .ok_or_else(|| anyhow::anyhow!("No staking params returned"))?;
+
+        StakingParams::try_from(params)
+    }
+
+    /// returns the raw json value of a legacy params module entry
+    async fn query_params(&self, subspace: &str, key: &str) -> anyhow::Result<String> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = ParamsQueryClient::new(channel);
+
+        let request = QueryParamsRequest {
+            subspace: subspace.to_string(),
+            key: key.to_string(),
+        };
+
+        let response = grpc_client
+            .params(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let param = response
+            .param
+            .ok_or_else(|| anyhow::anyhow!("No param returned for {subspace}/{key}"))?;
+
+        Ok(param.value)
+    }
+
+    async fn query_subspaces(&self) -> anyhow::Result<Vec<ParamSubspace>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = ParamsQueryClient::new(channel);
+
+        let response = grpc_client
+            .subspaces(QuerySubspacesRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok(response.subspaces.into_iter().map(Into::into).collect())
+    }
+
+    /// returns the max gas per block. an unlimited block gas (`-1`) is
+    /// returned as `u64::MAX`.
+    async fn query_max_gas(&self) -> anyhow::Result<u64> {
+        let block_params: serde_json::Value =
+            serde_json::from_str(&self.query_params("baseapp", "BlockParams").await?)?;
+
+        // int64 values are json encoded as strings
+        let max_gas = match &block_params["max_gas"] {
+            serde_json::Value::String(s) => s.parse::<i64>()?,
+            serde_json::Value::Number(n) => n
+                .as_i64()
+                .ok_or_else(|| anyhow::anyhow!("invalid max_gas value: {n}"))?,
+            other => return Err(anyhow::anyhow!("invalid max_gas value: {other}")),
+        };
+
+        Ok(u64::try_from(max_gas).unwrap_or(u64::MAX))
+    }
+
+    /// returns whether the given denom can be sent, falling back to the bank
+    /// module default if the denom has no explicit entry
+    async fn query_bank_send_enabled(&self, denom: &str) -> anyhow::Result<bool> {
+        let send_enabled: serde_json::Value =
+            serde_json::from_str(&self.query_params("bank", "SendEnabled").await?)?;
+
+        let denom_entry = send_enabled
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|entry| entry["denom"] == denom);
+
+        if let Some(entry) = denom_entry {
+            return entry["enabled"]
+                .as_bool()
+                .ok_or_else(|| anyhow::anyhow!("invalid send enabled entry: {entry}"));
+        }
+
+        let default_enabled: bool =
+            serde_json::from_str(&self.query_params("bank", "DefaultSendEnabled").await?)?;
+
+        Ok(default_enabled)
+    }
+
+    async fn query_bank_params(&self) -> anyhow::Result<BankParams> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = BankQueryClient::new(channel);
+
+        let params = grpc_client
+            .params(QueryBankParamsRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner()
+            .params
+            .ok_or_else(|| anyhow::anyhow!("No bank params returned"))?;
+
+        // sdk v0.47+ moved the per-denom send flags out of the params into
+        // their own store, only older versions still return them here
+        #[allow(deprecated)]
+        let mut send_enabled: Vec<_> = params.send_enabled.into_iter().map(Into::into).collect();
+
+        if send_enabled.is_empty() {
+            let response = grpc_client
+                .send_enabled(QuerySendEnabledRequest {
+                    denoms: vec![],
+                    pagination: None,
+                })
+                .await;
+
+            match response {
+                Ok(response) => {
+                    send_enabled = response
+                        .into_inner()
+                        .send_enabled
+                        .into_iter()
+                        .map(Into::into)
+                        .collect()
+                }
+                Err(status) if status.code() == tonic::Code::Unimplemented => {}
+                Err(status) => return Err(status.into()),
+            }
+        }
+
+        Ok(BankParams {
+            send_enabled,
+            default_send_enabled: params.default_send_enabled,
+        })
+    }
+
+    async fn query_ibc_transfer_params(&self) -> anyhow::Result<IbcTransferParams> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = IbcTransferQueryClient::new(channel);
+
+        let params = grpc_client
+            .params(QueryIbcTransferParamsRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner()
+            .params
+            .ok_or_else(|| anyhow::anyhow!("No ibc transfer params returned"))?;
+
+        Ok(params.into())
+    }
+
+    /// returns the params of the fee market module, available on chains
+    /// running skip's `x/feemarket` (e.g. neutron)
+    async fn query_fee_market_params(&self) -> anyhow::Result<FeeMarketParams> {
+        let response: FeeMarketParamsResponse = self
+            .query_grpc(
+                "/feemarket.feemarket.v1.Query/Params",
+                FeeMarketParamsRequest {},
+            )
+            .await?;
+
+        response
+            .params
+            .ok_or_else(|| anyhow::anyhow!("No fee market params returned"))?
+            .try_into()
+    }
+
+    /// returns the current gas price of the denom as set by the `x/feemarket`
+    /// module, for chains that price gas dynamically instead of relying on a
+    /// static minimum
+    async fn query_gas_oracle(&self, denom: &str) -> anyhow::Result<f64> {
+        let response: FeeMarketGasPriceResponse = self
+            .query_grpc(
+                "/feemarket.feemarket.v1.Query/GasPrice",
+                FeeMarketGasPriceRequest {
+                    denom: denom.to_string(),
+                },
+            )
+            .await?;
+
+        let price = response
+            .price
+            .ok_or_else(|| anyhow::anyhow!("No gas price returned for {denom}"))?;
+
+        parse_dec(&price.amount)
+    }
+
+    /// returns the evidence stored under the hex encoded hash, if any
+    async fn query_evidence(&self, evidence_hash: &str) -> anyhow::Result<Option<EvidenceInfo>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = EvidenceQueryClient::new(channel);
+
+        // older sdk versions only understand the deprecated raw bytes field
+        #[allow(deprecated)]
+        let request = QueryEvidenceRequest {
+            evidence_hash: hex::decode(evidence_hash)?,
+            hash: evidence_hash.to_string(),
+        };
+
+        match grpc_client.evidence(Request::new(request)).await {
+            Ok(response) => response
+                .into_inner()
+                .evidence
+                .map(EvidenceInfo::try_from)
+                .transpose(),
+            Err(status) if status.code() == tonic::Code::NotFound => Ok(None),
+            Err(status) => Err(status.into()),
+        }
+    }
+
+    async fn query_all_evidence_paged(
+        &self,
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<EvidenceInfo>, PageResponse)> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = EvidenceQueryClient::new(channel);
+
+        let request = QueryAllEvidenceRequest {
+            pagination: Some(page.into()),
+        };
+
+        let response = grpc_client
+            .all_evidence(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let evidence = response
+            .evidence
+            .into_iter()
+            .map(EvidenceInfo::try_from)
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok((evidence, response.pagination.unwrap_or_default().into()))
+    }
+
+    /// returns all evidence of validator misbehaviour stored on the chain
+    async fn query_all_evidence(&self) -> anyhow::Result<Vec<EvidenceInfo>> {
+        collect_all_pages(100, |page| self.query_all_evidence_paged(page)).await
+    }
+
+    /// submits evidence of validator misbehaviour, e.g. an `Equivocation`
+    async fn submit_evidence(
+        &self,
+        evidence: Any,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let submit_msg = MsgSubmitEvidence {
+            submitter: signing_client.address.to_string(),
+            evidence: Some(evidence),
+        };
+
+        self.estimate_and_broadcast(Any::from_msg(&submit_msg)?, memo)
+            .await
+    }
+
+    async fn query_proposal(&self, proposal_id: u64) -> anyhow::Result<CosmosProposal> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = GovQueryClient::new(channel);
+
+        let response = grpc_client
+            .proposal(Request::new(QueryProposalRequest { proposal_id }))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        response
+            .proposal
+            .ok_or_else(|| anyhow::anyhow!("proposal {proposal_id} not found"))?
+            .try_into()
+    }
+
+    /// lists proposals with the given status.
+    /// `ProposalStatus::Unspecified` lists proposals of every status.
+    async fn query_proposals_paged(
+        &self,
+        status: ProposalStatus,
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<CosmosProposal>, PageResponse)> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = GovQueryClient::new(channel);
+
+        let request = QueryProposalsRequest {
+            proposal_status: status as i32,
+            pagination: Some(page.into()),
+            ..Default::default()
+        };
+
+        let response = grpc_client
+            .proposals(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let proposals = response
+            .proposals
+            .into_iter()
+            .map(CosmosProposal::try_from)
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok((proposals, response.pagination.unwrap_or_default().into()))
+    }
+
+    async fn query_proposals(&self, status: ProposalStatus) -> anyhow::Result<Vec<CosmosProposal>> {
+        collect_all_pages(100, |page| self.query_proposals_paged(status, page)).await
+    }
+
+    async fn vote_on_proposal(
+        &self,
+        proposal_id: u64,
+        option: VoteOption,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        if option == VoteOption::Unspecified {
+            return Err(anyhow::anyhow!("vote option must be specified"));
+        }
+
+        let signing_client = self.get_signing_client().await?;
+
+        let vote_msg = MsgVote {
+            proposal_id,
+            voter: signing_client.address.to_string(),
+            option: option as i32,
+        };
+
+        self.estimate_and_broadcast(Any::from_msg(&vote_msg)?, memo)
+            .await
+    }
+}
+
+/// builds a `MsgSend` carrying all of the given coins. the bank module
+/// rejects sends that list the same denom more than once.
+fn multi_denom_send_msg(from: &AccountId, to: &str, amounts: &[Coin]) -> anyhow::Result<Any> {
+    if amounts.is_empty() {
+        return Err(anyhow::anyhow!("no transfer amounts provided for {to}"));
+    }
+
+    let mut denoms = HashSet::new();
+    if let Some(duplicate) = amounts
+        .iter()
+        .find(|coin| !denoms.insert(coin.denom.as_ref()))
+    {
+        return Err(anyhow::anyhow!(
+            "duplicate denom {} in transfer to {to}",
+            duplicate.denom
+        ));
+    }
+
+    MsgSend {
+        from_address: from.clone(),
+        to_address: AccountId::from_str(to)
+            .map_err(|e| anyhow::anyhow!("Failed to parse address: {e}"))?,
+        amount: amounts.to_vec(),
     }
+    .to_any()
+    .map_err(|e| anyhow::anyhow!("Failed to convert to Any: {e}"))
 }
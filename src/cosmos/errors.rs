@@ -0,0 +1,189 @@
+use std::fmt;
+
+/// cosmos-sdk error code for an incorrect account sequence
+const SDK_CODE_WRONG_SEQUENCE: u32 = 32;
+/// cosmos-sdk error code for insufficient funds
+const SDK_CODE_INSUFFICIENT_FUNDS: u32 = 5;
+
+/// typed cosmos client failures.
+///
+/// client methods return `anyhow` errors; where one of these failure modes
+/// is detected the underlying error is a `CosmosError` and can be recovered
+/// with `err.downcast_ref::<CosmosError>()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CosmosError {
+    GrpcStatus { code: tonic::Code, message: String },
+    SequenceMismatch { expected: u64, got: u64 },
+    InsufficientFunds { available: u128, required: u128 },
+    SimulationFailed(String),
+    BroadcastFailed { code: u32, raw_log: String },
+}
+
+impl CosmosError {
+    /// returns true for failures that may succeed if retried, i.e. an
+    /// unavailable node or a stale account sequence
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            CosmosError::GrpcStatus { code, .. } => *code == tonic::Code::Unavailable,
+            CosmosError::SequenceMismatch { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// maps a failed tx result onto the matching error variant
+    pub fn from_tx_result(code: u32, raw_log: &str) -> Self {
+        let parsed = match code {
+            SDK_CODE_WRONG_SEQUENCE => parse_sequence_mismatch(raw_log),
+            SDK_CODE_INSUFFICIENT_FUNDS => parse_insufficient_funds(raw_log),
+            _ => None,
+        };
+
+        parsed.unwrap_or_else(|| CosmosError::BroadcastFailed {
+            code,
+            raw_log: raw_log.to_string(),
+        })
+    }
+
+    /// maps a failed tx simulation onto the matching error variant. the sdk
+    /// does not return abci codes for simulations so the message is inspected.
+    pub fn from_simulation_status(status: tonic::Status) -> Self {
+        let message = status.message();
+
+        if let Some(err) =
+            parse_sequence_mismatch(message).or_else(|| parse_insufficient_funds(message))
+        {
+            return err;
+        }
+
+        match status.code() {
+            tonic::Code::Unavailable => CosmosError::from(status),
+            _ => CosmosError::SimulationFailed(message.to_string()),
+        }
+    }
+}
+
+impl From<tonic::Status> for CosmosError {
+    fn from(status: tonic::Status) -> Self {
+        CosmosError::GrpcStatus {
+            code: status.code(),
+            message: status.message().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for CosmosError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CosmosError::GrpcStatus { code, message } => {
+                write!(f, "grpc request failed ({code:?}): {message}")
+            }
+            CosmosError::SequenceMismatch { expected, got } => {
+                write!(
+                    f,
+                    "account sequence mismatch, expected {expected}, got {got}"
+                )
+            }
+            CosmosError::InsufficientFunds {
+                available,
+                required,
+            } => write!(
+                f,
+                "insufficient funds: {available} available, {required} required"
+            ),
+            CosmosError::SimulationFailed(message) => write!(f, "simulation failed: {message}"),
+            CosmosError::BroadcastFailed { code, raw_log } => {
+                write!(f, "tx failed with code {code}: {raw_log}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CosmosError {}
+
+/// parses `account sequence mismatch, expected 5, got 4: incorrect account sequence`
+fn parse_sequence_mismatch(log: &str) -> Option<CosmosError> {
+    let rest = &log[log.find("account sequence mismatch, expected ")? + 36..];
+    let (expected, rest) = rest.split_once(", got ")?;
+    let got: String = rest.chars().take_while(char::is_ascii_digit).collect();
+
+    Some(CosmosError::SequenceMismatch {
+        expected: expected.parse().ok()?,
+        got: got.parse().ok()?,
+    })
+}
+
+/// parses `spendable balance 100untrn is smaller than 200untrn: insufficient funds`
+fn parse_insufficient_funds(log: &str) -> Option<CosmosError> {
+    let rest = &log[log.find("spendable balance ")? + 18..];
+    let (available, rest) = rest.split_once(" is smaller than ")?;
+
+    let leading_amount = |s: &str| -> Option<u128> {
+        s.chars()
+            .take_while(char::is_ascii_digit)
+            .collect::<String>()
+            .parse()
+            .ok()
+    };
+
+    Some(CosmosError::InsufficientFunds {
+        // an empty spendable balance is rendered without an amount
+        available: leading_amount(available).unwrap_or_default(),
+        required: leading_amount(rest)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_sdk_failures_to_variants() {
+        assert_eq!(
+            CosmosError::from_tx_result(
+                32,
+                "account sequence mismatch, expected 12, got 11: incorrect account sequence"
+            ),
+            CosmosError::SequenceMismatch {
+                expected: 12,
+                got: 11
+            }
+        );
+        assert_eq!(
+            CosmosError::from_tx_result(
+                5,
+                "spendable balance 100untrn is smaller than 2500untrn: insufficient funds"
+            ),
+            CosmosError::InsufficientFunds {
+                available: 100,
+                required: 2500
+            }
+        );
+        assert_eq!(
+            CosmosError::from_tx_result(11, "out of gas"),
+            CosmosError::BroadcastFailed {
+                code: 11,
+                raw_log: "out of gas".to_string()
+            }
+        );
+        assert_eq!(
+            CosmosError::from_simulation_status(tonic::Status::unknown(
+                "failed to execute message; message index: 0: unauthorized"
+            )),
+            CosmosError::SimulationFailed(
+                "failed to execute message; message index: 0: unauthorized".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn only_transient_failures_are_retriable() {
+        assert!(CosmosError::from(tonic::Status::unavailable("down")).is_retriable());
+        assert!(CosmosError::SequenceMismatch {
+            expected: 2,
+            got: 1
+        }
+        .is_retriable());
+        assert!(!CosmosError::from(tonic::Status::not_found("missing")).is_retriable());
+        assert!(!CosmosError::SimulationFailed("boom".to_string()).is_retriable());
+    }
+}
@@ -1,15 +1,35 @@
+use std::sync::Arc;
+
 use alloy::transports::http::reqwest;
 use cosmos_sdk_proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse};
 use cosmrs::{
     tx::{BodyBuilder, Fee, SignDoc, SignerInfo},
     Any, Coin,
 };
+use tokio::sync::Mutex;
 use tonic::{
     async_trait,
+    codec::ProstCodec,
+    codegen::http::uri::PathAndQuery,
     transport::{Channel, ClientTlsConfig},
 };
 
-use super::{signing_client::SigningClient, CosmosServiceClient};
+use super::{errors::CosmosError, signing_client::SigningClient, CosmosServiceClient};
+
+/// chain-registry the gas prices of clients are looked up in by default
+pub const CHAIN_REGISTRY_URL: &str =
+    "https://raw.githubusercontent.com/cosmos/chain-registry/master";
+
+/// lazily opened grpc channel shared by all requests of a client. tonic
+/// channels multiplex requests over a single connection and are cheap to
+/// clone, so one channel serves every grpc service of the chain.
+#[derive(Debug, Clone, Default)]
+pub struct GrpcChannelCache {
+    channel: Arc<Mutex<Option<Channel>>>,
+    /// tls material the channel is opened with
+    #[cfg(feature = "tls")]
+    tls_config: crate::common::tls::SharedTlsConfig,
+}
 
 /// grpc signing client trait to enable transaction signing and grpc channel opening.
 /// implementing this trait is a prerequisite for any clients dealing with cosmos-sdk
@@ -24,12 +44,80 @@ pub trait GrpcSigningClient {
     fn gas_price(&self) -> f64;
     fn gas_adjustment(&self) -> f64;
 
-    /// opens and returns a grpc channel associated with the grpc url of the
-    /// implementing client
+    /// custom tls material to use for the grpc channel. native roots are
+    /// used if none is provided. defaults to the tls config stored in the
+    /// channel cache of the client.
+    #[cfg(feature = "tls")]
+    fn tls_config(&self) -> Option<crate::common::tls::TlsConfig> {
+        self.channel_cache()
+            .and_then(|cache| cache.tls_config.get())
+    }
+
+    /// connects to the grpc endpoint with the given tls material. the config
+    /// is stored in the channel cache of the client, so clients without one
+    /// need to override `tls_config` instead.
+    #[cfg(feature = "tls")]
+    fn with_tls_config(self, tls_config: crate::common::tls::TlsConfig) -> Self
+    where
+        Self: Sized,
+    {
+        if let Some(cache) = self.channel_cache() {
+            cache.tls_config.set(tls_config);
+        }
+        self
+    }
+
+    /// cache used to reuse a single grpc channel across requests. clients
+    /// which do not provide one open a new channel for every request.
+    fn channel_cache(&self) -> Option<&GrpcChannelCache> {
+        None
+    }
+
+    /// returns the cached grpc channel of the client, opening it on first use
     async fn get_grpc_channel(&self) -> anyhow::Result<Channel> {
+        let cache = match self.channel_cache() {
+            Some(cache) => cache,
+            None => return self.connect_grpc_channel().await,
+        };
+
+        // held across the connect so concurrent first requests share a channel
+        let mut cached = cache.channel.lock().await;
+
+        if let Some(channel) = cached.as_ref() {
+            return Ok(channel.clone());
+        }
+
+        let channel = self.connect_grpc_channel().await?;
+        *cached = Some(channel.clone());
+
+        Ok(channel)
+    }
+
+    /// replaces the cached grpc channel with a freshly opened one, e.g. after
+    /// the node was restarted
+    async fn reconnect(&self) -> anyhow::Result<()> {
+        let channel = self.connect_grpc_channel().await?;
+
+        if let Some(cache) = self.channel_cache() {
+            *cache.channel.lock().await = Some(channel);
+        }
+
+        Ok(())
+    }
+
+    /// opens a new grpc channel to the grpc url of the implementing client
+    async fn connect_grpc_channel(&self) -> anyhow::Result<Channel> {
+        #[cfg(feature = "tls")]
+        let tls_config = match self.tls_config() {
+            Some(tls_config) => tls_config.client_tls_config()?,
+            None => ClientTlsConfig::new().with_native_roots(),
+        };
+        #[cfg(not(feature = "tls"))]
+        let tls_config = ClientTlsConfig::new().with_native_roots();
+
         let channel = Channel::from_shared(self.grpc_url())
             .map_err(|_| anyhow::anyhow!("failed to build channel"))?
-            .tls_config(ClientTlsConfig::new().with_native_roots())?
+            .tls_config(tls_config)?
             .connect()
             .await?;
 
@@ -49,6 +137,16 @@ pub trait GrpcSigningClient {
         .await
     }
 
+    /// returns the current on-chain sequence of the signer account
+    async fn get_current_sequence(&self) -> anyhow::Result<u64> {
+        Ok(self.get_signing_client().await?.sequence)
+    }
+
+    /// returns the on-chain account number of the signer account
+    async fn get_account_number(&self) -> anyhow::Result<u64> {
+        Ok(self.get_signing_client().await?.account_number)
+    }
+
     fn get_tx_fee(&self, simulation_response: SimulateResponse) -> anyhow::Result<Fee> {
         let gas_used = simulation_response
             .gas_info
@@ -71,12 +169,21 @@ pub trait GrpcSigningClient {
 
     /// simulates a transaction with the given message.
     async fn simulate_tx(&self, msg: Any) -> anyhow::Result<SimulateResponse> {
+        self.simulate_multi_msg(vec![msg]).await
+    }
+
+    /// simulates a transaction containing all of the given messages. the gas
+    /// used by a batch is lower than the sum of simulating each message on its
+    /// own, as the fixed per-tx overhead is only paid once.
+    ///
+    /// simulations do not consume the account sequence.
+    async fn simulate_multi_msg(&self, msgs: Vec<Any>) -> anyhow::Result<SimulateResponse> {
         let channel = self.get_grpc_channel().await?;
         let signer = self.get_signing_client().await?;
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let tx_body = BodyBuilder::new().msg(msg).finish();
+        let tx_body = BodyBuilder::new().msgs(msgs).finish();
         let auth_info = SignerInfo::single_direct(Some(signer.public_key), signer.sequence)
             .auth_info(cosmrs::tx::Fee::from_amount_and_gas(
                 Coin {
@@ -110,19 +217,71 @@ pub trait GrpcSigningClient {
                 .map_err(|e| anyhow::anyhow!("failed to convert raw tx to bytes: {e}"))?,
         };
 
-        let sim_response = grpc_client.simulate(request).await?.into_inner();
+        let sim_response = grpc_client
+            .simulate(request)
+            .await
+            .map_err(CosmosError::from_simulation_status)?
+            .into_inner();
 
         Ok(sim_response)
     }
 
+    /// performs a unary grpc query against the given method path
+    /// (e.g. `/babylon.btclightclient.v1.Query/Tip`). intended for chain-specific
+    /// modules which do not ship generated grpc query clients.
+    async fn query_grpc<Req, Resp>(&self, path: &str, request: Req) -> anyhow::Result<Resp>
+    where
+        Req: prost::Message + Send + Sync + 'static,
+        Resp: prost::Message + Default + Send + Sync + 'static,
+    {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = tonic::client::Grpc::new(channel);
+        grpc_client
+            .ready()
+            .await
+            .map_err(|e| anyhow::anyhow!("grpc service was not ready: {e}"))?;
+
+        let path = PathAndQuery::try_from(path.to_string())
+            .map_err(|e| anyhow::anyhow!("invalid grpc path {path}: {e}"))?;
+
+        let response = grpc_client
+            .unary(
+                tonic::Request::new(request),
+                path,
+                ProstCodec::<Req, Resp>::default(),
+            )
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok(response)
+    }
+
+    /// estimates the fee for a transaction containing all of the given messages
+    async fn estimate_fee_for_multi_msg(&self, msgs: Vec<Any>) -> anyhow::Result<Fee> {
+        let simulation_response = self.simulate_multi_msg(msgs).await?;
+
+        self.get_tx_fee(simulation_response)
+    }
+
     /// fetches the chain-registry config for the given chain and denom and returns
     /// the average gas price for the chain denom.
     async fn query_chain_gas_config(chain: &str, denom: &str) -> anyhow::Result<f64> {
-        let chain_registry_url = format!(
-            "https://raw.githubusercontent.com/cosmos/chain-registry/master/{chain}/chain.json"
-        );
+        Self::query_registry_gas_price(CHAIN_REGISTRY_URL, chain, denom).await
+    }
+
+    /// fetches the `{chain}/chain.json` config of the given chain from a
+    /// registry laid out like the chain-registry and returns the average gas
+    /// price for the denom.
+    async fn query_registry_gas_price(
+        registry_url: &str,
+        chain: &str,
+        denom: &str,
+    ) -> anyhow::Result<f64> {
+        let chain_config_url = format!("{}/{chain}/chain.json", registry_url.trim_end_matches('/'));
 
-        let response = reqwest::get(chain_registry_url).await?;
+        let response = reqwest::get(chain_config_url).await?;
 
         let config: serde_json::Value = response.json().await?;
 
@@ -133,7 +292,7 @@ pub trait GrpcSigningClient {
         let native_fee = fee_tokens
             .iter()
             .find(|entry| entry["denom"] == denom)
-            .unwrap();
+            .ok_or_else(|| anyhow::anyhow!("no fee token {denom} registered for {chain}"))?;
 
         let average_gas_price = native_fee["average_gas_price"]
             .as_f64()
@@ -141,4 +300,30 @@ pub trait GrpcSigningClient {
 
         Ok(average_gas_price)
     }
+
+    /// updates the gas price used for the fees of the client. clients with a
+    /// fixed gas price return an error.
+    fn set_gas_price(&mut self, _gas_price: f64) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!(
+            "the gas price of the client can not be updated"
+        ))
+    }
+
+    /// updates the gas price of the client to the average gas price of its
+    /// denom in the registry entry of `chain` (e.g. `cosmoshub`). the cosmos
+    /// chain-registry is used if no `registry_url` is given.
+    async fn set_gas_price_from_registry(
+        &mut self,
+        chain: &str,
+        registry_url: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let gas_price = Self::query_registry_gas_price(
+            registry_url.unwrap_or(CHAIN_REGISTRY_URL),
+            chain,
+            &self.chain_denom(),
+        )
+        .await?;
+
+        self.set_gas_price(gas_price)
+    }
 }
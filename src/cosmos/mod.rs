@@ -1,7 +1,11 @@
 pub mod base_client;
+pub mod errors;
 pub mod grpc_client;
 pub mod proto_timestamp;
 pub mod signing_client;
+pub mod transaction_builder;
+pub mod types;
+pub mod utils;
 pub mod wasm_client;
 
 pub(crate) type CosmosServiceClient<T> =
@@ -12,3 +16,27 @@ pub(crate) type BankQueryClient<T> =
     cosmrs::proto::cosmos::bank::v1beta1::query_client::QueryClient<T>;
 pub(crate) type AuthQueryClient<T> =
     cosmos_sdk_proto::cosmos::auth::v1beta1::query_client::QueryClient<T>;
+pub(crate) type DistributionQueryClient<T> =
+    cosmos_sdk_proto::cosmos::distribution::v1beta1::query_client::QueryClient<T>;
+pub(crate) type SlashingQueryClient<T> =
+    cosmos_sdk_proto::cosmos::slashing::v1beta1::query_client::QueryClient<T>;
+pub(crate) type EvidenceQueryClient<T> =
+    cosmos_sdk_proto::cosmos::evidence::v1beta1::query_client::QueryClient<T>;
+pub(crate) type IbcFeeQueryClient<T> =
+    ibc_proto::ibc::applications::fee::v1::query_client::QueryClient<T>;
+pub(crate) type UpgradeQueryClient<T> =
+    cosmos_sdk_proto::cosmos::upgrade::v1beta1::query_client::QueryClient<T>;
+pub(crate) type MintQueryClient<T> =
+    cosmos_sdk_proto::cosmos::mint::v1beta1::query_client::QueryClient<T>;
+pub(crate) type StakingQueryClient<T> =
+    cosmos_sdk_proto::cosmos::staking::v1beta1::query_client::QueryClient<T>;
+pub(crate) type ParamsQueryClient<T> =
+    cosmos_sdk_proto::cosmos::params::v1beta1::query_client::QueryClient<T>;
+pub(crate) type IbcChannelQueryClient<T> =
+    ibc_proto::ibc::core::channel::v1::query_client::QueryClient<T>;
+pub(crate) type IbcTransferQueryClient<T> =
+    ibc_proto::ibc::applications::transfer::v1::query_client::QueryClient<T>;
+pub(crate) type IcaControllerQueryClient<T> =
+    ibc_proto::ibc::applications::interchain_accounts::controller::v1::query_client::QueryClient<T>;
+pub(crate) type GovQueryClient<T> =
+    cosmos_sdk_proto::cosmos::gov::v1beta1::query_client::QueryClient<T>;
@@ -1,6 +1,6 @@
 use bip32::{Language, Mnemonic};
 use cosmos_sdk_proto::cosmos::{
-    auth::v1beta1::QueryAccountInfoRequest,
+    auth::v1beta1::{BaseAccount, QueryAccountInfoRequest},
     tx::v1beta1::{BroadcastMode, BroadcastTxRequest},
 };
 use cosmrs::{
@@ -10,7 +10,7 @@ use cosmrs::{
 };
 use tonic::transport::Channel;
 
-use super::AuthQueryClient;
+use super::{errors::CosmosError, AuthQueryClient};
 
 const DERIVATION_PATH: &str = "m/44'/118'/0'/0/0";
 
@@ -44,18 +44,7 @@ impl SigningClient {
             .account_id(prefix)
             .map_err(|e| anyhow::anyhow!("Failed to get account ID: {e}"))?;
 
-        let mut client = AuthQueryClient::new(channel);
-
-        let account_info_resp = client
-            .account_info(QueryAccountInfoRequest {
-                address: sender_account_id.to_string(),
-            })
-            .await?
-            .into_inner();
-
-        let base_account = account_info_resp
-            .info
-            .ok_or_else(|| anyhow::anyhow!("failed to get base account"))?;
+        let base_account = query_base_account(channel, &sender_account_id).await?;
 
         Ok(SigningClient {
             signing_key,
@@ -67,15 +56,37 @@ impl SigningClient {
         })
     }
 
+    /// resyncs the account number and sequence with the on-chain account
+    /// state, e.g. after txs were sent from the same account elsewhere
+    pub async fn refresh_account(&mut self, channel: Channel) -> anyhow::Result<()> {
+        let base_account = query_base_account(channel, &self.address).await?;
+
+        self.account_number = base_account.account_number;
+        self.sequence = base_account.sequence;
+
+        Ok(())
+    }
+
     /// creates a transaction and signs it with the signing key
     pub async fn create_tx(
         &self,
         msg: Any,
         fee: Fee,
         memo: Option<&str>,
+    ) -> anyhow::Result<BroadcastTxRequest> {
+        self.create_multi_msg_tx(vec![msg], fee, memo).await
+    }
+
+    /// creates a transaction containing all of the given messages (executed
+    /// atomically and in order) and signs it with the signing key
+    pub async fn create_multi_msg_tx(
+        &self,
+        msgs: Vec<Any>,
+        fee: Fee,
+        memo: Option<&str>,
     ) -> anyhow::Result<BroadcastTxRequest> {
         let tx_body = tx::BodyBuilder::new()
-            .msg(msg)
+            .msgs(msgs)
             .memo(memo.unwrap_or_default())
             .finish();
 
@@ -110,3 +121,19 @@ impl SigningClient {
         Ok(broadcast_tx_request)
     }
 }
+
+async fn query_base_account(channel: Channel, address: &AccountId) -> anyhow::Result<BaseAccount> {
+    let mut client = AuthQueryClient::new(channel);
+
+    let account_info_resp = client
+        .account_info(QueryAccountInfoRequest {
+            address: address.to_string(),
+        })
+        .await
+        .map_err(CosmosError::from)?
+        .into_inner();
+
+    account_info_resp
+        .info
+        .ok_or_else(|| anyhow::anyhow!("failed to get base account"))
+}
@@ -0,0 +1,204 @@
+use std::fmt;
+
+use cosmos_sdk_proto::cosmos::tx::v1beta1::SimulateResponse;
+use cosmrs::Any;
+
+use crate::common::transaction::TransactionResponse;
+
+use super::{base_client::BaseClient, types::PollConfig};
+
+/// builds a tx out of one or more messages and runs it through simulation,
+/// fee estimation, signing, broadcasting and confirmation.
+///
+/// ```ignore
+/// let tx_response = TransactionBuilder::new(&client)
+///     .add_msg(approve_msg)
+///     .add_msg(deposit_msg)
+///     .with_memo("deposit")
+///     .with_fee_multiplier(1.5)
+///     .execute()
+///     .await?;
+/// ```
+pub struct TransactionBuilder<'a, C: BaseClient + Sync> {
+    client: &'a C,
+    msgs: Vec<Any>,
+    memo: Option<String>,
+    fee_multiplier: f64,
+}
+
+impl<'a, C: BaseClient + Sync> TransactionBuilder<'a, C> {
+    pub fn new(client: &'a C) -> Self {
+        TransactionBuilder {
+            client,
+            msgs: vec![],
+            memo: None,
+            fee_multiplier: 1.0,
+        }
+    }
+
+    pub fn add_msg(mut self, msg: Any) -> Self {
+        self.msgs.push(msg);
+        self
+    }
+
+    pub fn with_memo(mut self, memo: &str) -> Self {
+        self.memo = Some(memo.to_string());
+        self
+    }
+
+    /// scales the simulated gas on top of the client gas adjustment. must be
+    /// a positive, finite number; it is checked when the tx is executed.
+    pub fn with_fee_multiplier(mut self, fee_multiplier: f64) -> Self {
+        self.fee_multiplier = fee_multiplier;
+        self
+    }
+
+    /// simulates the tx without broadcasting it
+    pub async fn dry_run(self) -> anyhow::Result<SimulateResponse> {
+        self.ensure_msgs()?;
+
+        self.client.simulate_multi_msg(self.msgs).await
+    }
+
+    /// signs and broadcasts the tx, returning once it is included in a block
+    pub async fn execute(self) -> anyhow::Result<TransactionResponse> {
+        self.ensure_msgs()?;
+        self.ensure_fee_multiplier()?;
+
+        let mut simulation_response = self.client.simulate_multi_msg(self.msgs.clone()).await?;
+        if let Some(gas_info) = simulation_response.gas_info.as_mut() {
+            gas_info.gas_used = (gas_info.gas_used as f64 * self.fee_multiplier) as u64;
+        }
+        let fee = self.client.get_tx_fee(simulation_response)?;
+
+        self.client
+            .broadcast_multi_with_fee_and_wait(
+                self.msgs,
+                fee,
+                self.memo.as_deref(),
+                PollConfig::default(),
+            )
+            .await
+    }
+
+    fn ensure_msgs(&self) -> anyhow::Result<()> {
+        if self.msgs.is_empty() {
+            return Err(anyhow::anyhow!("transaction requires at least one message"));
+        }
+        Ok(())
+    }
+
+    fn ensure_fee_multiplier(&self) -> anyhow::Result<()> {
+        if !self.fee_multiplier.is_finite() || self.fee_multiplier <= 0.0 {
+            return Err(anyhow::anyhow!(
+                "fee multiplier must be a positive number, got {}",
+                self.fee_multiplier
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<C: BaseClient + Sync> fmt::Debug for TransactionBuilder<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransactionBuilder")
+            .field("msg_count", &self.msgs.len())
+            .field("memo", &self.memo)
+            .field("fee_multiplier", &self.fee_multiplier)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use crate::cosmos::grpc_client::GrpcSigningClient;
+
+    use super::*;
+
+    /// client without a reachable node, so any rpc made by a test fails
+    struct OfflineClient;
+
+    #[async_trait]
+    impl BaseClient for OfflineClient {}
+
+    #[async_trait]
+    impl GrpcSigningClient for OfflineClient {
+        fn grpc_url(&self) -> String {
+            "http://127.0.0.1:1".to_string()
+        }
+
+        fn mnemonic(&self) -> String {
+            String::new()
+        }
+
+        fn chain_prefix(&self) -> String {
+            "cosmos".to_string()
+        }
+
+        fn chain_id(&self) -> String {
+            "offline-1".to_string()
+        }
+
+        fn chain_denom(&self) -> String {
+            "uatom".to_string()
+        }
+
+        fn gas_price(&self) -> f64 {
+            0.005
+        }
+
+        fn gas_adjustment(&self) -> f64 {
+            1.5
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_tx_is_rejected() {
+        let error = TransactionBuilder::new(&OfflineClient)
+            .execute()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "transaction requires at least one message"
+        );
+
+        let error = TransactionBuilder::new(&OfflineClient)
+            .dry_run()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "transaction requires at least one message"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_fee_multiplier_is_rejected() {
+        for fee_multiplier in [0.0, -1.5, f64::NAN, f64::INFINITY] {
+            let error = TransactionBuilder::new(&OfflineClient)
+                .add_msg(Any::default())
+                .with_fee_multiplier(fee_multiplier)
+                .execute()
+                .await
+                .unwrap_err();
+            assert!(error.to_string().starts_with("fee multiplier must be"));
+        }
+    }
+
+    #[test]
+    fn debug_output_summarizes_the_tx() {
+        let builder = TransactionBuilder::new(&OfflineClient)
+            .add_msg(Any::default())
+            .add_msg(Any::default())
+            .with_memo("deposit")
+            .with_fee_multiplier(1.5);
+
+        assert_eq!(
+            format!("{builder:?}"),
+            r#"TransactionBuilder { msg_count: 2, memo: Some("deposit"), fee_multiplier: 1.5 }"#
+        );
+    }
+}
@@ -0,0 +1,2067 @@
+use std::collections::HashMap;
+
+use cosmos_sdk_proto::cosmos::{
+    bank::v1beta1::{Metadata, SendEnabled},
+    base::{
+        query::v1beta1::{PageRequest as ProtoPageRequest, PageResponse as ProtoPageResponse},
+        v1beta1::{Coin as ProtoCoin, DecCoin},
+    },
+    distribution::v1beta1::{Params, ValidatorSlashEvent},
+    evidence::v1beta1::Equivocation,
+    gov::v1beta1::{Proposal, TallyResult, TextProposal},
+    mint::v1beta1::Params as ProtoMintParams,
+    params::v1beta1::Subspace,
+    slashing::v1beta1::{Params as ProtoSlashingParams, ValidatorSigningInfo},
+    staking::v1beta1::{BondStatus, DelegationResponse, Params as ProtoStakingParams, Validator},
+    upgrade::v1beta1::{ModuleVersion as ProtoModuleVersion, Plan},
+    vesting::v1beta1::{
+        ContinuousVestingAccount, DelayedVestingAccount, PeriodicVestingAccount,
+        PermanentLockedAccount,
+    },
+};
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{
+    AccessType, ContractCodeHistoryEntry, ContractCodeHistoryOperationType,
+    Params as ProtoWasmParams,
+};
+use cosmos_sdk_proto::Any as ProtoAny;
+use cosmrs::proto::tendermint::p2p::DefaultNodeInfo;
+use cosmrs::{
+    bank::{MsgMultiSend, MultiSendIo},
+    tendermint::crypto::{default::Sha256, Sha256 as _},
+    Coin,
+};
+use ibc_proto::ibc::{
+    applications::{
+        fee::v1::{Fee, IdentifiedPacketFees},
+        transfer::v1::Params as ProtoIbcTransferParams,
+    },
+    core::channel::v1::{IdentifiedChannel, PacketState as ProtoPacketState},
+};
+use prost::{Message, Name};
+
+pub use cosmos_sdk_proto::cosmos::gov::v1beta1::{ProposalStatus, VoteOption};
+
+/// number of decimal places used by cosmos-sdk `LegacyDec` values
+const DEC_PRECISION: usize = 18;
+
+/// parses a cosmos-sdk decimal string into an f64.
+///
+/// `LegacyDec` values are transmitted over grpc as their 18-decimal scaled
+/// integer representation (e.g. `"20000000000000000"` for `0.02`), while json
+/// endpoints render them with a decimal point. both forms are accepted here.
+pub fn parse_dec(value: &str) -> anyhow::Result<f64> {
+    let parsed = if value.contains('.') {
+        value.parse::<f64>()?
+    } else {
+        value.parse::<f64>()? / 10f64.powi(DEC_PRECISION as i32)
+    };
+
+    Ok(parsed)
+}
+
+/// parses the integer part of a cosmos-sdk decimal string, truncating any
+/// fractional remainder. accepts the same representations as [`parse_dec`].
+pub fn parse_dec_truncated(value: &str) -> anyhow::Result<u128> {
+    let integer_part = match value.split_once('.') {
+        Some((integer, _)) => integer,
+        None if value.len() > DEC_PRECISION => &value[..value.len() - DEC_PRECISION],
+        None => "0",
+    };
+
+    if integer_part.is_empty() {
+        return Ok(0);
+    }
+
+    integer_part
+        .parse::<u128>()
+        .map_err(|e| anyhow::anyhow!("failed to parse decimal {value}: {e}"))
+}
+
+/// converts a list of proto decimal coins into integer coins, truncating
+/// any fractional amounts.
+pub fn dec_coins_to_coins(dec_coins: Vec<DecCoin>) -> anyhow::Result<Vec<Coin>> {
+    dec_coins
+        .into_iter()
+        .map(|dec_coin| {
+            Ok(Coin {
+                denom: dec_coin
+                    .denom
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Failed to parse denom: {e}"))?,
+                amount: parse_dec_truncated(&dec_coin.amount)?,
+            })
+        })
+        .collect()
+}
+
+/// parsing and arithmetic helpers for [`Coin`]
+pub trait CoinExt: Sized {
+    /// parses coin strings like `1000uatom`, `1000 uatom` or `5.5uusdc`.
+    /// decimal amounts are rounded to the nearest integer.
+    fn parse(s: &str) -> anyhow::Result<Self>;
+
+    /// builds a coin from a human readable amount, e.g. `1.5` with 6
+    /// decimals becomes `1500000`.
+    fn from_decimal(amount: f64, decimals: u8, denom: &str) -> anyhow::Result<Self>;
+
+    /// adds two coins of the same denom
+    fn add(&self, other: &Self) -> anyhow::Result<Self>;
+
+    /// multiplies the amount by `factor`, rounding to the nearest integer
+    fn scale(&self, factor: f64) -> Self;
+
+    fn is_zero(&self) -> bool;
+
+    /// renders the amount with `decimals` decimal places, dropping
+    /// trailing zeros, e.g. `1500000` with 6 decimals becomes `1.5`
+    fn to_display(&self, decimals: u8) -> String;
+}
+
+impl CoinExt for Coin {
+    fn parse(s: &str) -> anyhow::Result<Self> {
+        let s = s.trim();
+        let denom_start = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(|| anyhow::anyhow!("missing denom in coin {s}"))?;
+        let (amount, denom) = s.split_at(denom_start);
+
+        anyhow::ensure!(!amount.is_empty(), "missing amount in coin {s}");
+
+        let amount = if amount.contains('.') {
+            let amount = amount
+                .parse::<f64>()
+                .map_err(|e| anyhow::anyhow!("failed to parse amount of coin {s}: {e}"))?;
+            amount.round() as u128
+        } else {
+            amount
+                .parse::<u128>()
+                .map_err(|e| anyhow::anyhow!("failed to parse amount of coin {s}: {e}"))?
+        };
+
+        Ok(Coin {
+            denom: denom
+                .trim_start()
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Failed to parse denom: {e}"))?,
+            amount,
+        })
+    }
+
+    fn from_decimal(amount: f64, decimals: u8, denom: &str) -> anyhow::Result<Self> {
+        Ok(Coin {
+            denom: denom
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Failed to parse denom: {e}"))?,
+            amount: (amount * 10f64.powi(decimals as i32)).round() as u128,
+        })
+    }
+
+    fn add(&self, other: &Self) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            self.denom == other.denom,
+            "cannot add {} to {}",
+            other.denom,
+            self.denom
+        );
+
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or_else(|| anyhow::anyhow!("overflow adding {} amounts", self.denom))?;
+
+        Ok(Coin {
+            denom: self.denom.clone(),
+            amount,
+        })
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        Coin {
+            denom: self.denom.clone(),
+            amount: (self.amount as f64 * factor).round() as u128,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    fn to_display(&self, decimals: u8) -> String {
+        let unit = 10u128.pow(decimals as u32);
+        let whole = self.amount / unit;
+        let fraction = self.amount % unit;
+
+        if fraction == 0 {
+            return whole.to_string();
+        }
+
+        let fraction = format!("{fraction:0width$}", width = decimals as usize);
+
+        format!("{whole}.{}", fraction.trim_end_matches('0'))
+    }
+}
+
+/// sums the amounts of a multi-denom coin list per denom
+pub fn sum_coins(coins: &[Coin]) -> HashMap<String, u128> {
+    let mut totals: HashMap<String, u128> = HashMap::new();
+
+    for coin in coins {
+        *totals.entry(coin.denom.to_string()).or_default() += coin.amount;
+    }
+
+    totals
+}
+
+/// sender of a `MsgMultiSend`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiSendInput {
+    pub address: String,
+    pub coins: Vec<Coin>,
+}
+
+/// recipient of a `MsgMultiSend`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiSendOutput {
+    pub address: String,
+    pub coins: Vec<Coin>,
+}
+
+/// builds a `MsgMultiSend`, checking that the inputs and outputs move the
+/// same amount of every denom as the bank module requires
+pub fn multi_send_msg(
+    inputs: Vec<MultiSendInput>,
+    outputs: Vec<MultiSendOutput>,
+) -> anyhow::Result<MsgMultiSend> {
+    let input_coins: Vec<Coin> = inputs.iter().flat_map(|i| i.coins.clone()).collect();
+    let output_coins: Vec<Coin> = outputs.iter().flat_map(|o| o.coins.clone()).collect();
+
+    if sum_coins(&input_coins) != sum_coins(&output_coins) {
+        return Err(anyhow::anyhow!(
+            "multi-send inputs {input_coins:?} do not match outputs {output_coins:?}"
+        ));
+    }
+
+    let to_io = |address: String, coins: Vec<Coin>| {
+        Ok(MultiSendIo {
+            address: address
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Failed to parse address: {e}"))?,
+            coins,
+        })
+    };
+
+    Ok(MsgMultiSend {
+        inputs: inputs
+            .into_iter()
+            .map(|i| to_io(i.address, i.coins))
+            .collect::<anyhow::Result<_>>()?,
+        outputs: outputs
+            .into_iter()
+            .map(|o| to_io(o.address, o.coins))
+            .collect::<anyhow::Result<_>>()?,
+    })
+}
+
+/// pagination options of a list query. pages are selected either by the
+/// `next_key` of a previous response or by an offset, not both.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PageRequest {
+    pub key: Option<Vec<u8>>,
+    pub offset: Option<u64>,
+    /// max number of results per page. 0 falls back to the node default.
+    pub limit: u64,
+    pub count_total: bool,
+    pub reverse: bool,
+}
+
+impl From<PageRequest> for ProtoPageRequest {
+    fn from(value: PageRequest) -> Self {
+        ProtoPageRequest {
+            key: value.key.unwrap_or_default(),
+            offset: value.offset.unwrap_or_default(),
+            limit: value.limit,
+            count_total: value.count_total,
+            reverse: value.reverse,
+        }
+    }
+}
+
+/// pagination state returned along with a page of results
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PageResponse {
+    /// key to request the next page with. `None` once the last page is reached.
+    pub next_key: Option<Vec<u8>>,
+    /// total number of results, only set if requested with `count_total`
+    pub total: Option<u64>,
+}
+
+impl From<ProtoPageResponse> for PageResponse {
+    fn from(value: ProtoPageResponse) -> Self {
+        PageResponse {
+            next_key: Some(value.next_key).filter(|k| !k.is_empty()),
+            total: Some(value.total).filter(|t| *t > 0),
+        }
+    }
+}
+
+/// options applied to grpc query requests
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryOptions {
+    /// queries state as of the given block height. heights that have been
+    /// pruned can only be served by archive nodes.
+    WithBlockHeight(u64),
+}
+
+impl QueryOptions {
+    pub fn apply<T>(&self, request: &mut tonic::Request<T>) {
+        match self {
+            QueryOptions::WithBlockHeight(height) => {
+                request.metadata_mut().insert(
+                    "x-cosmos-block-height",
+                    tonic::metadata::MetadataValue::from(*height),
+                );
+            }
+        }
+    }
+}
+
+/// parameters of the distribution module
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistributionParams {
+    pub community_tax: f64,
+    pub base_proposer_reward: f64,
+    pub bonus_proposer_reward: f64,
+    pub withdraw_addr_enabled: bool,
+}
+
+impl TryFrom<Params> for DistributionParams {
+    type Error = anyhow::Error;
+
+    // proposer reward params are deprecated upstream but still returned by
+    // chains running older sdk versions
+    #[allow(deprecated)]
+    fn try_from(value: Params) -> anyhow::Result<Self> {
+        Ok(Self {
+            community_tax: parse_dec(&value.community_tax)?,
+            base_proposer_reward: parse_dec(&value.base_proposer_reward)?,
+            bonus_proposer_reward: parse_dec(&value.bonus_proposer_reward)?,
+            withdraw_addr_enabled: value.withdraw_addr_enabled,
+        })
+    }
+}
+
+/// parameters of the slashing module
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlashingParams {
+    pub signed_blocks_window: i64,
+    pub min_signed_per_window: f64,
+    /// jail duration for downtime in seconds
+    pub downtime_jail_duration: u64,
+    pub slash_fraction_double_sign: f64,
+    pub slash_fraction_downtime: f64,
+}
+
+impl TryFrom<ProtoSlashingParams> for SlashingParams {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ProtoSlashingParams) -> anyhow::Result<Self> {
+        // decimals are returned as the bytes of their string representation
+        let dec_bytes = |bytes: &[u8]| parse_dec(std::str::from_utf8(bytes)?);
+
+        let downtime_jail_duration = value
+            .downtime_jail_duration
+            .map(|d| u64::try_from(d.seconds))
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self {
+            signed_blocks_window: value.signed_blocks_window,
+            min_signed_per_window: dec_bytes(&value.min_signed_per_window)?,
+            downtime_jail_duration,
+            slash_fraction_double_sign: dec_bytes(&value.slash_fraction_double_sign)?,
+            slash_fraction_downtime: dec_bytes(&value.slash_fraction_downtime)?,
+        })
+    }
+}
+
+/// liveness tracking of a validator by the slashing module
+#[derive(Debug, Clone, PartialEq)]
+pub struct SigningInfo {
+    /// consensus address of the validator
+    pub address: String,
+    pub start_height: i64,
+    pub index_offset: i64,
+    pub missed_blocks_counter: i64,
+    /// rfc3339 time until which the validator is jailed, if ever jailed
+    pub jailed_until: Option<String>,
+    pub tombstoned: bool,
+}
+
+impl TryFrom<ValidatorSigningInfo> for SigningInfo {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ValidatorSigningInfo) -> anyhow::Result<Self> {
+        // never jailed validators report the zero timestamp
+        let jailed_until = value
+            .jailed_until
+            .filter(|time| time.seconds > 0)
+            .map(|time| anyhow::Ok(cosmrs::tendermint::Time::try_from(time)?.to_rfc3339()))
+            .transpose()?;
+
+        Ok(Self {
+            address: value.address,
+            start_height: value.start_height,
+            index_offset: value.index_offset,
+            missed_blocks_counter: value.missed_blocks_counter,
+            jailed_until,
+            tombstoned: value.tombstoned,
+        })
+    }
+}
+
+/// slash of a validator as recorded by the distribution module
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlashEvent {
+    pub validator_period: u64,
+    pub fraction: f64,
+}
+
+impl TryFrom<ValidatorSlashEvent> for SlashEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ValidatorSlashEvent) -> anyhow::Result<Self> {
+        Ok(Self {
+            validator_period: value.validator_period,
+            fraction: parse_dec(&value.fraction)?,
+        })
+    }
+}
+
+/// converts a list of proto coins into integer coins
+pub fn proto_coins_to_coins(proto_coins: &[ProtoCoin]) -> anyhow::Result<Vec<Coin>> {
+    proto_coins
+        .iter()
+        .map(|coin| Coin::try_from(coin).map_err(|e| anyhow::anyhow!("Failed to parse coin: {e}")))
+        .collect()
+}
+
+/// relayer fees to escrow for an ics-29 incentivised ibc packet
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IbcFeeParams {
+    pub recv_fee: Vec<Coin>,
+    pub ack_fee: Vec<Coin>,
+    pub timeout_fee: Vec<Coin>,
+}
+
+impl From<IbcFeeParams> for Fee {
+    fn from(value: IbcFeeParams) -> Self {
+        Fee {
+            recv_fee: value.recv_fee.into_iter().map(Into::into).collect(),
+            ack_fee: value.ack_fee.into_iter().map(Into::into).collect(),
+            timeout_fee: value.timeout_fee.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// ibc packet with the relayer fees escrowed for it. fees of every payer
+/// of the packet are combined.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncentivisedPacket {
+    /// (port id, channel id, sequence)
+    pub packet_id: (String, String, u64),
+    pub recv_fee: Vec<Coin>,
+    pub ack_fee: Vec<Coin>,
+    pub timeout_fee: Vec<Coin>,
+}
+
+impl TryFrom<IdentifiedPacketFees> for IncentivisedPacket {
+    type Error = anyhow::Error;
+
+    fn try_from(value: IdentifiedPacketFees) -> anyhow::Result<Self> {
+        let packet_id = value
+            .packet_id
+            .ok_or_else(|| anyhow::anyhow!("incentivised packet is missing its packet id"))?;
+
+        let mut packet = IncentivisedPacket {
+            packet_id: (packet_id.port_id, packet_id.channel_id, packet_id.sequence),
+            recv_fee: vec![],
+            ack_fee: vec![],
+            timeout_fee: vec![],
+        };
+
+        for fee in value.packet_fees.into_iter().filter_map(|f| f.fee) {
+            packet.recv_fee.extend(proto_coins_to_coins(&fee.recv_fee)?);
+            packet.ack_fee.extend(proto_coins_to_coins(&fee.ack_fee)?);
+            packet
+                .timeout_fee
+                .extend(proto_coins_to_coins(&fee.timeout_fee)?);
+        }
+
+        Ok(packet)
+    }
+}
+
+/// packet commitment or acknowledgement stored by the ibc channel module
+#[derive(Debug, Clone, PartialEq)]
+pub struct PacketState {
+    pub port_id: String,
+    pub channel_id: String,
+    pub sequence: u64,
+    /// commitment or acknowledgement hash
+    pub data: Vec<u8>,
+}
+
+impl From<ProtoPacketState> for PacketState {
+    fn from(value: ProtoPacketState) -> Self {
+        PacketState {
+            port_id: value.port_id,
+            channel_id: value.channel_id,
+            sequence: value.sequence,
+            data: value.data,
+        }
+    }
+}
+
+/// single leg of a multi-hop ibc transfer
+#[derive(Debug, Clone, PartialEq)]
+pub struct IbcHop {
+    pub channel: String,
+    pub port: String,
+    pub timeout_seconds: u64,
+}
+
+/// ibc transfer routed over several chains using packet forward middleware
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiHopTransferParams {
+    /// hops in the order they are taken. the first hop is the channel the
+    /// transfer is sent over from this chain; its timeout is `timeout_seconds`.
+    pub hops: Vec<IbcHop>,
+    pub final_receiver: String,
+    pub amount: u128,
+    pub denom: String,
+    pub timeout_seconds: u64,
+    /// number of times each forward is retried by the intermediate chains
+    pub retries: u8,
+}
+
+impl MultiHopTransferParams {
+    /// receiver placeholder accepted by packet forward middleware on
+    /// intermediate chains, where funds never settle
+    pub const PFM_RECEIVER_PLACEHOLDER: &'static str = "pfm";
+
+    /// receiver of the initial transfer: the final receiver for a single hop,
+    /// otherwise the intermediate chain which forwards the packet
+    pub fn first_hop_receiver(&self) -> String {
+        match self.hops.len() {
+            0 | 1 => self.final_receiver.clone(),
+            _ => Self::PFM_RECEIVER_PLACEHOLDER.to_string(),
+        }
+    }
+
+    /// builds the nested packet forward middleware memo for every hop after
+    /// the first one. returns `None` for single hop transfers.
+    pub fn pfm_memo(&self) -> Option<String> {
+        let forwards = self.hops.get(1..).filter(|hops| !hops.is_empty())?;
+
+        let memo = forwards
+            .iter()
+            .enumerate()
+            .rev()
+            .fold(None, |next, (i, hop)| {
+                let receiver = if i == forwards.len() - 1 {
+                    self.final_receiver.as_str()
+                } else {
+                    Self::PFM_RECEIVER_PLACEHOLDER
+                };
+
+                let mut forward = serde_json::json!({
+                    "receiver": receiver,
+                    "port": hop.port,
+                    "channel": hop.channel,
+                    "retries": self.retries,
+                });
+                if hop.timeout_seconds > 0 {
+                    forward["timeout"] = format!("{}s", hop.timeout_seconds).into();
+                }
+                if let Some(next) = next {
+                    forward["next"] = next;
+                }
+
+                Some(serde_json::json!({ "forward": forward }))
+            })?;
+
+        Some(memo.to_string())
+    }
+}
+
+/// connectivity diagnostics of a cosmos client
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthStatus {
+    pub block_height: u64,
+    pub chain_id: String,
+    /// round trip time of the health check query
+    pub latency_ms: u64,
+}
+
+/// p2p info the connected node reports about itself
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeInfo {
+    pub default_node_id: String,
+    pub listen_addr: String,
+    /// chain id of the network the node is part of
+    pub network: String,
+    pub version: String,
+    /// hex encoded ids of the p2p channels the node supports
+    pub channels: String,
+    pub moniker: String,
+    /// `tx_index` and `rpc_address` settings of the node
+    pub other: HashMap<String, String>,
+}
+
+impl From<DefaultNodeInfo> for NodeInfo {
+    fn from(value: DefaultNodeInfo) -> Self {
+        let other = value
+            .other
+            .map(|other| {
+                HashMap::from([
+                    ("tx_index".to_string(), other.tx_index),
+                    ("rpc_address".to_string(), other.rpc_address),
+                ])
+            })
+            .unwrap_or_default();
+
+        NodeInfo {
+            default_node_id: value.default_node_id,
+            listen_addr: value.listen_addr,
+            network: value.network,
+            version: value.version,
+            channels: hex::encode(value.channels),
+            moniker: value.moniker,
+            other,
+        }
+    }
+}
+
+/// evidence of validator misbehaviour stored by the evidence module.
+/// only equivocation evidence is decoded, other evidence types only carry
+/// their hash and type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvidenceInfo {
+    /// hex encoded hash the evidence is stored under
+    pub hash: String,
+    /// type url of the evidence
+    pub evidence_type: String,
+    pub height: i64,
+    /// rfc3339 time of the infraction
+    pub time: Option<String>,
+    /// consensus address of the misbehaving validator
+    pub address: String,
+    /// voting power of the validator at the time of the infraction
+    pub power: i64,
+}
+
+impl TryFrom<ProtoAny> for EvidenceInfo {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ProtoAny) -> anyhow::Result<Self> {
+        let mut evidence = EvidenceInfo {
+            hash: hex::encode_upper(Sha256::digest(&value.value)),
+            evidence_type: value.type_url.clone(),
+            height: 0,
+            time: None,
+            address: String::new(),
+            power: 0,
+        };
+
+        if value.type_url == Equivocation::type_url() {
+            let equivocation = Equivocation::decode(value.value.as_slice())?;
+
+            evidence.height = equivocation.height;
+            evidence.time = equivocation
+                .time
+                .map(|time| anyhow::Ok(cosmrs::tendermint::Time::try_from(time)?.to_rfc3339()))
+                .transpose()?;
+            evidence.address = equivocation.consensus_address;
+            evidence.power = equivocation.power;
+        }
+
+        Ok(evidence)
+    }
+}
+
+/// parameters of the mint module
+#[derive(Debug, Clone, PartialEq)]
+pub struct MintParams {
+    pub mint_denom: String,
+    pub inflation_rate_change: f64,
+    pub inflation_max: f64,
+    pub inflation_min: f64,
+    pub goal_bonded: f64,
+    pub blocks_per_year: u64,
+}
+
+impl TryFrom<ProtoMintParams> for MintParams {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ProtoMintParams) -> anyhow::Result<Self> {
+        Ok(Self {
+            mint_denom: value.mint_denom,
+            inflation_rate_change: parse_dec(&value.inflation_rate_change)?,
+            inflation_max: parse_dec(&value.inflation_max)?,
+            inflation_min: parse_dec(&value.inflation_min)?,
+            goal_bonded: parse_dec(&value.goal_bonded)?,
+            blocks_per_year: value.blocks_per_year,
+        })
+    }
+}
+
+/// parameters of the staking module
+#[derive(Debug, Clone, PartialEq)]
+pub struct StakingParams {
+    /// unbonding period in seconds
+    pub unbonding_time: u64,
+    pub max_validators: u32,
+    pub max_entries: u32,
+    pub historical_entries: u32,
+    pub bond_denom: String,
+}
+
+impl TryFrom<ProtoStakingParams> for StakingParams {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ProtoStakingParams) -> anyhow::Result<Self> {
+        let unbonding_time = value
+            .unbonding_time
+            .map(|d| u64::try_from(d.seconds))
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self {
+            unbonding_time,
+            max_validators: value.max_validators,
+            max_entries: value.max_entries,
+            historical_entries: value.historical_entries,
+            bond_denom: value.bond_denom,
+        })
+    }
+}
+
+/// staking module validator
+#[derive(Debug, Clone, PartialEq)]
+pub struct CosmosValidator {
+    pub operator_address: String,
+    pub moniker: String,
+    pub jailed: bool,
+    pub status: BondStatus,
+    /// tokens delegated to the validator, in the bond denom
+    pub tokens: u128,
+    pub delegator_shares: f64,
+    pub commission_rate: f64,
+}
+
+impl TryFrom<Validator> for CosmosValidator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Validator) -> anyhow::Result<Self> {
+        let status = BondStatus::try_from(value.status)
+            .map_err(|e| anyhow::anyhow!("unknown bond status {}: {e}", value.status))?;
+
+        let commission_rate = value
+            .commission
+            .and_then(|c| c.commission_rates)
+            .map(|rates| parse_dec(&rates.rate))
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self {
+            operator_address: value.operator_address,
+            moniker: value.description.unwrap_or_default().moniker,
+            jailed: value.jailed,
+            status,
+            tokens: value.tokens.parse()?,
+            delegator_shares: parse_dec(&value.delegator_shares)?,
+            commission_rate,
+        })
+    }
+}
+
+/// delegation of a delegator to a single validator
+#[derive(Debug, Clone, PartialEq)]
+pub struct CosmosDelegation {
+    pub delegator_address: String,
+    pub validator_address: String,
+    pub shares: f64,
+    /// tokens backing the delegation shares
+    pub balance: Coin,
+}
+
+impl TryFrom<DelegationResponse> for CosmosDelegation {
+    type Error = anyhow::Error;
+
+    fn try_from(value: DelegationResponse) -> anyhow::Result<Self> {
+        let delegation = value
+            .delegation
+            .ok_or_else(|| anyhow::anyhow!("delegation response has no delegation"))?;
+
+        let balance = value
+            .balance
+            .ok_or_else(|| anyhow::anyhow!("delegation response has no balance"))?;
+
+        Ok(Self {
+            delegator_address: delegation.delegator_address,
+            validator_address: delegation.validator_address,
+            shares: parse_dec(&delegation.shares)?,
+            balance: Coin::try_from(balance)
+                .map_err(|e| anyhow::anyhow!("Failed to parse coin: {e}"))?,
+        })
+    }
+}
+
+/// legacy (v1beta1) governance proposal
+#[derive(Debug, Clone, PartialEq)]
+pub struct CosmosProposal {
+    pub proposal_id: u64,
+    /// type url of the proposal content, e.g. `/cosmos.gov.v1beta1.TextProposal`
+    pub content_type_url: String,
+    pub title: String,
+    pub description: String,
+    pub status: ProposalStatus,
+    /// final tally, only populated once voting has ended
+    pub final_tally: ProposalTally,
+    /// unix timestamps (seconds) of the proposal lifecycle
+    pub submit_time: Option<i64>,
+    pub voting_end_time: Option<i64>,
+    pub total_deposit: Vec<Coin>,
+}
+
+/// vote tally of a proposal, in the bond denom
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProposalTally {
+    pub yes: u128,
+    pub abstain: u128,
+    pub no: u128,
+    pub no_with_veto: u128,
+}
+
+impl TryFrom<TallyResult> for ProposalTally {
+    type Error = anyhow::Error;
+
+    fn try_from(value: TallyResult) -> anyhow::Result<Self> {
+        // unset tallies are transmitted as empty strings
+        let parse = |amount: &str| -> anyhow::Result<u128> {
+            match amount.is_empty() {
+                true => Ok(0),
+                false => Ok(amount.parse()?),
+            }
+        };
+
+        Ok(Self {
+            yes: parse(&value.yes)?,
+            abstain: parse(&value.abstain)?,
+            no: parse(&value.no)?,
+            no_with_veto: parse(&value.no_with_veto)?,
+        })
+    }
+}
+
+impl TryFrom<Proposal> for CosmosProposal {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Proposal) -> anyhow::Result<Self> {
+        let status = ProposalStatus::try_from(value.status)
+            .map_err(|e| anyhow::anyhow!("unknown proposal status {}: {e}", value.status))?;
+
+        // every legacy content type starts with the title and description
+        // fields, so they can be read without knowing the concrete type
+        let content = value.content.unwrap_or_default();
+        let text = TextProposal::decode(content.value.as_slice())?;
+
+        Ok(Self {
+            proposal_id: value.proposal_id,
+            content_type_url: content.type_url,
+            title: text.title,
+            description: text.description,
+            status,
+            final_tally: value
+                .final_tally_result
+                .map(ProposalTally::try_from)
+                .transpose()?
+                .unwrap_or_default(),
+            submit_time: value.submit_time.map(|t| t.seconds),
+            voting_end_time: value.voting_end_time.map(|t| t.seconds),
+            total_deposit: proto_coins_to_coins(&value.total_deposit)?,
+        })
+    }
+}
+
+/// legacy params module subspace along with its registered keys
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamSubspace {
+    pub subspace: String,
+    pub keys: Vec<String>,
+}
+
+impl From<Subspace> for ParamSubspace {
+    fn from(value: Subspace) -> Self {
+        ParamSubspace {
+            subspace: value.subspace,
+            keys: value.keys,
+        }
+    }
+}
+
+/// operation that set the code of a contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractOperation {
+    Init,
+    Migrate,
+    Genesis,
+}
+
+/// entry of a contract's code history
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractHistoryEntry {
+    pub operation: ContractOperation,
+    pub code_id: u64,
+    /// height of the block in which the operation was executed
+    pub updated_height: Option<u64>,
+    /// instantiate or migrate msg, `Null` if the entry has none
+    pub msg: serde_json::Value,
+}
+
+impl TryFrom<ContractCodeHistoryEntry> for ContractHistoryEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ContractCodeHistoryEntry) -> anyhow::Result<Self> {
+        let operation = match value.operation() {
+            ContractCodeHistoryOperationType::Init => ContractOperation::Init,
+            ContractCodeHistoryOperationType::Migrate => ContractOperation::Migrate,
+            ContractCodeHistoryOperationType::Genesis => ContractOperation::Genesis,
+            ContractCodeHistoryOperationType::Unspecified => {
+                return Err(anyhow::anyhow!("unspecified contract history operation"))
+            }
+        };
+
+        let msg = match value.msg.is_empty() {
+            true => serde_json::Value::Null,
+            false => serde_json::from_slice(&value.msg)?,
+        };
+
+        Ok(ContractHistoryEntry {
+            operation,
+            code_id: value.code_id,
+            updated_height: value.updated.map(|position| position.block_height),
+            msg,
+        })
+    }
+}
+
+/// wasm module upload and instantiation permissions
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmParams {
+    /// access type name, e.g. `ACCESS_TYPE_EVERYBODY`
+    pub code_upload_access: String,
+    /// access type name, e.g. `ACCESS_TYPE_EVERYBODY`
+    pub instantiate_default_permission: String,
+    /// max size of an uploaded wasm binary in bytes
+    pub max_wasm_code_size: u64,
+}
+
+impl WasmParams {
+    /// default max code size compiled into wasmd, used when a chain does not
+    /// expose it as a param
+    pub const DEFAULT_MAX_WASM_CODE_SIZE: u64 = 800 * 1024;
+
+    pub(crate) fn from_proto(value: ProtoWasmParams, max_wasm_code_size: u64) -> Self {
+        let access_name = |permission: i32| {
+            AccessType::try_from(permission)
+                .unwrap_or(AccessType::Unspecified)
+                .as_str_name()
+                .to_string()
+        };
+
+        WasmParams {
+            code_upload_access: access_name(
+                value
+                    .code_upload_access
+                    .map(|a| a.permission)
+                    .unwrap_or_default(),
+            ),
+            instantiate_default_permission: access_name(value.instantiate_default_permission),
+            max_wasm_code_size,
+        }
+    }
+}
+
+/// a single unlock step of a periodic vesting schedule
+#[derive(Debug, Clone, PartialEq)]
+pub struct VestingPeriod {
+    /// duration of the period in seconds, relative to the end of the previous one
+    pub length: i64,
+    pub amount: Vec<Coin>,
+}
+
+/// vesting schedule of a continuous, delayed, periodic or permanently
+/// locked vesting account.
+/// delayed accounts have no `start_time`, permanently locked accounts have
+/// neither a `start_time` nor an `end_time`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VestingInfo {
+    pub original_vesting: Vec<Coin>,
+    pub delegated_free: Vec<Coin>,
+    pub delegated_vesting: Vec<Coin>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub vesting_periods: Vec<VestingPeriod>,
+}
+
+impl VestingInfo {
+    /// decodes the vesting schedule of an `x/auth` account. returns `None`
+    /// if the account is not a vesting account.
+    pub(crate) fn from_account(account: &ProtoAny) -> anyhow::Result<Option<Self>> {
+        let type_url = account.type_url.as_str();
+        let value = account.value.as_slice();
+
+        let (base, start_time, periods) = if type_url == ContinuousVestingAccount::type_url() {
+            let account = ContinuousVestingAccount::decode(value)?;
+            (
+                account.base_vesting_account,
+                Some(account.start_time),
+                vec![],
+            )
+        } else if type_url == DelayedVestingAccount::type_url() {
+            let account = DelayedVestingAccount::decode(value)?;
+            (account.base_vesting_account, None, vec![])
+        } else if type_url == PeriodicVestingAccount::type_url() {
+            let account = PeriodicVestingAccount::decode(value)?;
+            (
+                account.base_vesting_account,
+                Some(account.start_time),
+                account.vesting_periods,
+            )
+        } else if type_url == PermanentLockedAccount::type_url() {
+            let account = PermanentLockedAccount::decode(value)?;
+            (account.base_vesting_account, None, vec![])
+        } else {
+            return Ok(None);
+        };
+
+        let base = base.ok_or_else(|| anyhow::anyhow!("vesting account without base account"))?;
+
+        let end_time = match type_url == PermanentLockedAccount::type_url() {
+            true => None,
+            false => Some(base.end_time),
+        };
+
+        let vesting_periods = periods
+            .into_iter()
+            .map(|period| {
+                Ok(VestingPeriod {
+                    length: period.length,
+                    amount: proto_coins_to_coins(&period.amount)?,
+                })
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Some(VestingInfo {
+            original_vesting: proto_coins_to_coins(&base.original_vesting)?,
+            delegated_free: proto_coins_to_coins(&base.delegated_free)?,
+            delegated_vesting: proto_coins_to_coins(&base.delegated_vesting)?,
+            start_time,
+            end_time,
+            vesting_periods,
+        }))
+    }
+
+    /// returns the part of the original vesting that is unlocked and
+    /// therefore spendable at the given unix timestamp (in seconds)
+    pub fn spendable_at(&self, unix_timestamp: i64) -> Vec<Coin> {
+        let Some(end_time) = self.end_time else {
+            return vec![];
+        };
+
+        if unix_timestamp >= end_time {
+            return self.original_vesting.clone();
+        }
+
+        let Some(start_time) = self.start_time else {
+            return vec![];
+        };
+
+        if unix_timestamp <= start_time {
+            return vec![];
+        }
+
+        if !self.vesting_periods.is_empty() {
+            let mut period_end = start_time;
+            let mut unlocked: Vec<Coin> = vec![];
+
+            for period in &self.vesting_periods {
+                period_end += period.length;
+                if period_end > unix_timestamp {
+                    break;
+                }
+                for coin in &period.amount {
+                    match unlocked.iter_mut().find(|c| c.denom == coin.denom) {
+                        Some(existing) => existing.amount += coin.amount,
+                        None => unlocked.push(coin.clone()),
+                    }
+                }
+            }
+
+            return unlocked;
+        }
+
+        // continuous vesting unlocks linearly between start and end time
+        let elapsed = (unix_timestamp - start_time) as u128;
+        let duration = (end_time - start_time) as u128;
+
+        self.original_vesting
+            .iter()
+            .map(|coin| Coin {
+                denom: coin.denom.clone(),
+                amount: coin.amount * elapsed / duration,
+            })
+            .collect()
+    }
+}
+
+/// bank module metadata of a denom
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenomMetadata {
+    pub description: String,
+    pub base: String,
+    pub display: String,
+    pub symbol: String,
+    pub denom_units: Vec<DenomUnit>,
+}
+
+/// unit of a denom, `exponent` being the power of 10 of one unit in terms
+/// of the base denom
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenomUnit {
+    pub denom: String,
+    pub exponent: u32,
+    pub aliases: Vec<String>,
+}
+
+impl DenomMetadata {
+    /// returns the exponent of the display unit, e.g. 6 for `atom`
+    pub fn display_exponent(&self) -> Option<u32> {
+        self.denom_units
+            .iter()
+            .find(|unit| unit.denom == self.display)
+            .map(|unit| unit.exponent)
+    }
+}
+
+impl From<Metadata> for DenomMetadata {
+    fn from(value: Metadata) -> Self {
+        DenomMetadata {
+            description: value.description,
+            base: value.base,
+            display: value.display,
+            symbol: value.symbol,
+            denom_units: value
+                .denom_units
+                .into_iter()
+                .map(|unit| DenomUnit {
+                    denom: unit.denom,
+                    exponent: unit.exponent,
+                    aliases: unit.aliases,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// how often and how long to poll for a tx to be included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollConfig {
+    pub interval: std::time::Duration,
+    pub max_attempts: u32,
+}
+
+impl Default for PollConfig {
+    /// polls for ~10 seconds
+    fn default() -> Self {
+        PollConfig {
+            interval: std::time::Duration::from_millis(200),
+            max_attempts: 50,
+        }
+    }
+}
+
+/// ics-27 interchain account channel of a controller account
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcaChannel {
+    pub port_id: String,
+    pub channel_id: String,
+    pub connection_id: String,
+    /// host chain address of the interchain account, taken from the
+    /// negotiated channel version
+    pub address: Option<String>,
+}
+
+impl IcaChannel {
+    /// port prefix of ics-27 controller channels, followed by the owner
+    pub const CONTROLLER_PORT_PREFIX: &'static str = "icacontroller-";
+
+    pub(crate) fn from_channel(channel: IdentifiedChannel) -> Self {
+        IcaChannel {
+            address: ica_version_address(&channel.version),
+            connection_id: channel
+                .connection_hops
+                .into_iter()
+                .next()
+                .unwrap_or_default(),
+            port_id: channel.port_id,
+            channel_id: channel.channel_id,
+        }
+    }
+}
+
+/// extracts the account address from ics-27 version metadata, which may be
+/// wrapped in ics-29 fee middleware metadata
+fn ica_version_address(version: &str) -> Option<String> {
+    let metadata: serde_json::Value = serde_json::from_str(version).ok()?;
+
+    if let Some(app_version) = metadata["app_version"].as_str() {
+        return ica_version_address(app_version);
+    }
+
+    metadata["address"]
+        .as_str()
+        .filter(|address| !address.is_empty())
+        .map(str::to_string)
+}
+
+/// parameters of the bank module
+#[derive(Debug, Clone, PartialEq)]
+pub struct BankParams {
+    /// per-denom overrides of `default_send_enabled`
+    pub send_enabled: Vec<BankSendEnabled>,
+    pub default_send_enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BankSendEnabled {
+    pub denom: String,
+    pub enabled: bool,
+}
+
+impl From<SendEnabled> for BankSendEnabled {
+    fn from(value: SendEnabled) -> Self {
+        BankSendEnabled {
+            denom: value.denom,
+            enabled: value.enabled,
+        }
+    }
+}
+
+/// parameters of the ibc transfer (ics-20) module
+#[derive(Debug, Clone, PartialEq)]
+pub struct IbcTransferParams {
+    pub send_enabled: bool,
+    pub receive_enabled: bool,
+}
+
+impl From<ProtoIbcTransferParams> for IbcTransferParams {
+    fn from(value: ProtoIbcTransferParams) -> Self {
+        IbcTransferParams {
+            send_enabled: value.send_enabled,
+            receive_enabled: value.receive_enabled,
+        }
+    }
+}
+
+/// parameters of the eip-1559 style fee market module (`feemarket.feemarket.v1`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeMarketParams {
+    pub alpha: f64,
+    pub beta: f64,
+    pub gamma: f64,
+    pub delta: f64,
+    pub min_base_gas_price: f64,
+    pub min_learning_rate: f64,
+    pub max_learning_rate: f64,
+    pub max_block_utilization: u64,
+    pub fee_denom: String,
+    pub enabled: bool,
+}
+
+impl TryFrom<ProtoFeeMarketParams> for FeeMarketParams {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ProtoFeeMarketParams) -> anyhow::Result<Self> {
+        Ok(FeeMarketParams {
+            alpha: parse_dec(&value.alpha)?,
+            beta: parse_dec(&value.beta)?,
+            gamma: parse_dec(&value.gamma)?,
+            delta: parse_dec(&value.delta)?,
+            min_base_gas_price: parse_dec(&value.min_base_gas_price)?,
+            min_learning_rate: parse_dec(&value.min_learning_rate)?,
+            max_learning_rate: parse_dec(&value.max_learning_rate)?,
+            max_block_utilization: value.max_block_utilization,
+            fee_denom: value.fee_denom,
+            enabled: value.enabled,
+        })
+    }
+}
+
+/// block contents along with their execution results
+#[derive(Debug, Clone)]
+pub struct CosmosBlockResults {
+    pub height: u64,
+    /// hex encoded block hash
+    pub hash: String,
+    /// raw encoded txs included in the block, in execution order. the n-th tx
+    /// corresponds to the n-th entry of `results.txs_results`.
+    pub txs: Vec<Vec<u8>>,
+    pub results: cosmrs::rpc::endpoint::block_results::Response,
+}
+
+/// software upgrade plan scheduled through governance
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpgradePlan {
+    pub name: String,
+    pub height: i64,
+    pub info: String,
+}
+
+impl From<Plan> for UpgradePlan {
+    fn from(value: Plan) -> Self {
+        UpgradePlan {
+            name: value.name,
+            height: value.height,
+            info: value.info,
+        }
+    }
+}
+
+/// consensus version of a chain module
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleVersion {
+    pub name: String,
+    pub version: u64,
+}
+
+impl From<ProtoModuleVersion> for ModuleVersion {
+    fn from(value: ProtoModuleVersion) -> Self {
+        ModuleVersion {
+            name: value.name,
+            version: value.version,
+        }
+    }
+}
+
+// Proto definitions to interact with the skip feemarket module
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoFeeMarketParams {
+    #[prost(string, tag = "1")]
+    pub alpha: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub beta: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub gamma: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub delta: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub min_base_gas_price: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub min_learning_rate: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub max_learning_rate: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "8")]
+    pub max_block_utilization: u64,
+    #[prost(uint64, tag = "9")]
+    pub window: u64,
+    #[prost(string, tag = "10")]
+    pub fee_denom: ::prost::alloc::string::String,
+    #[prost(bool, tag = "11")]
+    pub enabled: bool,
+    #[prost(bool, tag = "12")]
+    pub distribute_fees: bool,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeMarketParamsRequest {}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeMarketParamsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub params: ::core::option::Option<ProtoFeeMarketParams>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeMarketGasPriceRequest {
+    #[prost(string, tag = "1")]
+    pub denom: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeMarketGasPriceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub price: ::core::option::Option<DecCoin>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coins_parse_from_strings() {
+        let coin = Coin::parse("1000uatom").unwrap();
+        assert_eq!(
+            (coin.denom.to_string(), coin.amount),
+            ("uatom".to_string(), 1000)
+        );
+
+        let coin = Coin::parse(" 1000 uatom ").unwrap();
+        assert_eq!(
+            (coin.denom.to_string(), coin.amount),
+            ("uatom".to_string(), 1000)
+        );
+
+        assert_eq!(Coin::parse("5.5uusdc").unwrap().amount, 6);
+        assert_eq!(Coin::parse("5.4uusdc").unwrap().amount, 5);
+        assert_eq!(
+            Coin::parse("1ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2")
+                .unwrap()
+                .amount,
+            1
+        );
+
+        assert!(Coin::parse("").is_err());
+        assert!(Coin::parse("1000").is_err());
+        assert!(Coin::parse("uatom").is_err());
+        assert!(Coin::parse("1.2.3uatom").is_err());
+    }
+
+    #[test]
+    fn proposals_round_trip_through_proto() {
+        use cosmos_sdk_proto::tendermint::google::protobuf::Timestamp;
+
+        let content = TextProposal {
+            title: "upgrade".to_string(),
+            description: "upgrade the chain".to_string(),
+        };
+
+        let proto_proposal = Proposal {
+            proposal_id: 7,
+            content: Some(ProtoAny {
+                type_url: TextProposal::type_url(),
+                value: content.encode_to_vec(),
+            }),
+            status: ProposalStatus::VotingPeriod as i32,
+            final_tally_result: Some(TallyResult {
+                yes: "100".to_string(),
+                abstain: String::new(),
+                no: "5".to_string(),
+                no_with_veto: "0".to_string(),
+            }),
+            submit_time: Some(Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 0,
+            }),
+            total_deposit: vec![ProtoCoin {
+                denom: "uatom".to_string(),
+                amount: "1000".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let decoded = Proposal::decode(proto_proposal.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, proto_proposal);
+
+        let proposal = CosmosProposal::try_from(decoded).unwrap();
+        assert_eq!(proposal.proposal_id, 7);
+        assert_eq!(
+            proposal.content_type_url,
+            "/cosmos.gov.v1beta1.TextProposal"
+        );
+        assert_eq!(proposal.title, "upgrade");
+        assert_eq!(proposal.description, "upgrade the chain");
+        assert_eq!(proposal.status, ProposalStatus::VotingPeriod);
+        assert_eq!(
+            proposal.final_tally,
+            ProposalTally {
+                yes: 100,
+                abstain: 0,
+                no: 5,
+                no_with_veto: 0,
+            }
+        );
+        assert_eq!(proposal.submit_time, Some(1_700_000_000));
+        assert_eq!(proposal.voting_end_time, None);
+        assert_eq!(proposal.total_deposit[0].amount, 1000);
+    }
+
+    #[test]
+    fn gov_enums_round_trip_through_proto_values() {
+        for status in [
+            ProposalStatus::Unspecified,
+            ProposalStatus::DepositPeriod,
+            ProposalStatus::VotingPeriod,
+            ProposalStatus::Passed,
+            ProposalStatus::Rejected,
+            ProposalStatus::Failed,
+        ] {
+            assert_eq!(ProposalStatus::try_from(status as i32).unwrap(), status);
+            assert_eq!(
+                ProposalStatus::from_str_name(status.as_str_name()),
+                Some(status)
+            );
+        }
+
+        for option in [
+            VoteOption::Unspecified,
+            VoteOption::Yes,
+            VoteOption::Abstain,
+            VoteOption::No,
+            VoteOption::NoWithVeto,
+        ] {
+            assert_eq!(VoteOption::try_from(option as i32).unwrap(), option);
+            assert_eq!(
+                VoteOption::from_str_name(option.as_str_name()),
+                Some(option)
+            );
+        }
+    }
+
+    #[test]
+    fn validators_convert_from_proto() {
+        use cosmos_sdk_proto::cosmos::staking::v1beta1::{
+            Commission, CommissionRates, Description,
+        };
+
+        let proto_validator = Validator {
+            operator_address: "cosmosvaloper1abc".to_string(),
+            jailed: false,
+            status: BondStatus::Bonded as i32,
+            tokens: "1000000".to_string(),
+            delegator_shares: "1000000000000000000000000".to_string(),
+            description: Some(Description {
+                moniker: "validator".to_string(),
+                ..Default::default()
+            }),
+            commission: Some(Commission {
+                commission_rates: Some(CommissionRates {
+                    rate: "50000000000000000".to_string(),
+                    ..Default::default()
+                }),
+                update_time: None,
+            }),
+            ..Default::default()
+        };
+
+        let validator = CosmosValidator::try_from(proto_validator.clone()).unwrap();
+        assert_eq!(validator.moniker, "validator");
+        assert_eq!(validator.status, BondStatus::Bonded);
+        assert_eq!(validator.tokens, 1_000_000);
+        assert_eq!(validator.delegator_shares, 1_000_000.0);
+        assert_eq!(validator.commission_rate, 0.05);
+
+        let unknown_status = Validator {
+            status: 42,
+            ..proto_validator
+        };
+        assert!(CosmosValidator::try_from(unknown_status).is_err());
+    }
+
+    #[test]
+    fn coin_arithmetic() {
+        let a = Coin::parse("1000uatom").unwrap();
+        let b = Coin::parse("500uatom").unwrap();
+
+        assert_eq!(a.add(&b).unwrap().amount, 1500);
+        assert!(a.add(&Coin::parse("1untrn").unwrap()).is_err());
+        assert_eq!(a.scale(0.25).amount, 250);
+        assert!(a.scale(0.0).is_zero());
+
+        let coin = Coin::from_decimal(1.5, 6, "uatom").unwrap();
+        assert_eq!(coin.amount, 1_500_000);
+        assert_eq!(coin.to_display(6), "1.5");
+        assert_eq!(Coin::parse("2000000uatom").unwrap().to_display(6), "2");
+        assert_eq!(Coin::parse("1uatom").unwrap().to_display(6), "0.000001");
+
+        let totals = sum_coins(&[a, b, Coin::parse("7untrn").unwrap()]);
+        assert_eq!(totals["uatom"], 1500);
+        assert_eq!(totals["untrn"], 7);
+    }
+
+    #[test]
+    fn node_info_converts_from_proto() {
+        let node_info = NodeInfo::from(DefaultNodeInfo {
+            network: "neutron-1".to_string(),
+            channels: vec![0x40, 0x20],
+            other: Some(cosmrs::proto::tendermint::p2p::DefaultNodeInfoOther {
+                tx_index: "on".to_string(),
+                rpc_address: "tcp://0.0.0.0:26657".to_string(),
+            }),
+            ..Default::default()
+        });
+
+        assert_eq!(node_info.network, "neutron-1");
+        assert_eq!(node_info.channels, "4020");
+        assert_eq!(node_info.other["tx_index"], "on");
+    }
+
+    #[test]
+    fn equivocation_evidence_is_decoded() {
+        let equivocation = Equivocation {
+            height: 42,
+            time: None,
+            power: 100,
+            consensus_address: "cosmosvalcons1abc".to_string(),
+        };
+
+        let evidence = EvidenceInfo::try_from(ProtoAny {
+            type_url: Equivocation::type_url(),
+            value: equivocation.encode_to_vec(),
+        })
+        .unwrap();
+
+        assert_eq!(evidence.height, 42);
+        assert_eq!(evidence.power, 100);
+        assert_eq!(evidence.address, "cosmosvalcons1abc");
+        assert_eq!(evidence.hash.len(), 64);
+
+        let unknown = EvidenceInfo::try_from(ProtoAny {
+            type_url: "/custom.Evidence".to_string(),
+            value: vec![1, 2, 3],
+        })
+        .unwrap();
+
+        assert_eq!(unknown.evidence_type, "/custom.Evidence");
+        assert_eq!(unknown.height, 0);
+    }
+
+    #[test]
+    fn multi_send_requires_balanced_inputs_and_outputs() {
+        let addr = |byte: u8| {
+            cosmrs::AccountId::new("cosmos", &[byte; 20])
+                .unwrap()
+                .to_string()
+        };
+        let sender = addr(1);
+        let input = |amount| MultiSendInput {
+            address: sender.clone(),
+            coins: vec![uatom(amount)],
+        };
+        let outputs = vec![
+            MultiSendOutput {
+                address: addr(2),
+                coins: vec![uatom(40)],
+            },
+            MultiSendOutput {
+                address: addr(3),
+                coins: vec![uatom(60)],
+            },
+        ];
+
+        let msg = multi_send_msg(vec![input(100)], outputs.clone()).unwrap();
+        assert_eq!(msg.outputs.len(), 2);
+
+        assert!(multi_send_msg(vec![input(90)], outputs).is_err());
+    }
+
+    #[test]
+    fn parse_dec_handles_both_representations() {
+        assert_eq!(parse_dec("0.020000000000000000").unwrap(), 0.02);
+        assert_eq!(parse_dec("20000000000000000").unwrap(), 0.02);
+        assert_eq!(parse_dec("0").unwrap(), 0.0);
+    }
+
+    #[test]
+    fn parse_dec_truncated_drops_fraction() {
+        assert_eq!(parse_dec_truncated("1234.999").unwrap(), 1234);
+        assert_eq!(parse_dec_truncated("1234999000000000000000").unwrap(), 1234);
+        assert_eq!(parse_dec_truncated("999").unwrap(), 0);
+        assert_eq!(parse_dec_truncated(".5").unwrap(), 0);
+    }
+
+    #[test]
+    fn dec_coins_convert_to_coins() {
+        let coins = dec_coins_to_coins(vec![DecCoin {
+            denom: "uatom".to_string(),
+            amount: "15500000000000000000".to_string(),
+        }])
+        .unwrap();
+
+        assert_eq!(coins.len(), 1);
+        assert_eq!(coins[0].amount, 15);
+        assert_eq!(coins[0].denom.to_string(), "uatom");
+    }
+
+    #[test]
+    fn page_response_maps_empty_values_to_none() {
+        let last_page = PageResponse::from(ProtoPageResponse {
+            next_key: vec![],
+            total: 0,
+        });
+        assert_eq!(last_page, PageResponse::default());
+
+        let page = PageResponse::from(ProtoPageResponse {
+            next_key: vec![1, 2],
+            total: 42,
+        });
+        assert_eq!(page.next_key, Some(vec![1, 2]));
+        assert_eq!(page.total, Some(42));
+    }
+
+    #[test]
+    fn wasm_params_render_access_type_names() {
+        use cosmos_sdk_proto::cosmwasm::wasm::v1::AccessConfig;
+
+        let params = WasmParams::from_proto(
+            ProtoWasmParams {
+                code_upload_access: Some(AccessConfig {
+                    permission: AccessType::AnyOfAddresses as i32,
+                    addresses: vec![],
+                }),
+                instantiate_default_permission: AccessType::Everybody as i32,
+            },
+            WasmParams::DEFAULT_MAX_WASM_CODE_SIZE,
+        );
+
+        assert_eq!(params.code_upload_access, "ACCESS_TYPE_ANY_OF_ADDRESSES");
+        assert_eq!(
+            params.instantiate_default_permission,
+            "ACCESS_TYPE_EVERYBODY"
+        );
+        assert_eq!(params.max_wasm_code_size, 819_200);
+    }
+
+    #[test]
+    fn pfm_memo_nests_forwards_in_hop_order() {
+        let hop = |channel: &str| IbcHop {
+            channel: channel.to_string(),
+            port: "transfer".to_string(),
+            timeout_seconds: 600,
+        };
+
+        let mut params = MultiHopTransferParams {
+            hops: vec![hop("channel-0")],
+            final_receiver: "noble1receiver".to_string(),
+            amount: 100,
+            denom: "uusdc".to_string(),
+            timeout_seconds: 600,
+            retries: 2,
+        };
+        assert_eq!(params.pfm_memo(), None);
+        assert_eq!(params.first_hop_receiver(), "noble1receiver");
+
+        params.hops.extend([hop("channel-1"), hop("channel-2")]);
+        assert_eq!(params.first_hop_receiver(), "pfm");
+
+        let memo: serde_json::Value = serde_json::from_str(&params.pfm_memo().unwrap()).unwrap();
+        assert_eq!(memo["forward"]["channel"], "channel-1");
+        assert_eq!(memo["forward"]["receiver"], "pfm");
+        assert_eq!(memo["forward"]["timeout"], "600s");
+        assert_eq!(memo["forward"]["next"]["forward"]["channel"], "channel-2");
+        assert_eq!(
+            memo["forward"]["next"]["forward"]["receiver"],
+            "noble1receiver"
+        );
+        assert!(memo["forward"]["next"]["forward"].get("next").is_none());
+    }
+
+    #[test]
+    fn block_height_option_sets_grpc_header() {
+        let mut request = tonic::Request::new(());
+        QueryOptions::WithBlockHeight(1234).apply(&mut request);
+
+        assert_eq!(
+            request.metadata().get("x-cosmos-block-height").unwrap(),
+            "1234"
+        );
+    }
+
+    #[test]
+    fn incentivised_packet_combines_payer_fees() {
+        use ibc_proto::ibc::{applications::fee::v1::PacketFee, core::channel::v1::PacketId};
+
+        let fee = |amount: &str| Fee {
+            recv_fee: vec![ProtoCoin {
+                denom: "untrn".to_string(),
+                amount: amount.to_string(),
+            }],
+            ack_fee: vec![],
+            timeout_fee: vec![],
+        };
+
+        let packet = IncentivisedPacket::try_from(IdentifiedPacketFees {
+            packet_id: Some(PacketId {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+                sequence: 7,
+            }),
+            packet_fees: vec![
+                PacketFee {
+                    fee: Some(fee("100")),
+                    refund_address: String::new(),
+                    relayers: vec![],
+                },
+                PacketFee {
+                    fee: Some(fee("50")),
+                    refund_address: String::new(),
+                    relayers: vec![],
+                },
+            ],
+        })
+        .unwrap();
+
+        assert_eq!(
+            packet.packet_id,
+            ("transfer".to_string(), "channel-0".to_string(), 7)
+        );
+        assert_eq!(packet.recv_fee.len(), 2);
+        assert_eq!(packet.recv_fee[1].amount, 50);
+        assert!(packet.ack_fee.is_empty());
+    }
+
+    #[test]
+    fn fee_market_params_parse_decimals() {
+        let params = FeeMarketParams::try_from(ProtoFeeMarketParams {
+            alpha: "0.000000000000000000".to_string(),
+            beta: "1.000000000000000000".to_string(),
+            gamma: "0.050000000000000000".to_string(),
+            delta: "0".to_string(),
+            min_base_gas_price: "0.005300000000000000".to_string(),
+            min_learning_rate: "0.125000000000000000".to_string(),
+            max_learning_rate: "0.125000000000000000".to_string(),
+            max_block_utilization: 30_000_000,
+            window: 1,
+            fee_denom: "untrn".to_string(),
+            enabled: true,
+            distribute_fees: false,
+        })
+        .unwrap();
+
+        assert_eq!(params.gamma, 0.05);
+        assert_eq!(params.min_base_gas_price, 0.0053);
+        assert_eq!(params.fee_denom, "untrn");
+
+        assert!(FeeMarketParams::try_from(ProtoFeeMarketParams {
+            alpha: "not a dec".to_string(),
+            ..Default::default()
+        })
+        .is_err());
+    }
+
+    fn uatom(amount: u128) -> Coin {
+        Coin {
+            denom: "uatom".parse().unwrap(),
+            amount,
+        }
+    }
+
+    #[test]
+    fn continuous_vesting_unlocks_linearly() {
+        let vesting = VestingInfo {
+            original_vesting: vec![uatom(1000)],
+            delegated_free: vec![],
+            delegated_vesting: vec![],
+            start_time: Some(100),
+            end_time: Some(200),
+            vesting_periods: vec![],
+        };
+
+        assert!(vesting.spendable_at(50).is_empty());
+        assert_eq!(vesting.spendable_at(125), vec![uatom(250)]);
+        assert_eq!(vesting.spendable_at(300), vec![uatom(1000)]);
+    }
+
+    #[test]
+    fn periodic_vesting_unlocks_completed_periods() {
+        let vesting = VestingInfo {
+            original_vesting: vec![uatom(300)],
+            delegated_free: vec![],
+            delegated_vesting: vec![],
+            start_time: Some(100),
+            end_time: Some(130),
+            vesting_periods: vec![
+                VestingPeriod {
+                    length: 10,
+                    amount: vec![uatom(100)],
+                },
+                VestingPeriod {
+                    length: 20,
+                    amount: vec![uatom(200)],
+                },
+            ],
+        };
+
+        assert!(vesting.spendable_at(105).is_empty());
+        assert_eq!(vesting.spendable_at(110), vec![uatom(100)]);
+        assert_eq!(vesting.spendable_at(129), vec![uatom(100)]);
+        assert_eq!(vesting.spendable_at(130), vec![uatom(300)]);
+    }
+
+    #[test]
+    fn vesting_info_is_none_for_base_accounts() {
+        let account = ProtoAny {
+            type_url: "/cosmos.auth.v1beta1.BaseAccount".to_string(),
+            value: vec![],
+        };
+        assert_eq!(VestingInfo::from_account(&account).unwrap(), None);
+
+        let delayed = DelayedVestingAccount {
+            base_vesting_account: Some(Default::default()),
+        };
+        let account = ProtoAny {
+            type_url: DelayedVestingAccount::type_url(),
+            value: delayed.encode_to_vec(),
+        };
+        let vesting = VestingInfo::from_account(&account).unwrap().unwrap();
+        assert_eq!(vesting.start_time, None);
+        assert_eq!(vesting.end_time, Some(0));
+    }
+
+    #[test]
+    fn contract_history_entry_decodes_msg() {
+        let entry = ContractHistoryEntry::try_from(ContractCodeHistoryEntry {
+            operation: ContractCodeHistoryOperationType::Migrate as i32,
+            code_id: 42,
+            updated: None,
+            msg: br#"{"new_owner":"neutron1abc"}"#.to_vec(),
+        })
+        .unwrap();
+
+        assert_eq!(entry.operation, ContractOperation::Migrate);
+        assert_eq!(entry.msg["new_owner"], "neutron1abc");
+
+        let genesis = ContractHistoryEntry::try_from(ContractCodeHistoryEntry {
+            operation: ContractCodeHistoryOperationType::Genesis as i32,
+            code_id: 1,
+            updated: None,
+            msg: vec![],
+        })
+        .unwrap();
+
+        assert_eq!(genesis.msg, serde_json::Value::Null);
+    }
+
+    #[test]
+    fn denom_metadata_resolves_display_exponent() {
+        let metadata = DenomMetadata::from(Metadata {
+            base: "uatom".to_string(),
+            display: "atom".to_string(),
+            symbol: "ATOM".to_string(),
+            denom_units: vec![
+                cosmos_sdk_proto::cosmos::bank::v1beta1::DenomUnit {
+                    denom: "uatom".to_string(),
+                    exponent: 0,
+                    aliases: vec!["microatom".to_string()],
+                },
+                cosmos_sdk_proto::cosmos::bank::v1beta1::DenomUnit {
+                    denom: "atom".to_string(),
+                    exponent: 6,
+                    aliases: vec![],
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(metadata.display_exponent(), Some(6));
+        assert_eq!(metadata.denom_units[0].aliases, vec!["microatom"]);
+    }
+
+    #[test]
+    fn ica_channel_reads_address_from_fee_wrapped_version() {
+        let app_version = r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-1","address":"cosmos1ica","encoding":"proto3","tx_type":"sdk_multi_msg"}"#;
+        let version = serde_json::json!({
+            "fee_version": "ics29-1",
+            "app_version": app_version,
+        })
+        .to_string();
+
+        let channel = IcaChannel::from_channel(IdentifiedChannel {
+            port_id: "icacontroller-neutron1owner".to_string(),
+            channel_id: "channel-5".to_string(),
+            connection_hops: vec!["connection-0".to_string()],
+            version,
+            ..Default::default()
+        });
+
+        assert_eq!(channel.address.as_deref(), Some("cosmos1ica"));
+        assert_eq!(channel.connection_id, "connection-0");
+        assert_eq!(
+            ica_version_address(app_version).as_deref(),
+            Some("cosmos1ica")
+        );
+        assert_eq!(ica_version_address("ics20-1"), None);
+    }
+
+    #[test]
+    fn slashing_types_convert_from_proto() {
+        use cosmos_sdk_proto::tendermint::google::protobuf::{Duration, Timestamp};
+
+        let params = SlashingParams::try_from(ProtoSlashingParams {
+            signed_blocks_window: 10_000,
+            min_signed_per_window: b"500000000000000000".to_vec(),
+            downtime_jail_duration: Some(Duration {
+                seconds: 600,
+                nanos: 0,
+            }),
+            slash_fraction_double_sign: b"50000000000000000".to_vec(),
+            slash_fraction_downtime: b"0.000100000000000000".to_vec(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            params,
+            SlashingParams {
+                signed_blocks_window: 10_000,
+                min_signed_per_window: 0.5,
+                downtime_jail_duration: 600,
+                slash_fraction_double_sign: 0.05,
+                slash_fraction_downtime: 0.0001,
+            }
+        );
+
+        let signing_info = |jailed_until: Timestamp| ValidatorSigningInfo {
+            address: "cosmosvalcons1abc".to_string(),
+            start_height: 5,
+            index_offset: 42,
+            jailed_until: Some(jailed_until),
+            tombstoned: false,
+            missed_blocks_counter: 3,
+        };
+
+        let never_jailed = SigningInfo::try_from(signing_info(Timestamp {
+            seconds: 0,
+            nanos: 0,
+        }))
+        .unwrap();
+        assert_eq!(never_jailed.jailed_until, None);
+        assert_eq!(never_jailed.missed_blocks_counter, 3);
+
+        let jailed = SigningInfo::try_from(signing_info(Timestamp {
+            seconds: 1_700_000_000,
+            nanos: 0,
+        }))
+        .unwrap();
+        assert_eq!(jailed.jailed_until.as_deref(), Some("2023-11-14T22:13:20Z"));
+
+        let slash = SlashEvent::try_from(ValidatorSlashEvent {
+            validator_period: 7,
+            fraction: "0.010000000000000000".to_string(),
+        })
+        .unwrap();
+        assert_eq!(
+            slash,
+            SlashEvent {
+                validator_period: 7,
+                fraction: 0.01,
+            }
+        );
+    }
+}
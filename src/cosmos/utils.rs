@@ -0,0 +1,196 @@
+use std::{future::Future, str::FromStr, time::Duration};
+
+use cosmrs::AccountId;
+
+use super::types::{PageRequest, PageResponse};
+
+/// repeatedly runs the query every `poll_interval` until its result
+/// satisfies the predicate, returning that result. query errors abort the
+/// polling. errors once `timeout` has elapsed, polls forever without one.
+///
+/// ```ignore
+/// let balance = poll_until(
+///     || client.query_balance(&address, "untrn"),
+///     |balance| *balance >= 1_000_000,
+///     Duration::from_secs(2),
+///     Some(Duration::from_secs(60)),
+/// )
+/// .await?;
+/// ```
+pub async fn poll_until<T, Q, Fut, P>(
+    mut query: Q,
+    predicate: P,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+) -> anyhow::Result<T>
+where
+    Q: FnMut() -> Fut,
+    Fut: Future<Output = anyhow::Result<T>>,
+    P: Fn(&T) -> bool,
+{
+    let start = tokio::time::Instant::now();
+
+    loop {
+        let result = query().await?;
+
+        if predicate(&result) {
+            return Ok(result);
+        }
+
+        if let Some(timeout) = timeout {
+            if start.elapsed() + poll_interval > timeout {
+                return Err(anyhow::anyhow!(
+                    "condition not met within {}s",
+                    timeout.as_secs_f64()
+                ));
+            }
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// re-encodes a bech32 address with a different prefix, keeping the account
+/// bytes, e.g. `cosmos1..` to `osmo1..`. only meaningful between chains
+/// deriving addresses the same way (same coin type and key algorithm).
+pub fn convert_bech32_prefix(address: &str, new_prefix: &str) -> anyhow::Result<String> {
+    let account_id = AccountId::from_str(address)
+        .map_err(|e| anyhow::anyhow!("Failed to parse address: {e}"))?;
+
+    let converted = AccountId::new(new_prefix, &account_id.to_bytes())
+        .map_err(|e| anyhow::anyhow!("Failed to encode address with prefix {new_prefix}: {e}"))?;
+
+    Ok(converted.to_string())
+}
+
+/// errors if the address is not valid bech32 or, if given, does not use the
+/// expected prefix
+pub fn validate_bech32_address(address: &str, expected_prefix: Option<&str>) -> anyhow::Result<()> {
+    let account_id = AccountId::from_str(address)
+        .map_err(|e| anyhow::anyhow!("Failed to parse address: {e}"))?;
+
+    if let Some(expected_prefix) = expected_prefix {
+        if account_id.prefix() != expected_prefix {
+            return Err(anyhow::anyhow!(
+                "expected {expected_prefix} address, got {address}"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// repeatedly calls a paged query, following the returned `next_key` until
+/// the last page is reached, and returns the combined results.
+///
+/// ```ignore
+/// let balances = collect_all_pages(100, |page| {
+///     client.query_all_balances_paged(&address, page)
+/// })
+/// .await?;
+/// ```
+pub async fn collect_all_pages<T, F, Fut>(page_limit: u64, mut query: F) -> anyhow::Result<Vec<T>>
+where
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = anyhow::Result<(Vec<T>, PageResponse)>>,
+{
+    let mut results = vec![];
+    let mut key = None;
+
+    loop {
+        let page = PageRequest {
+            key: key.take(),
+            limit: page_limit,
+            ..Default::default()
+        };
+
+        let (items, page_response) = query(page).await?;
+        results.extend(items);
+
+        match page_response.next_key {
+            Some(next_key) => key = Some(next_key),
+            None => break,
+        }
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn poll_until_stops_once_predicate_holds() {
+        let mut calls = 0;
+        let result = poll_until(
+            || {
+                calls += 1;
+                std::future::ready(Ok(calls))
+            },
+            |calls| *calls == 3,
+            Duration::from_millis(1),
+            Some(Duration::from_secs(10)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, 3);
+
+        let timed_out = poll_until(
+            || std::future::ready(Ok(0)),
+            |value| *value > 0,
+            Duration::from_millis(1),
+            Some(Duration::from_millis(5)),
+        )
+        .await;
+        assert!(timed_out.is_err());
+    }
+
+    #[test]
+    fn bech32_prefix_conversion_preserves_account_bytes() {
+        let bytes = [7u8; 20];
+        let cosmos_addr = AccountId::new("cosmos", &bytes).unwrap().to_string();
+
+        let osmo_addr = convert_bech32_prefix(&cosmos_addr, "osmo").unwrap();
+        let neutron_addr = convert_bech32_prefix(&osmo_addr, "neutron").unwrap();
+
+        validate_bech32_address(&osmo_addr, Some("osmo")).unwrap();
+        validate_bech32_address(&neutron_addr, Some("neutron")).unwrap();
+        assert_eq!(
+            AccountId::from_str(&neutron_addr).unwrap().to_bytes(),
+            bytes
+        );
+        assert_eq!(
+            convert_bech32_prefix(&neutron_addr, "cosmos").unwrap(),
+            cosmos_addr
+        );
+
+        assert!(validate_bech32_address(&neutron_addr, Some("cosmos")).is_err());
+        assert!(validate_bech32_address("cosmos1invalid", None).is_err());
+        assert!(convert_bech32_prefix("not an address", "osmo").is_err());
+    }
+
+    #[tokio::test]
+    async fn follows_next_key_until_exhausted() {
+        let pages = [vec![1, 2], vec![3, 4], vec![5]];
+
+        let results = collect_all_pages(2, |page| {
+            let index = page.key.map(|k| k[0] as usize).unwrap_or_default();
+            let items = pages[index].clone();
+            let next_key = (index + 1 < pages.len()).then(|| vec![index as u8 + 1]);
+            async move {
+                Ok((
+                    items,
+                    PageResponse {
+                        next_key,
+                        total: None,
+                    },
+                ))
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(results, vec![1, 2, 3, 4, 5]);
+    }
+}
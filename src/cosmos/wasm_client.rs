@@ -3,7 +3,8 @@ use std::{fs, path::Path, str::FromStr};
 use async_trait::async_trait;
 use cosmos_sdk_proto::cosmwasm::wasm::v1::{
     MsgInstantiateContract2, QueryBuildAddressRequest, QueryBuildAddressResponse, QueryCodeRequest,
-    QueryCodeResponse,
+    QueryCodeResponse, QueryContractHistoryRequest, QueryContractsByCodeRequest,
+    QueryParamsRequest,
 };
 use cosmrs::{cosmwasm::MsgInstantiateContract, tx::Fee, Any, Coin};
 use prost::{Message, Name};
@@ -13,12 +14,17 @@ use crate::common::transaction::TransactionResponse;
 use tonic::Request;
 
 use super::{
-    base_client::BaseClient, grpc_client::GrpcSigningClient, CosmosServiceClient, WasmQueryClient,
+    base_client::BaseClient,
+    errors::CosmosError,
+    grpc_client::GrpcSigningClient,
+    types::{ContractHistoryEntry, PageRequest, PageResponse, PollConfig, WasmParams},
+    utils::collect_all_pages,
+    CosmosServiceClient, WasmQueryClient,
 };
 
 use cosmrs::{
     cosmwasm::{MsgExecuteContract, MsgStoreCode},
-    proto::cosmwasm::wasm::v1::QuerySmartContractStateRequest,
+    proto::cosmwasm::wasm::v1::{QueryRawContractStateRequest, QuerySmartContractStateRequest},
     tx::Msg,
     AccountId,
 };
@@ -43,51 +49,37 @@ pub trait WasmClient: GrpcSigningClient + BaseClient {
             fs::read(wasm_path).map_err(|e| anyhow::anyhow!("Failed to read WASM file: {}", e))?;
 
         // 2. upload the wasm to the chain
+        self.upload_wasm(&wasm_bytes, None).await
+    }
+
+    /// stores the wasm code on chain and returns its code id, read from the
+    /// `store_code` event of the confirmed tx
+    async fn upload_wasm(&self, wasm_bytes: &[u8], memo: Option<&str>) -> anyhow::Result<u64> {
         let signing_client = self.get_signing_client().await?;
-        let channel = self.get_grpc_channel().await?;
 
         let store_code_msg = MsgStoreCode {
             sender: signing_client.address.clone(),
-            wasm_byte_code: wasm_bytes,
+            wasm_byte_code: wasm_bytes.to_vec(),
             instantiate_permission: None,
         }
         .to_any()
         .map_err(|e| anyhow::anyhow!("failed to convert MsgStoreCode to proto Any: {e}"))?;
 
-        let simulation_response = self.simulate_tx(store_code_msg.clone()).await?;
-        let fee = self.get_tx_fee(simulation_response)?;
-
-        let raw_tx = signing_client.create_tx(store_code_msg, fee, None).await?;
-
-        let mut grpc_client = CosmosServiceClient::new(channel);
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
-
-        let tx_response = match &broadcast_tx_response.tx_response {
-            Some(response) => response,
-            None => return Err(anyhow::anyhow!("No transaction response returned")),
-        };
-
-        // poll the node until txhash resolves to a response
-        let query_tx_response = self.poll_for_tx(&tx_response.txhash).await?;
-
-        // filter events to find the resulting code id
-        for event in query_tx_response.events.iter() {
-            if event.r#type == "store_code" {
-                for attr in event.attributes.iter() {
-                    if attr.key == "code_id" {
-                        return attr
-                            .value
-                            .parse::<u64>()
-                            .map_err(|_| anyhow::anyhow!("Failed to parse code_id"));
-                    }
-                }
-            }
-        }
+        let tx_response = self
+            .broadcast_and_wait(store_code_msg, memo, PollConfig::default())
+            .await?;
 
-        Err(anyhow::anyhow!(
-            "Failed to find code_id in transaction response: {:?}",
-            query_tx_response
-        ))
+        tx_response
+            .find_event("store_code")
+            .and_then(|event| event.get_attribute("code_id"))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Failed to find code_id in transaction response: {:?}",
+                    tx_response
+                )
+            })?
+            .parse::<u64>()
+            .map_err(|_| anyhow::anyhow!("Failed to parse code_id"))
     }
 
     async fn instantiate(
@@ -97,17 +89,31 @@ pub trait WasmClient: GrpcSigningClient + BaseClient {
         msg: impl Serialize + Send,
         admin: Option<String>,
     ) -> anyhow::Result<String> {
-        let signing_client = self.get_signing_client().await?;
-        let channel = self.get_grpc_channel().await?;
-
         let msg_bytes = serde_json::to_vec(&msg)?;
 
+        self.instantiate_contract(code_id, &msg_bytes, &label, vec![], admin.as_deref(), None)
+            .await
+    }
+
+    /// instantiates the code with an already serialized msg and returns the
+    /// address of the new contract
+    async fn instantiate_contract(
+        &self,
+        code_id: u64,
+        msg: &[u8],
+        label: &str,
+        funds: Vec<Coin>,
+        admin: Option<&str>,
+        memo: Option<&str>,
+    ) -> anyhow::Result<String> {
+        let signing_client = self.get_signing_client().await?;
+
         if label.is_empty() {
             return Err(anyhow::anyhow!("contract label cannot be empty"));
         }
 
         let admin = admin
-            .map(|a| AccountId::from_str(&a))
+            .map(AccountId::from_str)
             .transpose()
             .map_err(|e| anyhow::anyhow!("failed to get AccountId from str: {e}"))?;
 
@@ -115,58 +121,141 @@ pub trait WasmClient: GrpcSigningClient + BaseClient {
             sender: signing_client.address.clone(),
             admin,
             code_id,
-            label: Some(label),
-            msg: msg_bytes,
-            funds: vec![],
+            label: Some(label.to_string()),
+            msg: msg.to_vec(),
+            funds,
         }
         .to_any()
         .map_err(|e| {
             anyhow::anyhow!("failed to convert MsgInstantiateContract to proto Any: {e}")
         })?;
 
-        let simulation_response = self.simulate_tx(instantiate_tx.clone()).await?;
-        let fee = self.get_tx_fee(simulation_response)?;
+        let tx_response = self
+            .broadcast_and_wait(instantiate_tx, memo, PollConfig::default())
+            .await?;
+
+        tx_response.get_contract_address().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Failed to find contract address in transaction response: {:?}",
+                tx_response
+            )
+        })
+    }
 
-        let raw_tx = signing_client.create_tx(instantiate_tx, fee, None).await?;
+    async fn query_code_info(&self, code_id: u64) -> anyhow::Result<QueryCodeResponse> {
+        let channel = self.get_grpc_channel().await?;
 
-        let mut grpc_client = CosmosServiceClient::new(channel);
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let mut grpc_client = WasmQueryClient::new(channel);
+
+        let code_query_request = QueryCodeRequest { code_id };
+
+        let code_query_response = grpc_client
+            .code(code_query_request)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
-        let tx_response = match &broadcast_tx_response.tx_response {
-            Some(response) => response,
-            None => return Err(anyhow::anyhow!("No transaction response returned")),
+        Ok(code_query_response)
+    }
+
+    /// lists the addresses of contracts instantiated from the given code id
+    async fn query_contracts_by_code_paged(
+        &self,
+        code_id: u64,
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<String>, PageResponse)> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = WasmQueryClient::new(channel);
+
+        let request = QueryContractsByCodeRequest {
+            code_id,
+            pagination: Some(page.into()),
         };
 
-        // poll the node until txhash resolves to a response
-        let query_tx_response = self.poll_for_tx(&tx_response.txhash).await?;
-
-        // filter events to find the contract address
-        for event in query_tx_response.events.iter() {
-            if event.r#type == "instantiate" || event.r#type == "instantiate_contract" {
-                for attr in event.attributes.iter() {
-                    if attr.key == "_contract_address" || attr.key == "contract_address" {
-                        return Ok(attr.value.clone());
-                    }
-                }
-            }
-        }
+        let response = grpc_client
+            .contracts_by_code(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
-        Err(anyhow::anyhow!(
-            "Failed to find contract address in transaction response: {:?}",
-            query_tx_response
+        Ok((
+            response.contracts,
+            response.pagination.unwrap_or_default().into(),
         ))
     }
 
-    async fn query_code_info(&self, code_id: u64) -> anyhow::Result<QueryCodeResponse> {
+    async fn query_all_contracts_by_code(&self, code_id: u64) -> anyhow::Result<Vec<String>> {
+        collect_all_pages(100, |page| {
+            self.query_contracts_by_code_paged(code_id, page)
+        })
+        .await
+    }
+
+    /// lists the code history of a contract, oldest entry first
+    async fn query_contract_history_paged(
+        &self,
+        contract: &str,
+        page: PageRequest,
+    ) -> anyhow::Result<(Vec<ContractHistoryEntry>, PageResponse)> {
         let channel = self.get_grpc_channel().await?;
 
         let mut grpc_client = WasmQueryClient::new(channel);
 
-        let code_query_request = QueryCodeRequest { code_id };
+        let request = QueryContractHistoryRequest {
+            address: contract.to_string(),
+            pagination: Some(page.into()),
+        };
 
-        let code_query_response = grpc_client.code(code_query_request).await?.into_inner();
+        let response = grpc_client
+            .contract_history(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
-        Ok(code_query_response)
+        let entries = response
+            .entries
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok((entries, response.pagination.unwrap_or_default().into()))
+    }
+
+    /// returns the full code history of a contract, e.g. to detect migrations
+    async fn query_contract_history(
+        &self,
+        contract: &str,
+    ) -> anyhow::Result<Vec<ContractHistoryEntry>> {
+        collect_all_pages(100, |page| {
+            self.query_contract_history_paged(contract, page)
+        })
+        .await
+    }
+
+    /// returns the wasm upload and instantiation permissions of the chain
+    async fn query_wasm_params(&self) -> anyhow::Result<WasmParams> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = WasmQueryClient::new(channel);
+
+        let response = grpc_client
+            .params(QueryParamsRequest {})
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        let params = response
+            .params
+            .ok_or_else(|| anyhow::anyhow!("No wasm params returned"))?;
+
+        // only older wasmd versions expose the max code size as a param
+        let max_wasm_code_size = match self.query_params("wasm", "maxWasmCodeSize").await {
+            Ok(value) => value.trim_matches('"').parse()?,
+            Err(_) => WasmParams::DEFAULT_MAX_WASM_CODE_SIZE,
+        };
+
+        Ok(WasmParams::from_proto(params, max_wasm_code_size))
     }
 
     async fn predict_instantiate2_addr(
@@ -210,11 +299,39 @@ pub trait WasmClient: GrpcSigningClient + BaseClient {
         admin: Option<String>,
         salt: String,
     ) -> anyhow::Result<String> {
-        let signing_client = self.get_signing_client().await?;
-        let channel = self.get_grpc_channel().await?;
-
         let msg_bytes = serde_json::to_vec(&msg)?;
 
+        let salt =
+            hex::decode(salt).map_err(|e| anyhow::anyhow!("failed to decode hex salt: {e}"))?;
+
+        self.instantiate2_contract(
+            code_id,
+            &msg_bytes,
+            &label,
+            vec![],
+            admin.as_deref(),
+            &salt,
+            None,
+        )
+        .await
+    }
+
+    /// instantiates the code at the address predicted from the code checksum,
+    /// creator and salt (see `predict_instantiate2_addr`). without an admin the
+    /// contract can not be migrated.
+    #[allow(clippy::too_many_arguments)]
+    async fn instantiate2_contract(
+        &self,
+        code_id: u64,
+        msg: &[u8],
+        label: &str,
+        funds: Vec<Coin>,
+        admin: Option<&str>,
+        salt: &[u8],
+        memo: Option<&str>,
+    ) -> anyhow::Result<String> {
+        let signing_client = self.get_signing_client().await?;
+
         let sender = signing_client.address.to_string();
 
         if label.is_empty() {
@@ -222,14 +339,13 @@ pub trait WasmClient: GrpcSigningClient + BaseClient {
         }
 
         let instantiate_contract2_msg = MsgInstantiateContract2 {
-            admin: admin.unwrap_or_default(),
+            admin: admin.unwrap_or_default().to_string(),
             sender,
             code_id,
-            label,
-            msg: msg_bytes,
-            funds: vec![],
-            salt: hex::decode(salt)
-                .map_err(|e| anyhow::anyhow!("failed to decode hex salt: {e}"))?,
+            label: label.to_string(),
+            msg: msg.to_vec(),
+            funds: funds.into_iter().map(Into::into).collect(),
+            salt: salt.to_vec(),
             fix_msg: false,
         };
 
@@ -243,34 +359,16 @@ pub trait WasmClient: GrpcSigningClient + BaseClient {
             value,
         };
 
-        let simulation_response = self.simulate_tx(any_msg.clone()).await?;
-        let fee = self.get_tx_fee(simulation_response)?;
-
-        let raw_tx = signing_client.create_tx(any_msg, fee, None).await?;
-
-        let mut grpc_client = CosmosServiceClient::new(channel);
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
-
-        let tx_response = match &broadcast_tx_response.tx_response {
-            Some(response) => response,
-            None => return Err(anyhow::anyhow!("No transaction response returned")),
-        };
-        // poll the node until txhash resolves to a response
-        let query_tx_response = self.poll_for_tx(&tx_response.txhash).await?;
-
-        // filter events to find the contract address
-        for event in query_tx_response.events.iter() {
-            for event_attr in event.attributes.iter() {
-                if event_attr.key == "_contract_address" {
-                    return Ok(event_attr.value.to_string());
-                }
-            }
-        }
+        let tx_response = self
+            .broadcast_and_wait(any_msg, memo, PollConfig::default())
+            .await?;
 
-        Err(anyhow::anyhow!(
-            "Failed to find contract address in transaction response: {:?}",
-            query_tx_response
-        ))
+        tx_response.get_contract_address().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Failed to find contract address in transaction response: {:?}",
+                tx_response
+            )
+        })
     }
 
     async fn query_contract_state<T: DeserializeOwned>(
@@ -278,25 +376,90 @@ pub trait WasmClient: GrpcSigningClient + BaseClient {
         contract_address: &str,
         query_data: impl Serialize + Send,
     ) -> anyhow::Result<T> {
+        let bin_query = serde_json::to_vec(&query_data)?;
+
+        let response = self
+            .query_smart_contract(contract_address, &bin_query)
+            .await?;
+
+        let parsed: T = serde_json::from_slice(&response)?;
+
+        Ok(parsed)
+    }
+
+    /// runs a smart query with an already serialized msg and returns the raw
+    /// response bytes
+    async fn query_smart_contract(
+        &self,
+        contract_address: &str,
+        msg: &[u8],
+    ) -> anyhow::Result<Vec<u8>> {
         let channel = self.get_grpc_channel().await?;
 
         let mut grpc_client = WasmQueryClient::new(channel);
 
-        let bin_query = serde_json::to_vec(&query_data)?;
-
         let request = QuerySmartContractStateRequest {
             address: contract_address.to_string(),
-            query_data: bin_query,
+            query_data: msg.to_vec(),
         };
 
         let response = grpc_client
             .smart_contract_state(Request::new(request))
-            .await?
+            .await
+            .map_err(CosmosError::from)?
             .into_inner();
 
-        let parsed: T = serde_json::from_slice(&response.data)?;
+        Ok(response.data)
+    }
 
-        Ok(parsed)
+    /// returns the value stored under `key` in the contract's storage, empty
+    /// if the key is not set
+    async fn query_raw_contract_state(
+        &self,
+        contract_address: &str,
+        key: &[u8],
+    ) -> anyhow::Result<Vec<u8>> {
+        let channel = self.get_grpc_channel().await?;
+
+        let mut grpc_client = WasmQueryClient::new(channel);
+
+        let request = QueryRawContractStateRequest {
+            address: contract_address.to_string(),
+            query_data: key.to_vec(),
+        };
+
+        let response = grpc_client
+            .raw_contract_state(Request::new(request))
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
+
+        Ok(response.data)
+    }
+
+    /// executes the contract with an already serialized msg, estimating the
+    /// fee from a simulation
+    async fn execute_contract(
+        &self,
+        contract_address: &str,
+        msg: &[u8],
+        funds: Vec<Coin>,
+        memo: Option<&str>,
+    ) -> anyhow::Result<TransactionResponse> {
+        let signing_client = self.get_signing_client().await?;
+
+        let execute_msg = MsgExecuteContract {
+            sender: signing_client.address.clone(),
+            contract: AccountId::from_str(contract_address).map_err(|e| {
+                anyhow::anyhow!("failed to parse contract addr into AccountId: {e}")
+            })?,
+            msg: msg.to_vec(),
+            funds,
+        }
+        .to_any()
+        .map_err(|e| anyhow::anyhow!("failed to convert MsgExecuteContract to proto Any: {e}"))?;
+
+        self.estimate_and_broadcast(execute_msg, memo).await
     }
 
     async fn execute_wasm(
@@ -331,7 +494,11 @@ pub trait WasmClient: GrpcSigningClient + BaseClient {
 
         let mut grpc_client = CosmosServiceClient::new(channel);
 
-        let broadcast_tx_response = grpc_client.broadcast_tx(raw_tx).await?.into_inner();
+        let broadcast_tx_response = grpc_client
+            .broadcast_tx(raw_tx)
+            .await
+            .map_err(CosmosError::from)?
+            .into_inner();
 
         match broadcast_tx_response.tx_response {
             Some(tx_response) => Ok(TransactionResponse::try_from(tx_response)?),
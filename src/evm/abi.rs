@@ -0,0 +1,90 @@
+use alloy::{
+    dyn_abi::{DynSolType, DynSolValue, JsonAbiExt},
+    json_abi::Function,
+    primitives::Bytes,
+};
+
+/// abi encodes a call of the function with the given human readable
+/// signature (e.g. `transfer(address,uint256)`). meant for calls to contracts
+/// without `sol!` bindings, which should be preferred otherwise.
+pub fn encode_call(signature: &str, args: &[DynSolValue]) -> anyhow::Result<Bytes> {
+    let function = Function::parse(signature)
+        .map_err(|e| anyhow::anyhow!("invalid function signature {signature}: {e}"))?;
+
+    let calldata = function
+        .abi_encode_input(args)
+        .map_err(|e| anyhow::anyhow!("failed to encode arguments of {signature}: {e}"))?;
+
+    Ok(calldata.into())
+}
+
+/// decodes abi encoded return data into values of the given solidity types
+/// (e.g. `["uint256", "bool"]`)
+pub fn decode_returns(types: &[&str], data: &[u8]) -> anyhow::Result<Vec<DynSolValue>> {
+    let types = types
+        .iter()
+        .map(|ty| {
+            DynSolType::parse(ty).map_err(|e| anyhow::anyhow!("invalid solidity type {ty}: {e}"))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let decoded = DynSolType::Tuple(types)
+        .abi_decode_params(data)
+        .map_err(|e| anyhow::anyhow!("failed to decode return data: {e}"))?;
+
+    match decoded {
+        DynSolValue::Tuple(values) => Ok(values),
+        value => Ok(vec![value]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        hex,
+        primitives::{address, U256},
+    };
+
+    use super::*;
+
+    #[test]
+    fn transfer_call_is_abi_encoded() {
+        let calldata = encode_call(
+            "transfer(address,uint256)",
+            &[
+                address!("00000000000000000000000000000000000000aa").into(),
+                U256::from(1000).into(),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            calldata,
+            Bytes::from(hex!(
+                "a9059cbb"
+                "00000000000000000000000000000000000000000000000000000000000000aa"
+                "00000000000000000000000000000000000000000000000000000000000003e8"
+            ))
+        );
+
+        assert!(encode_call("transfer(address,uint256)", &[U256::from(1).into()]).is_err());
+        assert!(encode_call("transfer(address,", &[]).is_err());
+    }
+
+    #[test]
+    fn return_values_are_abi_decoded() {
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "00000000000000000000000000000000000000000000000000000000000003e8"
+        );
+
+        assert_eq!(
+            decode_returns(&["bool", "uint256"], &data).unwrap(),
+            vec![
+                DynSolValue::Bool(true),
+                DynSolValue::Uint(U256::from(1000), 256)
+            ]
+        );
+        assert!(decode_returns(&["uint256"], &data[..16]).is_err());
+    }
+}
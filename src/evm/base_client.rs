@@ -1,21 +1,34 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use alloy::contract::{CallBuilder, CallDecoder};
 use alloy::network::{Ethereum, TransactionBuilder};
 use alloy::network::{EthereumWallet, Network};
-use alloy::primitives::{Address, Bytes, U256};
+use alloy::primitives::{Address, Bytes, TxHash, U256};
 use alloy::providers::{
     fillers::{BlobGasFiller, ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller},
     Identity, RootProvider,
 };
-use alloy::transports::Transport;
+use alloy::transports::{RpcError, Transport, TransportError, TransportErrorKind};
 
+use alloy::providers::ext::{DebugApi, TxPoolApi};
 use alloy::providers::Provider;
-use alloy::rpc::types::{TransactionReceipt, TransactionRequest};
+use alloy::rpc::types::{
+    state::StateOverride,
+    trace::geth::{GethDebugTracingCallOptions, GethTrace},
+    txpool::TxpoolStatus,
+    AccessList, Block, BlockId, BlockNumberOrTag, BlockTransactionsKind, Bundle, FeeHistory,
+    Header, Log, StateContext, SyncStatus, Transaction, TransactionReceipt, TransactionRequest,
+};
 use alloy::transports::http::{Client, Http};
 use tonic::async_trait;
 
+use super::chains::RetryConfig;
+use super::logs::{LogFilter, WatchHandle};
+use super::multicall::{
+    decode_aggregate3, encode_aggregate3, MultiCallRequest, MultiCallResult, MULTICALL3_ADDRESS,
+};
 use super::request_provider_client::RequestProviderClient;
+use super::subscriptions::{spawn_block_poller, SubscriptionHandle};
 
 pub type CustomProvider = FillProvider<
     JoinFill<
@@ -27,6 +40,39 @@ pub type CustomProvider = FillProvider<
     Ethereum,
 >;
 
+/// json-rpc error code returned by nodes that do not implement a method
+const METHOD_NOT_FOUND_CODE: i64 = -32601;
+
+/// returns true if the rpc error indicates that the node does not support
+/// the requested method
+pub(crate) fn is_method_not_found(err: &TransportError) -> bool {
+    err.as_error_resp()
+        .is_some_and(|payload| payload.code == METHOD_NOT_FOUND_CODE)
+}
+
+/// returns true for transport level failures worth retrying: connection
+/// errors and http 429 rate limiting. json-rpc errors returned by the node
+/// are never retried.
+pub(crate) fn is_retryable(err: &TransportError) -> bool {
+    match err {
+        RpcError::Transport(TransportErrorKind::HttpError(http_err)) => http_err.status == 429,
+        RpcError::Transport(TransportErrorKind::Custom(_) | TransportErrorKind::BackendGone) => {
+            true
+        }
+        _ => false,
+    }
+}
+
+/// maps method-not-found rpc errors to a not implemented error naming the
+/// unsupported method, passing any other errors through
+fn not_implemented_err(err: TransportError, method: &str) -> anyhow::Error {
+    if is_method_not_found(&err) {
+        anyhow::anyhow!("not implemented: node does not support {method}")
+    } else {
+        err.into()
+    }
+}
+
 pub trait EvmQueryRequest: Clone {
     /// decoded output type for this query
     type Output;
@@ -62,6 +108,120 @@ where
     }
 }
 
+/// connectivity diagnostics of an evm client
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthStatus {
+    pub block_number: u64,
+    pub chain_id: u64,
+    /// round trip time of the health check requests
+    pub latency_ms: u64,
+}
+
+/// polling behaviour of [`EvmBaseClient::watch_transaction`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchConfig {
+    pub poll_interval: Duration,
+    /// max time to wait for the tx to reach the requested confirmations
+    pub timeout: Option<Duration>,
+    /// number of blocks that must be built on top of the block including the
+    /// tx. 0 returns as soon as the tx is included, 12-20 are common for
+    /// ethereum mainnet.
+    pub confirmations: u32,
+}
+
+impl WatchConfig {
+    /// whether a tx included at `included_at` has enough confirmations at
+    /// block `current`
+    pub fn is_confirmed(&self, included_at: u64, current: u64) -> bool {
+        current.saturating_sub(included_at) >= self.confirmations as u64
+    }
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        WatchConfig {
+            poll_interval: Duration::from_secs(2),
+            timeout: Some(Duration::from_secs(30 * 60)),
+            confirmations: 1,
+        }
+    }
+}
+
+/// suggested eip-1559 fee caps, in wei
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Eip1559FeeEstimate {
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    /// base fee the estimate was derived from
+    pub base_fee_per_gas: u128,
+}
+
+impl Eip1559FeeEstimate {
+    /// caps the fee at 1.5x the current base fee plus the tip, leaving room
+    /// for the base fee to rise over the next blocks
+    pub fn from_base_fee(base_fee_per_gas: u128, max_priority_fee_per_gas: u128) -> Self {
+        Eip1559FeeEstimate {
+            max_fee_per_gas: base_fee_per_gas * 3 / 2 + max_priority_fee_per_gas,
+            max_priority_fee_per_gas,
+            base_fee_per_gas,
+        }
+    }
+
+    /// uses the median of the sampled block rewards as the tip and caps the
+    /// fee at twice the next block's base fee plus the tip, which covers six
+    /// consecutive full blocks of base fee increases
+    pub fn from_fee_history(fee_history: &FeeHistory) -> anyhow::Result<Self> {
+        let base_fee_per_gas = fee_history
+            .next_block_base_fee()
+            .ok_or_else(|| anyhow::anyhow!("fee history contains no base fee"))?;
+
+        // a single percentile is requested, so each block has one reward
+        let mut rewards: Vec<u128> = fee_history
+            .reward
+            .iter()
+            .flatten()
+            .filter_map(|block_rewards| block_rewards.first().copied())
+            .collect();
+        rewards.sort_unstable();
+
+        let max_priority_fee_per_gas = match rewards.len() {
+            0 => 0,
+            len if len % 2 == 0 => (rewards[len / 2 - 1] + rewards[len / 2]) / 2,
+            len => rewards[len / 2],
+        };
+
+        Ok(Eip1559FeeEstimate {
+            max_fee_per_gas: base_fee_per_gas * 2 + max_priority_fee_per_gas,
+            max_priority_fee_per_gas,
+            base_fee_per_gas,
+        })
+    }
+}
+
+/// sync progress of the node an evm client is connected to. block numbers
+/// are only set while the node is syncing.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EvmSyncStatus {
+    pub is_syncing: bool,
+    pub starting_block: Option<u64>,
+    pub current_block: Option<u64>,
+    pub highest_block: Option<u64>,
+}
+
+impl From<SyncStatus> for EvmSyncStatus {
+    fn from(value: SyncStatus) -> Self {
+        match value {
+            SyncStatus::Info(info) => EvmSyncStatus {
+                is_syncing: true,
+                starting_block: info.starting_block.try_into().ok(),
+                current_block: info.current_block.try_into().ok(),
+                highest_block: info.highest_block.try_into().ok(),
+            },
+            SyncStatus::None => EvmSyncStatus::default(),
+        }
+    }
+}
+
 /// base client trait with default implementations for evm based clients.
 ///
 /// for chains which are somehow unique in their common module implementations,
@@ -76,6 +236,134 @@ pub trait EvmBaseClient: RequestProviderClient {
         Ok(block)
     }
 
+    /// verifies that the client can reach the node by fetching the latest
+    /// block number and the chain id
+    async fn health_check(&self) -> anyhow::Result<HealthStatus> {
+        let client = self.get_request_provider().await?;
+
+        let start = std::time::Instant::now();
+
+        let block_number = client.get_block_number().await?;
+        let chain_id = client.get_chain_id().await?;
+
+        Ok(HealthStatus {
+            block_number,
+            chain_id,
+            latency_ms: start.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// returns the block currently being built by the node, with tx hashes only
+    async fn get_pending_block(&self) -> anyhow::Result<Option<Block>> {
+        let client = self.get_request_provider().await?;
+
+        let block = client
+            .get_block_by_number(BlockNumberOrTag::Pending, BlockTransactionsKind::Hashes)
+            .await?;
+
+        Ok(block)
+    }
+
+    async fn eth_syncing_status(&self) -> anyhow::Result<EvmSyncStatus> {
+        let client = self.get_request_provider().await?;
+
+        let status = client.syncing().await?;
+
+        Ok(status.into())
+    }
+
+    async fn is_syncing(&self) -> anyhow::Result<bool> {
+        Ok(self.eth_syncing_status().await?.is_syncing)
+    }
+
+    async fn get_max_priority_fee_per_gas(&self) -> anyhow::Result<u128> {
+        let client = self.get_request_provider().await?;
+
+        client
+            .get_max_priority_fee_per_gas()
+            .await
+            .map_err(|e| not_implemented_err(e, "eth_maxPriorityFeePerGas"))
+    }
+
+    /// suggests eip-1559 fees from the latest base fee and the node's tip
+    /// estimate. nodes without eip-1559 support get the legacy gas price for
+    /// both values.
+    async fn suggest_eip1559_fees(&self) -> anyhow::Result<Eip1559FeeEstimate> {
+        let client = self.get_request_provider().await?;
+
+        let base_fee = client
+            .get_block_by_number(BlockNumberOrTag::Latest, BlockTransactionsKind::Hashes)
+            .await?
+            .and_then(|block| block.header.base_fee_per_gas);
+
+        let priority_fee = match client.get_max_priority_fee_per_gas().await {
+            Ok(fee) => Some(fee),
+            Err(e) if is_method_not_found(&e) => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        match (base_fee, priority_fee) {
+            (Some(base_fee), Some(priority_fee)) => Ok(Eip1559FeeEstimate::from_base_fee(
+                base_fee as u128,
+                priority_fee,
+            )),
+            _ => {
+                log::debug!("eip-1559 fees unavailable, falling back to eth_gasPrice");
+                let gas_price = client.get_gas_price().await?;
+
+                Ok(Eip1559FeeEstimate {
+                    max_fee_per_gas: gas_price,
+                    max_priority_fee_per_gas: gas_price,
+                    base_fee_per_gas: gas_price,
+                })
+            }
+        }
+    }
+
+    /// estimates eip-1559 fees from the tips paid over the last `block_count`
+    /// blocks at the given reward percentile (e.g. 50.0), see
+    /// [`Eip1559FeeEstimate::from_fee_history`]
+    async fn estimate_eip1559_fees(
+        &self,
+        block_count: u64,
+        reward_percentile: f64,
+    ) -> anyhow::Result<Eip1559FeeEstimate> {
+        anyhow::ensure!(
+            (0.0..=100.0).contains(&reward_percentile),
+            "reward percentile {reward_percentile} is not within 0 and 100"
+        );
+
+        let client = self.get_request_provider().await?;
+
+        let fee_history = client
+            .get_fee_history(block_count, BlockNumberOrTag::Latest, &[reward_percentile])
+            .await
+            .map_err(|e| not_implemented_err(e, "eth_feeHistory"))?;
+
+        Eip1559FeeEstimate::from_fee_history(&fee_history)
+    }
+
+    /// sets the suggested eip-1559 fees on a tx request that has none set.
+    /// fees that were already set on the request are kept.
+    async fn fill_eip1559_fees(
+        &self,
+        tx: TransactionRequest,
+    ) -> anyhow::Result<TransactionRequest> {
+        if tx.max_fee_per_gas.is_some() && tx.max_priority_fee_per_gas.is_some() {
+            return Ok(tx);
+        }
+
+        let fees = self.suggest_eip1559_fees().await?;
+
+        Ok(TransactionRequest {
+            max_fee_per_gas: tx.max_fee_per_gas.or(Some(fees.max_fee_per_gas)),
+            max_priority_fee_per_gas: tx
+                .max_priority_fee_per_gas
+                .or(Some(fees.max_priority_fee_per_gas)),
+            ..tx
+        })
+    }
+
     async fn query_balance(&self, address: &str) -> anyhow::Result<U256> {
         let client = self.get_request_provider().await?;
 
@@ -85,10 +373,21 @@ pub trait EvmBaseClient: RequestProviderClient {
         Ok(balance)
     }
 
+    /// sets the client's default gas limit on a tx request without one
+    fn with_default_gas_limit(&self, mut tx: TransactionRequest) -> TransactionRequest {
+        if tx.gas.is_none() {
+            tx.gas = self.default_gas_limit();
+        }
+
+        tx
+    }
+
     async fn execute_tx(&self, tx: TransactionRequest) -> anyhow::Result<TransactionReceipt> {
         let client = self.get_request_provider().await?;
 
-        let signed_tx = tx.from(self.signer().address());
+        let signed_tx = self
+            .with_default_gas_limit(tx)
+            .from(self.signer().address());
 
         let tx_response = client
             .send_transaction(signed_tx)
@@ -99,6 +398,73 @@ pub trait EvmBaseClient: RequestProviderClient {
         Ok(tx_response)
     }
 
+    /// lists the accounts managed by the node.
+    ///
+    /// node-managed accounts are only available on development nodes (e.g.
+    /// anvil, hardhat) with unlocked accounts. production setups should sign
+    /// with the client's own signer instead.
+    async fn get_accounts(&self) -> anyhow::Result<Vec<Address>> {
+        let client = self.get_request_provider().await?;
+
+        client
+            .get_accounts()
+            .await
+            .map_err(|e| not_implemented_err(e, "eth_accounts"))
+    }
+
+    /// signs the message with a node-managed account via `personal_sign`
+    async fn personal_sign(&self, message: &Bytes, address: Address) -> anyhow::Result<[u8; 65]> {
+        let client = self.get_request_provider().await?;
+
+        let signature: Bytes = client
+            .client()
+            .request("personal_sign", (message, address))
+            .await
+            .map_err(|e| not_implemented_err(e, "personal_sign"))?;
+
+        signature
+            .as_ref()
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("invalid signature length: {}", signature.len()))
+    }
+
+    /// signs eip-712 typed data with a node-managed account
+    async fn sign_typed_data_v4(
+        &self,
+        address: Address,
+        typed_data: &serde_json::Value,
+    ) -> anyhow::Result<[u8; 65]> {
+        let client = self.get_request_provider().await?;
+
+        let signature: Bytes = client
+            .client()
+            .request("eth_signTypedData_v4", (address, typed_data))
+            .await
+            .map_err(|e| not_implemented_err(e, "eth_signTypedData_v4"))?;
+
+        signature
+            .as_ref()
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("invalid signature length: {}", signature.len()))
+    }
+
+    /// sends the tx through `eth_sendTransaction`, leaving the signing to the
+    /// node. the tx `from` must be one of the node-managed accounts.
+    async fn send_transaction_from_node(
+        &self,
+        tx: TransactionRequest,
+    ) -> anyhow::Result<TransactionReceipt> {
+        if tx.from.is_none() {
+            return Err(anyhow::anyhow!("node-managed txs require a from address"));
+        }
+
+        let client = self.get_request_provider().await?;
+
+        let receipt = client.send_transaction(tx).await?.get_receipt().await?;
+
+        Ok(receipt)
+    }
+
     async fn sign_and_send(&self, tx: TransactionRequest) -> anyhow::Result<TransactionReceipt> {
         let wallet = EthereumWallet::from(self.signer());
         let rp = self.get_request_provider().await?;
@@ -106,7 +472,10 @@ pub trait EvmBaseClient: RequestProviderClient {
         // Get current nonce
         let nonce = rp.get_transaction_count(self.signer().address()).await?;
         // Add nonce and sender to transaction request
-        let tx_with_nonce_and_sender = tx.nonce(nonce).from(self.signer().address());
+        let tx_with_nonce_and_sender = self
+            .with_default_gas_limit(tx)
+            .nonce(nonce)
+            .from(self.signer().address());
         // Fill the transaction request with all other necessary information from request provider
         let tx_request = match rp.fill(tx_with_nonce_and_sender).await?.as_builder() {
             Some(tx_request) => tx_request.clone(),
@@ -129,18 +498,467 @@ pub trait EvmBaseClient: RequestProviderClient {
         Ok(tx_hash)
     }
 
+    /// asks the node for the storage slots the tx touches, returning the
+    /// suggested access list along with the gas used when it is applied
+    async fn create_access_list(
+        &self,
+        tx: &TransactionRequest,
+    ) -> anyhow::Result<(AccessList, U256)> {
+        let client = self.get_request_provider().await?;
+
+        let tx = tx.clone().from(self.signer().address());
+
+        let result = client
+            .create_access_list(&tx)
+            .await
+            .map_err(|e| not_implemented_err(e, "eth_createAccessList"))?;
+
+        if let Some(err) = result.error {
+            return Err(anyhow::anyhow!("failed to create access list: {err}"));
+        }
+
+        Ok((result.access_list, result.gas_used))
+    }
+
+    /// signs and sends the tx with an access list attached. if the request has
+    /// no access list one is created via `eth_createAccessList`. requests
+    /// without eip-1559 fees are sent as eip-2930 (type 1) txs.
+    async fn sign_and_send_with_access_list(
+        &self,
+        tx: TransactionRequest,
+    ) -> anyhow::Result<TransactionReceipt> {
+        let mut tx = tx;
+
+        if tx.access_list.is_none() {
+            let (access_list, _) = self.create_access_list(&tx).await?;
+            tx.access_list = Some(access_list);
+        }
+
+        if tx.max_fee_per_gas.is_none() && tx.gas_price.is_none() {
+            let client = self.get_request_provider().await?;
+            tx.gas_price = Some(client.get_gas_price().await?);
+        }
+
+        self.sign_and_send(tx).await
+    }
+
+    /// waits until the tx is included and buried under enough blocks to
+    /// satisfy the configured number of confirmations
+    async fn watch_transaction(
+        &self,
+        tx_hash: TxHash,
+        config: WatchConfig,
+    ) -> anyhow::Result<TransactionReceipt> {
+        let client = self.get_request_provider().await?;
+
+        let watch = async {
+            let receipt = loop {
+                if let Some(receipt) = client.get_transaction_receipt(tx_hash).await? {
+                    break receipt;
+                }
+                tokio::time::sleep(config.poll_interval).await;
+            };
+
+            let included_at = receipt
+                .block_number
+                .ok_or_else(|| anyhow::anyhow!("receipt of tx {tx_hash} has no block number"))?;
+
+            while !config.is_confirmed(included_at, client.get_block_number().await?) {
+                tokio::time::sleep(config.poll_interval).await;
+            }
+
+            Ok(receipt)
+        };
+
+        match config.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, watch).await.map_err(|_| {
+                anyhow::anyhow!(
+                    "tx {tx_hash} did not reach {} confirmations within {timeout:?}",
+                    config.confirmations
+                )
+            })?,
+            None => watch.await,
+        }
+    }
+
+    /// fetches all transaction receipts of a block using `eth_getBlockReceipts`.
+    /// nodes that do not support the bulk endpoint are handled by falling back
+    /// to fetching each receipt individually.
+    async fn get_block_receipts(
+        &self,
+        block_number: u64,
+    ) -> anyhow::Result<Vec<TransactionReceipt>> {
+        let client = self.get_request_provider().await?;
+
+        let block_id = BlockId::Number(BlockNumberOrTag::Number(block_number));
+
+        match client.get_block_receipts(block_id).await {
+            Ok(Some(receipts)) => return Ok(receipts),
+            Ok(None) => return Err(anyhow::anyhow!("block {block_number} not found")),
+            Err(e) if is_method_not_found(&e) => {
+                log::debug!("eth_getBlockReceipts not supported, fetching receipts one by one");
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let block = client
+            .get_block_by_number(block_number.into(), BlockTransactionsKind::Hashes)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("block {block_number} not found"))?;
+
+        let mut receipts = Vec::with_capacity(block.transactions.len());
+        for tx_hash in block.transactions.hashes() {
+            let receipt = client
+                .get_transaction_receipt(tx_hash)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("receipt for tx {tx_hash} not found"))?;
+            receipts.push(receipt);
+        }
+
+        Ok(receipts)
+    }
+
+    /// returns the number of receipts (one per transaction) in a block
+    /// without fetching the receipts themselves
+    async fn get_block_receipt_count(&self, block_number: u64) -> anyhow::Result<usize> {
+        let client = self.get_request_provider().await?;
+
+        let count = client
+            .get_block_transaction_count_by_number(block_number.into())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("block {block_number} not found"))?;
+
+        Ok(count as usize)
+    }
+
     async fn query<Q: EvmQueryRequest + Send>(&self, builder: Q) -> anyhow::Result<Q::Output> {
         let client = self.get_request_provider().await?;
 
         let tx_request: TransactionRequest = builder.get_tx_request();
 
-        let raw_response = client.call(&tx_request).await?;
+        let raw_response = self.call_with_retry(&client, &tx_request).await?;
 
         let decoded = builder.decode_response(raw_response)?;
 
         Ok(decoded)
     }
 
+    /// fetches the logs matching the filter's addresses and topics within
+    /// `[from_block, to_block]`. the filter's own start block and poll
+    /// interval are only used by [`EvmBaseClient::watch_logs`].
+    async fn query_logs(
+        &self,
+        filter: &LogFilter,
+        from_block: u64,
+        to_block: u64,
+    ) -> anyhow::Result<Vec<Log>> {
+        anyhow::ensure!(
+            from_block <= to_block,
+            "from block {from_block} is after to block {to_block}"
+        );
+
+        let client = self.get_request_provider().await?;
+
+        let logs = client
+            .get_logs(&filter.to_filter(from_block, to_block)?)
+            .await?;
+
+        Ok(logs)
+    }
+
+    /// polls `eth_getLogs` in the background, calling the handler for every
+    /// new log matching the filter in block order. polling errors are logged
+    /// and the range is retried on the next tick. the watcher runs until the
+    /// returned handle is dropped.
+    async fn watch_logs<F>(&self, filter: LogFilter, handler: F) -> anyhow::Result<WatchHandle>
+    where
+        F: Fn(Log) + Send + 'static,
+    {
+        // validates the filter before spawning the watcher
+        filter.to_filter(filter.from_block, filter.from_block)?;
+        anyhow::ensure!(
+            !filter.poll_interval.is_zero(),
+            "log watcher poll interval must be non-zero"
+        );
+
+        let client = self.get_request_provider().await?;
+
+        let task = tokio::spawn(async move {
+            let mut next_block = filter.from_block;
+            let mut interval = tokio::time::interval(filter.poll_interval);
+
+            loop {
+                interval.tick().await;
+
+                let latest_block = match client.get_block_number().await {
+                    Ok(block) => block,
+                    Err(e) => {
+                        log::warn!("failed to fetch latest block while watching logs: {e}");
+                        continue;
+                    }
+                };
+
+                if latest_block < next_block {
+                    continue;
+                }
+
+                let logs = match filter.to_filter(next_block, latest_block) {
+                    Ok(range_filter) => client.get_logs(&range_filter).await,
+                    Err(_) => return,
+                };
+
+                match logs {
+                    Ok(logs) => {
+                        logs.into_iter().for_each(&handler);
+                        next_block = latest_block + 1;
+                    }
+                    Err(e) => log::warn!(
+                        "failed to fetch logs of blocks {next_block}..={latest_block}: {e}"
+                    ),
+                }
+            }
+        });
+
+        Ok(WatchHandle { task })
+    }
+
+    /// polls `eth_blockNumber` in the background, calling the handler with the
+    /// latest block number whenever it increases, starting with the current
+    /// one. blocks produced within a single poll interval are reported once,
+    /// with the highest number. the subscription runs until the returned
+    /// handle is dropped.
+    async fn subscribe_new_blocks<F>(
+        &self,
+        handler: F,
+        poll_interval: Duration,
+    ) -> anyhow::Result<SubscriptionHandle>
+    where
+        F: Fn(u64) + Send + 'static,
+    {
+        anyhow::ensure!(
+            !poll_interval.is_zero(),
+            "block subscription poll interval must be non-zero"
+        );
+
+        let client = self.get_request_provider().await?;
+
+        Ok(spawn_block_poller(
+            client,
+            poll_interval,
+            |_, block_number| std::future::ready(Ok(block_number)),
+            handler,
+        ))
+    }
+
+    /// same as [`EvmBaseClient::subscribe_new_blocks`], but calls the handler
+    /// with the header of the new block, fetched via `eth_getBlockByNumber`
+    async fn subscribe_new_blocks_with_header<F>(
+        &self,
+        handler: F,
+        poll_interval: Duration,
+    ) -> anyhow::Result<SubscriptionHandle>
+    where
+        F: Fn(Header) + Send + 'static,
+    {
+        anyhow::ensure!(
+            !poll_interval.is_zero(),
+            "block subscription poll interval must be non-zero"
+        );
+
+        let client = self.get_request_provider().await?;
+
+        Ok(spawn_block_poller(
+            client,
+            poll_interval,
+            |client, block_number| async move {
+                client
+                    .get_block_by_number(block_number.into(), BlockTransactionsKind::Hashes)
+                    .await?
+                    .map(|block| block.header)
+                    .ok_or_else(|| anyhow::anyhow!("block {block_number} not found"))
+            },
+            handler,
+        ))
+    }
+
+    /// performs an `eth_call`, retrying transport failures with the backoff
+    /// of the client's retry config
+    async fn call_with_retry(
+        &self,
+        client: &CustomProvider,
+        tx_request: &TransactionRequest,
+    ) -> anyhow::Result<Bytes> {
+        let retry_config = self.retry_config().unwrap_or_else(RetryConfig::none);
+        retry_config.validate()?;
+
+        let mut attempt = 1;
+
+        loop {
+            match client.call(tx_request).await {
+                Ok(response) => return Ok(response),
+                Err(err) if is_retryable(&err) && attempt < retry_config.max_attempts => {
+                    let backoff = retry_config.backoff(attempt);
+                    log::warn!("eth_call attempt {attempt} failed, retrying in {backoff:?}: {err}");
+
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    /// executes the calls in a single `eth_call` through the canonical
+    /// multicall3 contract. results are returned in the order of the calls.
+    async fn multicall(
+        &self,
+        calls: Vec<MultiCallRequest>,
+    ) -> anyhow::Result<Vec<MultiCallResult>> {
+        let client = self.get_request_provider().await?;
+
+        let tx_request = TransactionRequest::default()
+            .to(MULTICALL3_ADDRESS)
+            .input(encode_aggregate3(&calls).into());
+
+        let raw_response = self.call_with_retry(&client, &tx_request).await?;
+
+        decode_aggregate3(&raw_response)
+    }
+
+    /// executes an `eth_call` against state modified by the given per-account
+    /// overrides (balance, nonce, code, storage) without touching chain state.
+    /// an empty override map performs a plain `eth_call`.
+    ///
+    /// nodes without state override support (e.g. many light clients) answer
+    /// with a json-rpc error, which is surfaced as a not implemented error.
+    async fn call_contract_with_override(
+        &self,
+        to: Address,
+        data: Bytes,
+        from: Option<Address>,
+        block: Option<u64>,
+        state_override: &StateOverride,
+    ) -> anyhow::Result<Bytes> {
+        let client = self.get_request_provider().await?;
+
+        let mut tx_request = TransactionRequest::default().to(to).input(data.into());
+        if let Some(from) = from {
+            tx_request = tx_request.from(from);
+        }
+
+        let mut call = client.call(&tx_request);
+        if let Some(block) = block {
+            call = call.block(BlockId::Number(BlockNumberOrTag::Number(block)));
+        }
+        if !state_override.is_empty() {
+            call = call.overrides(state_override);
+        }
+
+        call.await
+            .map_err(|e| not_implemented_err(e, "eth_call state overrides"))
+    }
+
+    /// simulates the tx on top of the given block (latest if `None`) and
+    /// returns its full execution trace instead of just the return value
+    async fn debug_trace_call(
+        &self,
+        tx: TransactionRequest,
+        block: Option<u64>,
+        trace_options: GethDebugTracingCallOptions,
+    ) -> anyhow::Result<GethTrace> {
+        let client = self.get_request_provider().await?;
+
+        let block = block
+            .map(|height| BlockId::Number(BlockNumberOrTag::Number(height)))
+            .unwrap_or(BlockId::Number(BlockNumberOrTag::Latest));
+
+        client
+            .debug_trace_call(tx, block, trace_options)
+            .await
+            .map_err(|e| not_implemented_err(e, "debug_traceCall"))
+    }
+
+    /// simulates the txs in order, each on top of the state left by the
+    /// previous one, and returns one trace per tx
+    async fn debug_trace_call_many(
+        &self,
+        txs: Vec<TransactionRequest>,
+        block: Option<u64>,
+        trace_options: GethDebugTracingCallOptions,
+    ) -> anyhow::Result<Vec<GethTrace>> {
+        let client = self.get_request_provider().await?;
+
+        let bundle = Bundle {
+            transactions: txs,
+            block_override: None,
+        };
+
+        let state_context = StateContext {
+            block_number: block.map(|height| BlockId::Number(BlockNumberOrTag::Number(height))),
+            transaction_index: None,
+        };
+
+        client
+            .debug_trace_call_many(vec![bundle], state_context, trace_options)
+            .await
+            .map_err(|e| not_implemented_err(e, "debug_traceCallMany"))
+    }
+
+    /// returns the number of pending and queued txs in the node's tx pool
+    async fn txpool_status(&self) -> anyhow::Result<TxpoolStatus> {
+        let client = self.get_request_provider().await?;
+
+        client
+            .txpool_status()
+            .await
+            .map_err(|e| not_implemented_err(e, "txpool_status"))
+    }
+
+    /// returns a textual summary of every tx in the node's tx pool as raw json.
+    /// cheaper than `txpool_content` while still listing each tx.
+    async fn txpool_inspect(&self) -> anyhow::Result<serde_json::Value> {
+        let client = self.get_request_provider().await?;
+
+        client
+            .client()
+            .request_noparams("txpool_inspect")
+            .await
+            .map_err(|e| not_implemented_err(e, "txpool_inspect"))
+    }
+
+    /// returns the number of pending txs, using `txpool_status` (geth) and
+    /// falling back to `parity_pendingTransactions` on nodes without it
+    async fn get_pending_transaction_count(&self) -> anyhow::Result<u64> {
+        let client = self.get_request_provider().await?;
+
+        match client.txpool_status().await {
+            Ok(status) => return Ok(status.pending),
+            Err(e) if is_method_not_found(&e) => {
+                log::debug!("txpool_status not supported, falling back to parity api");
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let pending: Vec<serde_json::Value> = client
+            .client()
+            .request_noparams("parity_pendingTransactions")
+            .await
+            .map_err(|e| not_implemented_err(e, "parity_pendingTransactions"))?;
+
+        Ok(pending.len() as u64)
+    }
+
+    /// returns the pending txs of the node via `eth_pendingTransactions`
+    async fn get_pending_transactions(&self) -> anyhow::Result<Vec<Transaction>> {
+        let client = self.get_request_provider().await?;
+
+        client
+            .client()
+            .request_noparams("eth_pendingTransactions")
+            .await
+            .map_err(|e| not_implemented_err(e, "eth_pendingTransactions"))
+    }
+
     async fn blocking_query<Q, F>(
         &self,
         builder: Q,   // query definition
@@ -179,3 +997,137 @@ pub trait EvmBaseClient: RequestProviderClient {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy::rpc::json_rpc::ErrorPayload;
+
+    use super::*;
+
+    #[test]
+    fn method_not_found_triggers_receipts_fallback() {
+        let unsupported: TransportError = RpcError::ErrorResp(ErrorPayload {
+            code: -32601,
+            message: "the method eth_getBlockReceipts does not exist".into(),
+            data: None,
+        });
+        assert!(is_method_not_found(&unsupported));
+
+        let other: TransportError = RpcError::ErrorResp(ErrorPayload {
+            code: -32000,
+            message: "header not found".into(),
+            data: None,
+        });
+        assert!(!is_method_not_found(&other));
+
+        assert!(!is_method_not_found(&RpcError::NullResp));
+    }
+
+    #[test]
+    fn only_transport_failures_are_retried() {
+        use alloy::transports::HttpError;
+
+        let rate_limited: TransportError =
+            RpcError::Transport(TransportErrorKind::HttpError(HttpError {
+                status: 429,
+                body: "too many requests".into(),
+            }));
+        assert!(is_retryable(&rate_limited));
+
+        let server_error: TransportError =
+            RpcError::Transport(TransportErrorKind::HttpError(HttpError {
+                status: 400,
+                body: "bad request".into(),
+            }));
+        assert!(!is_retryable(&server_error));
+
+        assert!(is_retryable(&TransportErrorKind::custom_str(
+            "connection refused"
+        )));
+
+        let reverted: TransportError = RpcError::ErrorResp(ErrorPayload {
+            code: 3,
+            message: "execution reverted".into(),
+            data: None,
+        });
+        assert!(!is_retryable(&reverted));
+    }
+
+    #[test]
+    fn sync_status_parses_both_eth_syncing_responses() {
+        let idle: SyncStatus = serde_json::from_str("false").unwrap();
+        assert_eq!(EvmSyncStatus::from(idle), EvmSyncStatus::default());
+
+        let syncing: SyncStatus = serde_json::from_str(
+            r#"{"startingBlock":"0x10","currentBlock":"0x20","highestBlock":"0x30"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            EvmSyncStatus::from(syncing),
+            EvmSyncStatus {
+                is_syncing: true,
+                starting_block: Some(16),
+                current_block: Some(32),
+                highest_block: Some(48),
+            }
+        );
+    }
+
+    #[test]
+    fn eip1559_estimate_adds_tip_to_scaled_base_fee() {
+        let estimate = Eip1559FeeEstimate::from_base_fee(10_000_000_000, 1_000_000_000);
+
+        assert_eq!(estimate.max_fee_per_gas, 16_000_000_000);
+        assert_eq!(estimate.max_priority_fee_per_gas, 1_000_000_000);
+    }
+
+    #[test]
+    fn confirmations_count_blocks_on_top_of_the_inclusion_block() {
+        let config = WatchConfig {
+            confirmations: 3,
+            ..Default::default()
+        };
+
+        assert!(!config.is_confirmed(100, 100));
+        assert!(!config.is_confirmed(100, 102));
+        assert!(config.is_confirmed(100, 103));
+        assert!(config.is_confirmed(100, 104));
+
+        let inclusion_only = WatchConfig {
+            confirmations: 0,
+            ..Default::default()
+        };
+        assert!(inclusion_only.is_confirmed(100, 100));
+    }
+
+    #[test]
+    fn fee_history_estimate_uses_median_tip_and_doubled_base_fee() {
+        let fee_history = FeeHistory {
+            base_fee_per_gas: vec![8, 9, 10, 12],
+            reward: Some(vec![vec![3], vec![1], vec![2]]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Eip1559FeeEstimate::from_fee_history(&fee_history).unwrap(),
+            Eip1559FeeEstimate {
+                max_fee_per_gas: 26,
+                max_priority_fee_per_gas: 2,
+                base_fee_per_gas: 12,
+            }
+        );
+
+        let even_samples = FeeHistory {
+            reward: Some(vec![vec![1], vec![4], vec![2], vec![8]]),
+            ..fee_history
+        };
+        assert_eq!(
+            Eip1559FeeEstimate::from_fee_history(&even_samples)
+                .unwrap()
+                .max_priority_fee_per_gas,
+            3
+        );
+
+        assert!(Eip1559FeeEstimate::from_fee_history(&FeeHistory::default()).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+//! chain ids, public rpc endpoints and gas defaults of the supported evm
+//! chains. the public endpoints are rate limited and meant for development,
+//! production setups should override them.
+
+pub const ETHEREUM_MAINNET_CHAIN_ID: u64 = 1;
+pub const GOERLI_CHAIN_ID: u64 = 5;
+pub const SEPOLIA_CHAIN_ID: u64 = 11155111;
+pub const BASE_MAINNET_CHAIN_ID: u64 = 8453;
+pub const BASE_SEPOLIA_CHAIN_ID: u64 = 84532;
+pub const ARBITRUM_ONE_CHAIN_ID: u64 = 42161;
+pub const ARBITRUM_GOERLI_CHAIN_ID: u64 = 421613;
+pub const POLYGON_MAINNET_CHAIN_ID: u64 = 137;
+pub const POLYGON_MUMBAI_CHAIN_ID: u64 = 80001;
+pub const POLYGON_AMOY_CHAIN_ID: u64 = 80002;
+pub const AVALANCHE_MAINNET_CHAIN_ID: u64 = 43114;
+pub const AVALANCHE_FUJI_CHAIN_ID: u64 = 43113;
+
+pub const ETHEREUM_MAINNET_RPC_URL: &str = "https://ethereum-rpc.publicnode.com";
+pub const GOERLI_RPC_URL: &str = "https://ethereum-goerli-rpc.publicnode.com";
+pub const SEPOLIA_RPC_URL: &str = "https://ethereum-sepolia-rpc.publicnode.com";
+pub const BASE_MAINNET_RPC_URL: &str = "https://mainnet.base.org";
+pub const BASE_SEPOLIA_RPC_URL: &str = "https://sepolia.base.org";
+pub const ARBITRUM_ONE_RPC_URL: &str = "https://arb1.arbitrum.io/rpc";
+pub const ARBITRUM_GOERLI_RPC_URL: &str = "https://goerli-rollup.arbitrum.io/rpc";
+pub const POLYGON_MAINNET_RPC_URL: &str = "https://polygon-rpc.com";
+pub const POLYGON_MUMBAI_RPC_URL: &str = "https://rpc-mumbai.maticvigil.com";
+pub const POLYGON_AMOY_RPC_URL: &str = "https://rpc-amoy.polygon.technology";
+pub const AVALANCHE_MAINNET_RPC_URL: &str = "https://api.avax.network/ext/bc/C/rpc";
+pub const AVALANCHE_FUJI_RPC_URL: &str = "https://api.avax-test.network/ext/bc/C/rpc";
+
+/// gas limit used when a tx does not set one and estimation is skipped
+pub const DEFAULT_GAS_LIMIT: u64 = 500_000;
+/// arbitrum gas usage includes the l1 calldata cost, so the same tx needs a
+/// considerably higher limit than on l1
+pub const ARBITRUM_DEFAULT_GAS_LIMIT: u64 = 5_000_000;
@@ -0,0 +1,244 @@
+pub mod constants;
+
+use std::time::Duration;
+
+use constants::*;
+
+/// evm chains with known defaults
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EvmChain {
+    EthereumMainnet,
+    /// deprecated testnet
+    Goerli,
+    Sepolia,
+    BaseMainnet,
+    BaseSepolia,
+    ArbitrumOne,
+    /// deprecated testnet
+    ArbitrumGoerli,
+    PolygonMainnet,
+    /// deprecated testnet
+    PolygonMumbai,
+    PolygonAmoy,
+    AvalancheMainnet,
+    AvalancheFuji,
+}
+
+impl EvmChain {
+    pub const ALL: [EvmChain; 12] = [
+        EvmChain::EthereumMainnet,
+        EvmChain::Goerli,
+        EvmChain::Sepolia,
+        EvmChain::BaseMainnet,
+        EvmChain::BaseSepolia,
+        EvmChain::ArbitrumOne,
+        EvmChain::ArbitrumGoerli,
+        EvmChain::PolygonMainnet,
+        EvmChain::PolygonMumbai,
+        EvmChain::PolygonAmoy,
+        EvmChain::AvalancheMainnet,
+        EvmChain::AvalancheFuji,
+    ];
+
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            EvmChain::EthereumMainnet => ETHEREUM_MAINNET_CHAIN_ID,
+            EvmChain::Goerli => GOERLI_CHAIN_ID,
+            EvmChain::Sepolia => SEPOLIA_CHAIN_ID,
+            EvmChain::BaseMainnet => BASE_MAINNET_CHAIN_ID,
+            EvmChain::BaseSepolia => BASE_SEPOLIA_CHAIN_ID,
+            EvmChain::ArbitrumOne => ARBITRUM_ONE_CHAIN_ID,
+            EvmChain::ArbitrumGoerli => ARBITRUM_GOERLI_CHAIN_ID,
+            EvmChain::PolygonMainnet => POLYGON_MAINNET_CHAIN_ID,
+            EvmChain::PolygonMumbai => POLYGON_MUMBAI_CHAIN_ID,
+            EvmChain::PolygonAmoy => POLYGON_AMOY_CHAIN_ID,
+            EvmChain::AvalancheMainnet => AVALANCHE_MAINNET_CHAIN_ID,
+            EvmChain::AvalancheFuji => AVALANCHE_FUJI_CHAIN_ID,
+        }
+    }
+
+    /// public rpc endpoint of the chain
+    pub fn default_rpc_url(&self) -> &'static str {
+        match self {
+            EvmChain::EthereumMainnet => ETHEREUM_MAINNET_RPC_URL,
+            EvmChain::Goerli => GOERLI_RPC_URL,
+            EvmChain::Sepolia => SEPOLIA_RPC_URL,
+            EvmChain::BaseMainnet => BASE_MAINNET_RPC_URL,
+            EvmChain::BaseSepolia => BASE_SEPOLIA_RPC_URL,
+            EvmChain::ArbitrumOne => ARBITRUM_ONE_RPC_URL,
+            EvmChain::ArbitrumGoerli => ARBITRUM_GOERLI_RPC_URL,
+            EvmChain::PolygonMainnet => POLYGON_MAINNET_RPC_URL,
+            EvmChain::PolygonMumbai => POLYGON_MUMBAI_RPC_URL,
+            EvmChain::PolygonAmoy => POLYGON_AMOY_RPC_URL,
+            EvmChain::AvalancheMainnet => AVALANCHE_MAINNET_RPC_URL,
+            EvmChain::AvalancheFuji => AVALANCHE_FUJI_RPC_URL,
+        }
+    }
+
+    pub fn default_gas_limit(&self) -> u64 {
+        match self {
+            EvmChain::ArbitrumOne | EvmChain::ArbitrumGoerli => ARBITRUM_DEFAULT_GAS_LIMIT,
+            _ => DEFAULT_GAS_LIMIT,
+        }
+    }
+
+    pub fn from_chain_id(id: u64) -> Option<Self> {
+        EvmChain::ALL
+            .into_iter()
+            .find(|chain| chain.chain_id() == id)
+    }
+}
+
+/// exponential backoff applied to rpc calls that fail at the transport
+/// level (e.g. dropped connections or http 429 rate limiting)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryConfig {
+    /// total number of attempts, including the first one
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub backoff_multiplier: f64,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            backoff_multiplier: 2.0,
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// disables retries, failing on the first error
+    pub fn none() -> Self {
+        RetryConfig {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// checks that the backoff multiplier is a finite, non-negative number
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.backoff_multiplier.is_finite() && self.backoff_multiplier >= 0.0,
+            "invalid retry backoff multiplier {}",
+            self.backoff_multiplier
+        );
+
+        Ok(())
+    }
+
+    /// backoff to wait after the given (1-based) failed attempt
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self
+            .backoff_multiplier
+            .powi(attempt.saturating_sub(1).try_into().unwrap_or(i32::MAX));
+
+        // clamped in f64, as the product overflows a duration for large
+        // attempt counts
+        Duration::try_from_secs_f64(self.initial_backoff.as_secs_f64() * factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+/// connection settings and defaults of an evm client
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvmClientConfig {
+    pub chain_id: u64,
+    pub rpc_url: String,
+    /// gas limit of txs which do not set one, used instead of estimating
+    pub default_gas_limit: u64,
+    pub retry: RetryConfig,
+    /// custom tls material for the rpc endpoint
+    #[cfg(feature = "tls")]
+    pub tls: Option<crate::common::tls::TlsConfig>,
+}
+
+impl EvmClientConfig {
+    /// returns the config of the chain with its public rpc endpoint
+    pub fn for_chain(chain: EvmChain) -> Self {
+        EvmClientConfig {
+            chain_id: chain.chain_id(),
+            rpc_url: chain.default_rpc_url().to_string(),
+            default_gas_limit: chain.default_gas_limit(),
+            retry: RetryConfig::default(),
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+
+    /// overrides the rpc endpoint, keeping the chain defaults
+    pub fn with_rpc_url(mut self, url: &str) -> Self {
+        self.rpc_url = url.to_string();
+        self
+    }
+
+    pub fn with_retry(mut self, config: RetryConfig) -> Self {
+        self.retry = config;
+        self
+    }
+
+    #[cfg(feature = "tls")]
+    pub fn with_tls(mut self, tls_config: crate::common::tls::TlsConfig) -> Self {
+        self.tls = Some(tls_config);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chains_round_trip_through_chain_id() {
+        for chain in EvmChain::ALL {
+            assert_eq!(EvmChain::from_chain_id(chain.chain_id()), Some(chain));
+        }
+
+        assert_eq!(EvmChain::from_chain_id(0), None);
+    }
+
+    #[test]
+    fn config_keeps_chain_defaults_when_overriding_rpc_url() {
+        let config =
+            EvmClientConfig::for_chain(EvmChain::ArbitrumOne).with_rpc_url("http://127.0.0.1:8545");
+
+        assert_eq!(config.chain_id, ARBITRUM_ONE_CHAIN_ID);
+        assert_eq!(config.rpc_url, "http://127.0.0.1:8545");
+        assert_eq!(config.default_gas_limit, ARBITRUM_DEFAULT_GAS_LIMIT);
+    }
+
+    #[test]
+    fn retry_backoff_grows_up_to_the_cap() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            backoff_multiplier: 3.0,
+            max_backoff: Duration::from_secs(1),
+        };
+
+        assert_eq!(config.backoff(1), Duration::from_millis(100));
+        assert_eq!(config.backoff(2), Duration::from_millis(300));
+        assert_eq!(config.backoff(3), Duration::from_millis(900));
+        assert_eq!(config.backoff(4), Duration::from_secs(1));
+        assert_eq!(config.backoff(1_000), Duration::from_secs(1));
+        assert_eq!(config.backoff(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn retry_config_rejects_invalid_multipliers() {
+        assert!(RetryConfig::default().validate().is_ok());
+
+        for backoff_multiplier in [-1.0, f64::NAN, f64::INFINITY] {
+            let config = RetryConfig {
+                backoff_multiplier,
+                ..Default::default()
+            };
+
+            assert!(config.validate().is_err());
+        }
+    }
+}
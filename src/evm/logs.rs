@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use alloy::{
+    primitives::{Address, B256},
+    rpc::types::Filter,
+};
+use tokio::task::JoinHandle;
+
+/// logs to watch with [`crate::evm::base_client::EvmBaseClient::watch_logs`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogFilter {
+    /// contracts to watch, any contract if `None`
+    pub address: Option<Vec<Address>>,
+    /// positional topics to match, `None` matching any topic. at most four
+    /// topics are supported, the first one being the event signature.
+    pub topics: Vec<Option<B256>>,
+    /// first block to fetch logs from
+    pub from_block: u64,
+    pub poll_interval: Duration,
+}
+
+impl LogFilter {
+    /// builds the `eth_getLogs` filter for the given block range
+    pub(crate) fn to_filter(&self, from_block: u64, to_block: u64) -> anyhow::Result<Filter> {
+        anyhow::ensure!(
+            self.topics.len() <= 4,
+            "log filters support at most 4 topics, got {}",
+            self.topics.len()
+        );
+
+        let mut filter = Filter::new().from_block(from_block).to_block(to_block);
+
+        if let Some(addresses) = &self.address {
+            filter = filter.address(addresses.clone());
+        }
+
+        for (position, topic) in self.topics.iter().enumerate() {
+            if let Some(topic) = topic {
+                filter.topics[position] = (*topic).into();
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+/// handle of a background log watcher. the watcher is stopped when the
+/// handle is dropped.
+#[derive(Debug)]
+pub struct WatchHandle {
+    pub(crate) task: JoinHandle<()>,
+}
+
+impl WatchHandle {
+    /// stops the watcher
+    pub fn cancel(self) {}
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::rpc::types::FilterBlockOption;
+
+    use super::*;
+
+    #[test]
+    fn filter_keeps_topic_positions() {
+        let transfer_topic = B256::repeat_byte(0x01);
+        let recipient_topic = B256::repeat_byte(0x02);
+
+        let log_filter = LogFilter {
+            address: Some(vec![Address::repeat_byte(0x11)]),
+            topics: vec![Some(transfer_topic), None, Some(recipient_topic)],
+            from_block: 10,
+            poll_interval: Duration::from_secs(1),
+        };
+
+        let filter = log_filter.to_filter(10, 20).unwrap();
+
+        assert_eq!(
+            filter.block_option,
+            FilterBlockOption::Range {
+                from_block: Some(10u64.into()),
+                to_block: Some(20u64.into()),
+            }
+        );
+        assert!(filter.address.matches(&Address::repeat_byte(0x11)));
+        assert!(filter.topics[0].matches(&transfer_topic));
+        assert!(filter.topics[1].is_empty());
+        assert!(filter.topics[2].matches(&recipient_topic));
+
+        let too_many_topics = LogFilter {
+            topics: vec![None; 5],
+            ..log_filter
+        };
+        assert!(too_many_topics.to_filter(10, 20).is_err());
+    }
+}
@@ -1,7 +1,12 @@
+pub mod abi;
 #[cfg(feature = "test-utils")]
 pub mod anvil;
 pub mod base_client;
+pub mod chains;
+pub mod logs;
+pub mod multicall;
 pub mod request_provider_client;
+pub mod subscriptions;
 
 #[cfg(test)]
 pub mod testing;
@@ -0,0 +1,169 @@
+use alloy::{
+    primitives::{address, Address, Bytes},
+    sol,
+    sol_types::SolCall,
+};
+
+/// canonical multicall3 deployment, available at the same address on most
+/// evm chains
+pub const MULTICALL3_ADDRESS: Address = address!("cA11bde05977b3631167028862bE2a173976CA11");
+
+sol!(
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+    }
+);
+
+/// single call batched through multicall3
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiCallRequest {
+    pub target: Address,
+    pub calldata: Bytes,
+    /// if false, a revert of this call reverts the whole batch
+    pub allow_failure: bool,
+}
+
+/// outcome of a single batched call
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiCallResult {
+    pub success: bool,
+    /// abi encoded return value, or revert data if the call failed
+    pub return_data: Bytes,
+}
+
+/// collects the calls of a multicall3 batch
+#[derive(Debug, Clone, Default)]
+pub struct MulticallBuilder {
+    calls: Vec<MultiCallRequest>,
+}
+
+impl MulticallBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds a call with raw calldata
+    pub fn add_call(
+        mut self,
+        target: Address,
+        calldata: impl Into<Bytes>,
+        allow_failure: bool,
+    ) -> Self {
+        self.calls.push(MultiCallRequest {
+            target,
+            calldata: calldata.into(),
+            allow_failure,
+        });
+        self
+    }
+
+    /// adds a call encoded from a `sol!` generated call type
+    pub fn add_sol_call<C: SolCall>(self, target: Address, call: &C, allow_failure: bool) -> Self {
+        self.add_call(target, call.abi_encode(), allow_failure)
+    }
+
+    pub fn build(self) -> Vec<MultiCallRequest> {
+        self.calls
+    }
+}
+
+/// abi encodes the calls as an `aggregate3` invocation
+pub fn encode_aggregate3(calls: &[MultiCallRequest]) -> Bytes {
+    let calls = calls
+        .iter()
+        .map(|call| IMulticall3::Call3 {
+            target: call.target,
+            allowFailure: call.allow_failure,
+            callData: call.calldata.clone(),
+        })
+        .collect();
+
+    IMulticall3::aggregate3Call { calls }.abi_encode().into()
+}
+
+/// decodes the return data of an `aggregate3` invocation
+pub fn decode_aggregate3(data: &[u8]) -> anyhow::Result<Vec<MultiCallResult>> {
+    let decoded = IMulticall3::aggregate3Call::abi_decode_returns(data, true)?;
+
+    Ok(decoded
+        .returnData
+        .into_iter()
+        .map(|result| MultiCallResult {
+            success: result.success,
+            return_data: result.returnData,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{primitives::U256, sol_types::SolValue};
+
+    use crate::evm::testing::solidity_contracts::MockERC20;
+
+    use super::*;
+
+    #[test]
+    fn aggregate3_round_trips() {
+        let token = Address::repeat_byte(0x11);
+        let holder = Address::repeat_byte(0x22);
+
+        let calls = MulticallBuilder::new()
+            .add_sol_call(token, &MockERC20::totalSupplyCall {}, false)
+            .add_call(holder, vec![0xde, 0xad], true)
+            .build();
+
+        let encoded = encode_aggregate3(&calls);
+        assert_eq!(encoded[..4], IMulticall3::aggregate3Call::SELECTOR);
+
+        let decoded_calls = IMulticall3::aggregate3Call::abi_decode(&encoded, true)
+            .unwrap()
+            .calls;
+        assert_eq!(decoded_calls.len(), 2);
+        assert_eq!(decoded_calls[0].target, token);
+        assert!(!decoded_calls[0].allowFailure);
+        assert_eq!(
+            decoded_calls[0].callData,
+            Bytes::from(MockERC20::totalSupplyCall {}.abi_encode())
+        );
+        assert!(decoded_calls[1].allowFailure);
+
+        let results = vec![
+            IMulticall3::Result {
+                success: true,
+                returnData: U256::from(1000).abi_encode().into(),
+            },
+            IMulticall3::Result {
+                success: false,
+                returnData: Bytes::new(),
+            },
+        ];
+        let return_data = IMulticall3::aggregate3Call::abi_encode_returns(&(results,));
+
+        let decoded = decode_aggregate3(&return_data).unwrap();
+        assert_eq!(
+            decoded,
+            vec![
+                MultiCallResult {
+                    success: true,
+                    return_data: U256::from(1000).abi_encode().into(),
+                },
+                MultiCallResult {
+                    success: false,
+                    return_data: Bytes::new(),
+                },
+            ]
+        );
+    }
+}
@@ -3,10 +3,21 @@ use alloy::{
     providers::{Provider, ProviderBuilder},
     transports::http::reqwest,
 };
+#[cfg(feature = "tls")]
+use alloy::{rpc::client::RpcClient, transports::http::Http};
 use alloy_signer_local::PrivateKeySigner;
 use tonic::async_trait;
 
-use super::base_client::CustomProvider;
+use super::{base_client::CustomProvider, chains::RetryConfig};
+
+/// connection settings of a client's request provider. clients hold them so
+/// the default provider setup of [`RequestProviderClient`] can read them.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionConfig {
+    /// tls material the provider connects with
+    #[cfg(feature = "tls")]
+    tls_config: crate::common::tls::SharedTlsConfig,
+}
 
 /// trait for evm-based clients to enable signing and request provider functionality.
 /// each implementation must provide getters for the rpc url and signer which are used
@@ -16,12 +27,63 @@ pub trait RequestProviderClient {
     fn rpc_url(&self) -> String;
     fn signer(&self) -> PrivateKeySigner;
 
+    /// connection settings of the client. clients which do not provide them
+    /// connect with the defaults.
+    fn connection_config(&self) -> Option<&ConnectionConfig> {
+        None
+    }
+
+    /// custom tls material to use for the http provider. defaults to the tls
+    /// config stored in the connection config of the client.
+    #[cfg(feature = "tls")]
+    fn tls_config(&self) -> Option<crate::common::tls::TlsConfig> {
+        self.connection_config()
+            .and_then(|config| config.tls_config.get())
+    }
+
+    /// connects to the rpc endpoint with the given tls material. the config
+    /// is stored in the connection config of the client, so clients without
+    /// one need to override `tls_config` instead.
+    #[cfg(feature = "tls")]
+    fn with_tls_config(self, tls_config: crate::common::tls::TlsConfig) -> Self
+    where
+        Self: Sized,
+    {
+        if let Some(config) = self.connection_config() {
+            config.tls_config.set(tls_config);
+        }
+        self
+    }
+
+    /// retry policy of `eth_call`s. calls are not retried if none is
+    /// provided.
+    fn retry_config(&self) -> Option<RetryConfig> {
+        None
+    }
+
+    /// gas limit of txs which do not set one. such txs are estimated if none
+    /// is provided.
+    fn default_gas_limit(&self) -> Option<u64> {
+        None
+    }
+
     async fn get_request_provider(&self) -> anyhow::Result<CustomProvider> {
         let url: reqwest::Url = self
             .rpc_url()
             .parse()
             .map_err(|_| anyhow::anyhow!("failed to parse url"))?;
 
+        #[cfg(feature = "tls")]
+        if let Some(tls_config) = self.tls_config() {
+            let transport = Http::with_client(tls_config.reqwest_client()?, url);
+
+            let provider = ProviderBuilder::new()
+                .with_recommended_fillers()
+                .on_client(RpcClient::new(transport, false));
+
+            return Ok(provider);
+        }
+
         let provider = ProviderBuilder::new()
             .with_recommended_fillers()
             .on_http(url);
@@ -0,0 +1,93 @@
+use std::{future::Future, time::Duration};
+
+use alloy::providers::Provider;
+use tokio::task::JoinHandle;
+
+use super::base_client::CustomProvider;
+
+/// handle of a background block subscription. the subscription is stopped
+/// when the handle is dropped.
+#[derive(Debug)]
+pub struct SubscriptionHandle {
+    pub(crate) task: JoinHandle<()>,
+}
+
+impl SubscriptionHandle {
+    /// stops the subscription
+    pub fn cancel(self) {}
+}
+
+impl Drop for SubscriptionHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// polls `eth_blockNumber` every `poll_interval`, fetching the data of every
+/// newly seen latest block with `fetch` and passing it to `handler`. failed
+/// polls or fetches are logged and retried on the next tick.
+pub(crate) fn spawn_block_poller<T, Fetch, Fut, H>(
+    client: CustomProvider,
+    poll_interval: Duration,
+    fetch: Fetch,
+    handler: H,
+) -> SubscriptionHandle
+where
+    T: Send,
+    Fetch: Fn(CustomProvider, u64) -> Fut + Send + 'static,
+    Fut: Future<Output = anyhow::Result<T>> + Send,
+    H: Fn(T) + Send + 'static,
+{
+    let task = tokio::spawn(async move {
+        let mut last_seen = None;
+        let mut interval = tokio::time::interval(poll_interval);
+
+        loop {
+            interval.tick().await;
+
+            let latest_block = match client.get_block_number().await {
+                Ok(block) => block,
+                Err(e) => {
+                    log::warn!("failed to fetch latest block of block subscription: {e}");
+                    continue;
+                }
+            };
+
+            if last_seen.is_some_and(|seen| latest_block <= seen) {
+                continue;
+            }
+
+            match fetch(client.clone(), latest_block).await {
+                Ok(data) => {
+                    handler(data);
+                    last_seen = Some(latest_block);
+                }
+                Err(e) => log::warn!("failed to fetch block {latest_block}: {e}"),
+            }
+        }
+    });
+
+    SubscriptionHandle { task }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn dropping_the_handle_stops_the_task() {
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<()>(1);
+
+        let handle = SubscriptionHandle {
+            task: tokio::spawn(async move {
+                let _tx = tx;
+                std::future::pending::<()>().await;
+            }),
+        };
+
+        drop(handle);
+
+        // the sender is dropped along with the aborted task
+        assert_eq!(rx.recv().await, None);
+    }
+}
@@ -9,7 +9,5 @@ pub mod cosmos;
 pub mod evm;
 #[cfg(feature = "indexer")]
 pub mod indexer;
-
-// TODO fix
-//#[cfg(feature = "solana")]
-//pub mod solana;
+#[cfg(feature = "solana")]
+pub mod solana;
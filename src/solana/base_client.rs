@@ -2,8 +2,8 @@
 use async_trait::async_trait;
 use log::{info, warn};
 use solana_sdk::{
-    instruction::Instruction, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signature,
-    signer::Signer, system_instruction, transaction::Transaction,
+    epoch_info::EpochInfo, instruction::Instruction, native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey, signature::Signature, signer::Signer,
 };
 use std::str::FromStr;
 
@@ -53,7 +53,8 @@ pub trait SolanaBaseClient: SolanaSigningClient {
     ) -> anyhow::Result<TransactionResponse> {
         let keypair = self.get_keypair();
 
-        let instruction = system_instruction::create_account(
+        #[allow(deprecated)]
+        let instruction = solana_sdk::system_instruction::create_account(
             &keypair.pubkey(),
             new_account_pubkey,
             lamports,
@@ -85,6 +86,7 @@ pub trait SolanaBaseClient: SolanaSigningClient {
             success: confirmed,
             block_height: slot,
             gas_used: 0,
+            events: vec![],
         })
     }
 
@@ -189,7 +191,7 @@ pub trait SolanaBaseClient: SolanaSigningClient {
     }
 
     /// Get epoch info
-    async fn get_epoch_info(&self) -> anyhow::Result<solana_rpc_client_api::response::EpochInfo> {
+    async fn get_epoch_info(&self) -> anyhow::Result<EpochInfo> {
         let rpc_client = self.get_rpc_client();
         let epoch_info = rpc_client.get_epoch_info().await?;
         Ok(epoch_info)
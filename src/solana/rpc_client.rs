@@ -36,6 +36,11 @@ pub trait SolanaRpcClient {
         Ok(blockhash)
     }
 
+    /// Get the latest blockhash as a base58 string
+    async fn get_recent_blockhash(&self) -> anyhow::Result<String> {
+        Ok(self.get_latest_blockhash().await?.to_string())
+    }
+
     /// Get account balance in lamports
     async fn get_balance(&self, pubkey: &Pubkey) -> anyhow::Result<u64> {
         let rpc_client = self.get_rpc_client();
@@ -65,6 +70,12 @@ pub trait SolanaRpcClient {
         }
     }
 
+    /// Send an already signed, bincode serialized transaction
+    async fn send_raw_transaction(&self, transaction: &[u8]) -> anyhow::Result<Signature> {
+        let transaction: Transaction = bincode::deserialize(transaction)?;
+        self.send_transaction(&transaction).await
+    }
+
     /// Send a transaction
     async fn send_transaction(&self, transaction: &Transaction) -> anyhow::Result<Signature> {
         let rpc_client = self.get_rpc_client();
@@ -82,13 +93,32 @@ pub trait SolanaRpcClient {
         Ok(signature)
     }
 
-    /// Confirm a transaction
-    async fn confirm_transaction(&self, signature: &Signature) -> anyhow::Result<bool> {
+    /// Check a transaction for confirmation up to `max_retries` times, returning
+    /// whether it reached the client's commitment level
+    async fn confirm_transaction(
+        &self,
+        signature: &Signature,
+        max_retries: u32,
+    ) -> anyhow::Result<bool> {
         let rpc_client = self.get_rpc_client();
-        let result = rpc_client
-            .confirm_transaction_with_spinner(signature, &self.commitment())
-            .await?;
-        Ok(result)
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(
+            DEFAULT_POLLING_INTERVAL_MS,
+        ));
+
+        for _ in 0..max_retries {
+            interval.tick().await;
+
+            let confirmed = rpc_client
+                .confirm_transaction_with_commitment(signature, self.commitment())
+                .await?
+                .value;
+
+            if confirmed {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
     }
 
     /// Get slot (block height)
@@ -152,14 +182,8 @@ pub trait SolanaRpcClient {
             }
 
             match rpc_client.get_signature_status(signature).await {
-                Ok(Some(status)) => {
-                    if let Some(result) = status {
-                        match result {
-                            Ok(()) => return Ok(true),
-                            Err(e) => return Err(anyhow::anyhow!("Transaction failed: {:?}", e)),
-                        }
-                    }
-                }
+                Ok(Some(Ok(()))) => return Ok(true),
+                Ok(Some(Err(e))) => return Err(anyhow::anyhow!("Transaction failed: {:?}", e)),
                 Ok(None) => {
                     // Transaction not yet processed, continue polling
                     continue;
@@ -1,13 +1,13 @@
 // Solana signing client for transaction signing and keypair management
 use async_trait::async_trait;
-use bip32::{Language, Mnemonic};
 use solana_sdk::{
     derivation_path::DerivationPath,
     instruction::Instruction,
     message::Message,
     native_token::LAMPORTS_PER_SOL,
     pubkey::Pubkey,
-    signature::{Keypair, Signature, Signer},
+    signature::{keypair_from_seed_and_derivation_path, Keypair, Signer},
+    signer::keypair::generate_seed_from_seed_phrase_and_passphrase,
     transaction::Transaction,
 };
 use std::str::FromStr;
@@ -79,6 +79,7 @@ pub trait SolanaSigningClient: SolanaRpcClient {
             success: confirmed,
             block_height: slot,
             gas_used: 0, // Solana doesn't have gas, we could use compute units if needed
+            events: vec![],
         })
     }
 
@@ -91,6 +92,7 @@ pub trait SolanaSigningClient: SolanaRpcClient {
         let keypair = self.get_keypair();
         let to_pubkey = Pubkey::from_str(to)?;
 
+        #[allow(deprecated)]
         let instruction = solana_sdk::system_instruction::transfer(
             &keypair.pubkey(),
             &to_pubkey,
@@ -145,6 +147,7 @@ pub trait SolanaSigningClient: SolanaRpcClient {
             success: confirmed,
             block_height: slot,
             gas_used: 0,
+            events: vec![],
         })
     }
 
@@ -180,7 +183,7 @@ impl SolanaClient {
 
     /// Create a new signing client from a private key byte array
     pub fn from_bytes(private_key: &[u8], rpc_url: &str) -> anyhow::Result<Self> {
-        let keypair = Keypair::from_bytes(private_key)?;
+        let keypair = Keypair::try_from(private_key)?;
         Ok(Self::new(keypair, rpc_url))
     }
 
@@ -198,18 +201,14 @@ impl SolanaClient {
 
     /// Create a new signing client from a mnemonic
     pub fn from_mnemonic(mnemonic: &str, rpc_url: &str) -> anyhow::Result<Self> {
-        let mnemonic = Mnemonic::new(mnemonic, Language::English)?;
-        let seed = mnemonic.to_seed("");
+        let seed = generate_seed_from_seed_phrase_and_passphrase(mnemonic, "");
 
         // Use the standard Solana derivation path
-        let derivation_path = DerivationPath::from_str(SOLANA_DERIVATION_PATH)?;
+        let derivation_path = DerivationPath::from_absolute_path_str(SOLANA_DERIVATION_PATH)?;
 
-        // Derive the keypair from the seed using the derivation path
-        let extended_key = bip32::ExtendedPrivateKey::derive_from_path(&seed, &derivation_path)?;
-        let private_key = extended_key.private_key();
-
-        // Create Solana keypair from the private key
-        let keypair = Keypair::from_bytes(&private_key.to_bytes())?;
+        // Derive the ed25519 keypair from the seed (SLIP-0010)
+        let keypair = keypair_from_seed_and_derivation_path(&seed, Some(derivation_path))
+            .map_err(|e| anyhow::anyhow!("Failed to derive keypair: {e}"))?;
 
         Ok(Self::new(keypair, rpc_url))
     }